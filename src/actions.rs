@@ -0,0 +1,532 @@
+use crate::config::{Binding, Keybinds};
+use crate::i18n::I18n;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Search,
+    Compare,
+    Refresh,
+    Export,
+    FilterHero,
+    CycleMatchType,
+    CycleRegion,
+    ClearFilters,
+    Select,
+    Up,
+    Down,
+    Top,
+    Bottom,
+    NextTab,
+    PrevTab,
+    TabOverview,
+    TabMatches,
+    TabStats,
+    TabCharts,
+    TabHeroes,
+    TabPro,
+    TabLive,
+    TabPublic,
+    TabExplorer,
+    TabCompare,
+    CycleStatsPatch,
+    CycleChartView,
+    ChartNext,
+    ChartPrev,
+    ToggleLaning,
+    CycleHeroSort,
+    CycleHeroRole,
+    CycleHeroBracket,
+    CycleProView,
+    CyclePublicMinRank,
+    CyclePublicMaxRank,
+    EditQuery,
+    CycleSavedQuery,
+    SaveQuery,
+    DeleteQuery,
+    ExplorerLeft,
+    ExplorerRight,
+    ToggleFavorite,
+    FavoriteNext,
+    FavoritePrev,
+    FavoriteMoveUp,
+    FavoriteMoveDown,
+    FavoriteRemove,
+    FavoriteAlias,
+    RecentNext,
+    RecentPrev,
+    CycleTheme,
+    CycleLanguage,
+    ClearCache,
+    ReloadConfig,
+    OpenPalette,
+    Cancel,
+    Help,
+    Quit,
+}
+
+impl Action {
+    pub const ALL: [Action; 59] = [
+        Action::Search,
+        Action::Compare,
+        Action::Refresh,
+        Action::Export,
+        Action::FilterHero,
+        Action::CycleMatchType,
+        Action::CycleRegion,
+        Action::ClearFilters,
+        Action::Select,
+        Action::Up,
+        Action::Down,
+        Action::Top,
+        Action::Bottom,
+        Action::NextTab,
+        Action::PrevTab,
+        Action::TabOverview,
+        Action::TabMatches,
+        Action::TabStats,
+        Action::TabCharts,
+        Action::TabHeroes,
+        Action::TabPro,
+        Action::TabLive,
+        Action::TabPublic,
+        Action::TabExplorer,
+        Action::TabCompare,
+        Action::CycleStatsPatch,
+        Action::CycleChartView,
+        Action::ChartNext,
+        Action::ChartPrev,
+        Action::ToggleLaning,
+        Action::CycleHeroSort,
+        Action::CycleHeroRole,
+        Action::CycleHeroBracket,
+        Action::CycleProView,
+        Action::CyclePublicMinRank,
+        Action::CyclePublicMaxRank,
+        Action::EditQuery,
+        Action::CycleSavedQuery,
+        Action::SaveQuery,
+        Action::DeleteQuery,
+        Action::ExplorerLeft,
+        Action::ExplorerRight,
+        Action::ToggleFavorite,
+        Action::FavoriteNext,
+        Action::FavoritePrev,
+        Action::FavoriteMoveUp,
+        Action::FavoriteMoveDown,
+        Action::FavoriteRemove,
+        Action::FavoriteAlias,
+        Action::RecentNext,
+        Action::RecentPrev,
+        Action::CycleTheme,
+        Action::CycleLanguage,
+        Action::ClearCache,
+        Action::ReloadConfig,
+        Action::OpenPalette,
+        Action::Cancel,
+        Action::Help,
+        Action::Quit,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::Search => "search",
+            Action::Compare => "compare",
+            Action::Refresh => "refresh",
+            Action::Export => "export",
+            Action::FilterHero => "filter_hero",
+            Action::CycleMatchType => "filter_type",
+            Action::CycleRegion => "filter_region",
+            Action::ClearFilters => "clear_filters",
+            Action::Select => "open_match",
+            Action::Up => "up",
+            Action::Down => "down",
+            Action::Top => "top",
+            Action::Bottom => "bottom",
+            Action::NextTab => "tab_next",
+            Action::PrevTab => "tab_prev",
+            Action::TabOverview => "tab_overview",
+            Action::TabMatches => "tab_matches",
+            Action::TabStats => "tab_stats",
+            Action::TabCharts => "tab_charts",
+            Action::TabHeroes => "tab_heroes",
+            Action::TabPro => "tab_pro",
+            Action::TabLive => "tab_live",
+            Action::TabPublic => "tab_public",
+            Action::TabExplorer => "tab_explorer",
+            Action::TabCompare => "tab_compare",
+            Action::CycleStatsPatch => "stats_patch",
+            Action::CycleChartView => "chart_view",
+            Action::ChartNext => "chart_next",
+            Action::ChartPrev => "chart_prev",
+            Action::ToggleLaning => "laning",
+            Action::CycleHeroSort => "hero_sort",
+            Action::CycleHeroRole => "hero_role",
+            Action::CycleHeroBracket => "hero_bracket",
+            Action::CycleProView => "pro_view",
+            Action::CyclePublicMinRank => "public_min_rank",
+            Action::CyclePublicMaxRank => "public_max_rank",
+            Action::EditQuery => "explorer_edit",
+            Action::CycleSavedQuery => "explorer_query",
+            Action::SaveQuery => "explorer_save",
+            Action::DeleteQuery => "explorer_delete",
+            Action::ExplorerLeft => "explorer_left",
+            Action::ExplorerRight => "explorer_right",
+            Action::ToggleFavorite => "favorite",
+            Action::FavoriteNext => "favorite_next",
+            Action::FavoritePrev => "favorite_prev",
+            Action::FavoriteMoveUp => "favorite_move_up",
+            Action::FavoriteMoveDown => "favorite_move_down",
+            Action::FavoriteRemove => "favorite_remove",
+            Action::FavoriteAlias => "favorite_alias",
+            Action::RecentNext => "recent_next",
+            Action::RecentPrev => "recent_prev",
+            Action::CycleTheme => "theme",
+            Action::CycleLanguage => "language",
+            Action::ClearCache => "clear_cache",
+            Action::ReloadConfig => "reload_config",
+            Action::OpenPalette => "palette",
+            Action::Cancel => "cancel",
+            Action::Help => "help",
+            Action::Quit => "quit",
+        }
+    }
+
+    pub fn keybind_name(self) -> Option<&'static str> {
+        match self {
+            Action::Search => Some("search"),
+            Action::Compare => Some("compare"),
+            Action::Refresh => Some("refresh"),
+            Action::Export => Some("export"),
+            Action::FilterHero => Some("filter_hero"),
+            Action::CycleMatchType => Some("filter_type"),
+            Action::CycleRegion => Some("filter_region"),
+            Action::Select => Some("select"),
+            Action::Up => Some("up"),
+            Action::Down => Some("down"),
+            Action::Top => Some("top"),
+            Action::Bottom => Some("bottom"),
+            Action::NextTab => Some("tab_next"),
+            Action::PrevTab => Some("tab_prev"),
+            Action::CycleStatsPatch => Some("stats_patch"),
+            Action::CycleChartView => Some("chart_view"),
+            Action::ChartNext => Some("chart_next"),
+            Action::ChartPrev => Some("chart_prev"),
+            Action::ToggleLaning => Some("laning"),
+            Action::CycleHeroSort => Some("hero_sort"),
+            Action::CycleHeroRole => Some("hero_role"),
+            Action::CycleHeroBracket => Some("hero_bracket"),
+            Action::CycleProView => Some("pro_view"),
+            Action::CyclePublicMinRank => Some("public_min_rank"),
+            Action::CyclePublicMaxRank => Some("public_max_rank"),
+            Action::EditQuery => Some("explorer_edit"),
+            Action::CycleSavedQuery => Some("explorer_query"),
+            Action::SaveQuery => Some("explorer_save"),
+            Action::ExplorerLeft => Some("explorer_left"),
+            Action::ExplorerRight => Some("explorer_right"),
+            Action::ToggleFavorite => Some("favorite"),
+            Action::FavoriteNext => Some("favorite_next"),
+            Action::FavoritePrev => Some("favorite_prev"),
+            Action::FavoriteMoveUp => Some("favorite_move_up"),
+            Action::FavoriteMoveDown => Some("favorite_move_down"),
+            Action::FavoriteRemove => Some("favorite_remove"),
+            Action::FavoriteAlias => Some("favorite_alias"),
+            Action::RecentNext => Some("recent_next"),
+            Action::RecentPrev => Some("recent_prev"),
+            Action::OpenPalette => Some("palette"),
+            Action::Cancel => Some("cancel"),
+            Action::Help => Some("help"),
+            Action::Quit => Some("quit"),
+            Action::TabOverview
+            | Action::TabMatches
+            | Action::TabStats
+            | Action::TabCharts
+            | Action::TabHeroes
+            | Action::TabPro
+            | Action::TabLive
+            | Action::TabPublic
+            | Action::TabExplorer
+            | Action::TabCompare
+            | Action::CycleTheme
+            | Action::CycleLanguage
+            | Action::ClearCache
+            | Action::ReloadConfig
+            | Action::DeleteQuery
+            | Action::ClearFilters => None,
+        }
+    }
+
+    pub fn group(self) -> ActionGroup {
+        match self {
+            Action::Search
+            | Action::Compare
+            | Action::Refresh
+            | Action::Export
+            | Action::FilterHero
+            | Action::CycleMatchType
+            | Action::CycleRegion
+            | Action::ClearFilters => ActionGroup::Search,
+            Action::Up
+            | Action::Down
+            | Action::Top
+            | Action::Bottom
+            | Action::Select
+            | Action::RecentNext
+            | Action::RecentPrev
+            | Action::ExplorerLeft
+            | Action::ExplorerRight => ActionGroup::Navigation,
+            Action::NextTab
+            | Action::PrevTab
+            | Action::TabOverview
+            | Action::TabMatches
+            | Action::TabStats
+            | Action::TabCharts
+            | Action::TabHeroes
+            | Action::TabPro
+            | Action::TabLive
+            | Action::TabPublic
+            | Action::TabExplorer
+            | Action::TabCompare
+            | Action::CycleStatsPatch
+            | Action::CycleChartView
+            | Action::ChartNext
+            | Action::ChartPrev
+            | Action::ToggleLaning
+            | Action::CycleHeroSort
+            | Action::CycleHeroRole
+            | Action::CycleHeroBracket
+            | Action::CycleProView
+            | Action::CyclePublicMinRank
+            | Action::CyclePublicMaxRank
+            | Action::EditQuery
+            | Action::CycleSavedQuery
+            | Action::SaveQuery
+            | Action::DeleteQuery => ActionGroup::Views,
+            Action::ToggleFavorite
+            | Action::FavoriteNext
+            | Action::FavoritePrev
+            | Action::FavoriteMoveUp
+            | Action::FavoriteMoveDown
+            | Action::FavoriteRemove
+            | Action::FavoriteAlias => ActionGroup::Favorites,
+            Action::CycleTheme
+            | Action::CycleLanguage
+            | Action::ClearCache
+            | Action::ReloadConfig
+            | Action::OpenPalette
+            | Action::Cancel
+            | Action::Help
+            | Action::Quit => ActionGroup::General,
+        }
+    }
+
+    pub fn in_palette(self) -> bool {
+        !matches!(self, Action::OpenPalette | Action::Cancel)
+    }
+
+    pub fn from_keybind(name: &str) -> Option<Action> {
+        Action::ALL
+            .into_iter()
+            .find(|action| action.keybind_name() == Some(name))
+    }
+
+    pub fn binding(self, keybinds: &Keybinds) -> Option<&Binding> {
+        self.keybind_name().and_then(|name| keybinds.get(name))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EditAction {
+    Submit,
+    Cancel,
+    ClearInput,
+    Complete,
+    Next,
+    Prev,
+    Help,
+    Run,
+}
+
+impl EditAction {
+    pub const ALL: [EditAction; 8] = [
+        EditAction::Submit,
+        EditAction::Cancel,
+        EditAction::ClearInput,
+        EditAction::Complete,
+        EditAction::Next,
+        EditAction::Prev,
+        EditAction::Help,
+        EditAction::Run,
+    ];
+
+    pub fn keybind_name(self) -> &'static str {
+        match self {
+            EditAction::Submit => "submit",
+            EditAction::Cancel => "cancel",
+            EditAction::ClearInput => "clear_input",
+            EditAction::Complete => "complete",
+            EditAction::Next => "next",
+            EditAction::Prev => "prev",
+            EditAction::Help => "help",
+            EditAction::Run => "run",
+        }
+    }
+
+    pub fn from_keybind(name: &str) -> Option<EditAction> {
+        EditAction::ALL
+            .into_iter()
+            .find(|action| action.keybind_name() == name)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ActionGroup {
+    Search,
+    Navigation,
+    Views,
+    Favorites,
+    Editing,
+    General,
+}
+
+impl ActionGroup {
+    pub const ALL: [ActionGroup; 6] = [
+        ActionGroup::Search,
+        ActionGroup::Navigation,
+        ActionGroup::Views,
+        ActionGroup::Favorites,
+        ActionGroup::Editing,
+        ActionGroup::General,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ActionGroup::Search => "search",
+            ActionGroup::Navigation => "navigation",
+            ActionGroup::Views => "views",
+            ActionGroup::Favorites => "favorites",
+            ActionGroup::Editing => "editing",
+            ActionGroup::General => "general",
+        }
+    }
+}
+
+pub struct HelpEntry {
+    pub keys: String,
+    pub label: String,
+}
+
+pub fn help_sections(keybinds: &Keybinds, i18n: &I18n) -> Vec<(ActionGroup, Vec<HelpEntry>)> {
+    let keys = |binding: Option<&Binding>| {
+        binding
+            .filter(|binding| !binding.0.is_empty())
+            .map(|binding| binding.to_string())
+            .unwrap_or_else(|| i18n.help_palette_only().to_string())
+    };
+    ActionGroup::ALL
+        .into_iter()
+        .map(|group| {
+            let entries = if group == ActionGroup::Editing {
+                EditAction::ALL
+                    .into_iter()
+                    .map(|action| HelpEntry {
+                        keys: keys(keybinds.editing.get(action.keybind_name())),
+                        label: i18n.edit_action_label(action).to_string(),
+                    })
+                    .collect()
+            } else {
+                Action::ALL
+                    .into_iter()
+                    .filter(|action| action.group() == group)
+                    .map(|action| HelpEntry {
+                        keys: keys(action.binding(keybinds)),
+                        label: i18n.action_label(action).to_string(),
+                    })
+                    .collect()
+            };
+            (group, entries)
+        })
+        .collect()
+}
+
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+    let candidate: Vec<char> = candidate.chars().flat_map(char::to_lowercase).collect();
+    let mut score = 0i64;
+    let mut qi = 0;
+    let mut last_match: Option<usize> = None;
+    for (ci, c) in candidate.iter().enumerate() {
+        if qi == query.len() {
+            break;
+        }
+        if *c != query[qi] {
+            continue;
+        }
+        score += 1;
+        if last_match.is_some_and(|last| last + 1 == ci) {
+            score += 5;
+        }
+        if ci == 0 || !candidate[ci - 1].is_alphanumeric() {
+            score += 3;
+        }
+        last_match = Some(ci);
+        qi += 1;
+    }
+    if qi < query.len() {
+        return None;
+    }
+    Some(score - candidate.len() as i64 / 8)
+}
+
+pub fn filter_actions(query: &str, i18n: &I18n) -> Vec<Action> {
+    let mut scored: Vec<(i64, Action)> = Action::ALL
+        .iter()
+        .filter(|action| action.in_palette())
+        .filter_map(|action| {
+            let label = fuzzy_score(query, i18n.action_label(*action));
+            let name = fuzzy_score(query, action.name());
+            label.max(name).map(|score| (score, *action))
+        })
+        .collect();
+    scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    scored.into_iter().map(|(_, action)| action).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_query_matches_everything() {
+        assert_eq!(fuzzy_score("", "Refresh"), Some(0));
+        assert_eq!(fuzzy_score("   ", ""), Some(0));
+    }
+
+    #[test]
+    fn requires_every_query_character_in_order() {
+        assert_eq!(fuzzy_score("xyz", "Refresh"), None);
+        assert_eq!(fuzzy_score("hser", "Refresh"), None);
+        assert_eq!(fuzzy_score("refresh now", "Refresh"), None);
+        assert!(fuzzy_score("r f h", "Refresh").is_some());
+        assert!(fuzzy_score("REF", "refresh").is_some());
+        assert!(fuzzy_score("поиск", "Поиск игрока").is_some());
+        assert!(fuzzy_score("搜索", "搜索玩家").is_some());
+    }
+
+    #[test]
+    fn prefers_contiguous_and_word_start_matches() {
+        let contiguous = fuzzy_score("ref", "Refresh").unwrap();
+        let scattered = fuzzy_score("rfh", "Refresh").unwrap();
+        assert!(contiguous > scattered);
+        let word_start = fuzzy_score("tn", "Tab next").unwrap();
+        let inner = fuzzy_score("tn", "Tint").unwrap();
+        assert!(word_start > inner);
+        assert!(fuzzy_score("ref", "Refresh").unwrap() > fuzzy_score("ref", "Refresh the loaded player list").unwrap());
+    }
+}
@@ -9,7 +9,26 @@ use anyhow::Result;
 use tokio::sync::{Mutex, Semaphore};
 
 use crate::config::ApiConfig;
-use crate::models::{HeroConstant, HeroStat, ItemConstant, MatchDetail, PlayerMatch, PlayerResponse};
+use crate::models::{
+    Distributions, ExplorerResult, GameConstants, HeroConstant, HeroDuration, HeroMatchup, HeroStat, HistogramBucket,
+    ItemConstant, ItemPopularity, League, LiveGame, MatchDetail, PatchConstant, PlayerCounts, PlayerMatch,
+    PlayerResponse, PlayerTotal, ProMatch, ProPlayer, ProTeam, PublicMatch, TeamMatch, TeamPlayer, WardMap, WinLoss,
+    WordCloud,
+};
+
+const MATCH_FIELDS: [&str; 11] = [
+    "duration",
+    "start_time",
+    "hero_id",
+    "game_mode",
+    "lobby_type",
+    "cluster",
+    "kills",
+    "deaths",
+    "assists",
+    "gold_per_min",
+    "xp_per_min",
+];
 
 #[derive(Clone)]
 pub struct ApiClient {
@@ -45,13 +64,10 @@ impl ApiClient {
         }
     }
 
-    pub async fn fetch_heroes(&self) -> Result<HashMap<i32, String>> {
+    pub async fn fetch_heroes(&self) -> Result<HashMap<i32, HeroStat>> {
         let url = format!("{}/heroStats", self.base_url);
         let heroes: Vec<HeroStat> = self.get_json(url, None).await?;
-        Ok(heroes
-            .into_iter()
-            .map(|hero| (hero.id, hero.localized_name))
-            .collect())
+        Ok(heroes.into_iter().map(|hero| (hero.id, hero)).collect())
     }
 
     pub async fn fetch_profile(&self, account_id: u32) -> Result<PlayerResponse> {
@@ -70,11 +86,114 @@ impl ApiClient {
             "fallback matches for account_id={}",
             account_id
         ));
-        self.get_json(
-            fallback,
-            Some(vec![("limit", "20".to_string()), ("significant", "0".to_string())]),
-        )
-        .await
+        let mut query = vec![("limit", "20".to_string()), ("significant", "0".to_string())];
+        query.extend(MATCH_FIELDS.iter().map(|field| ("project", field.to_string())));
+        self.get_json(fallback, Some(query)).await
+    }
+
+    pub async fn fetch_totals(&self, account_id: u32) -> Result<Vec<PlayerTotal>> {
+        let url = format!("{}/players/{account_id}/totals", self.base_url);
+        self.get_json(url, None).await
+    }
+
+    pub async fn fetch_counts(&self, account_id: u32) -> Result<PlayerCounts> {
+        let url = format!("{}/players/{account_id}/counts", self.base_url);
+        self.get_json(url, None).await
+    }
+
+    pub async fn fetch_wl(&self, account_id: u32) -> Result<WinLoss> {
+        let url = format!("{}/players/{account_id}/wl", self.base_url);
+        self.get_json(url, None).await
+    }
+
+    pub async fn fetch_histogram(
+        &self,
+        account_id: u32,
+        field: &str,
+        query: Vec<(&str, String)>,
+    ) -> Result<Vec<HistogramBucket>> {
+        let url = format!("{}/players/{account_id}/histograms/{field}", self.base_url);
+        self.get_json(url, Some(query)).await
+    }
+
+    pub async fn fetch_wardmap(&self, account_id: u32, query: Vec<(&str, String)>) -> Result<WardMap> {
+        let url = format!("{}/players/{account_id}/wardmap", self.base_url);
+        self.get_json(url, Some(query)).await
+    }
+
+    pub async fn fetch_wordcloud(&self, account_id: u32, query: Vec<(&str, String)>) -> Result<WordCloud> {
+        let url = format!("{}/players/{account_id}/wordcloud", self.base_url);
+        self.get_json(url, Some(query)).await
+    }
+
+    pub async fn fetch_hero_matchups(&self, hero_id: i32) -> Result<Vec<HeroMatchup>> {
+        let url = format!("{}/heroes/{hero_id}/matchups", self.base_url);
+        self.get_json(url, None).await
+    }
+
+    pub async fn fetch_hero_item_popularity(&self, hero_id: i32) -> Result<ItemPopularity> {
+        let url = format!("{}/heroes/{hero_id}/itemPopularity", self.base_url);
+        self.get_json(url, None).await
+    }
+
+    pub async fn fetch_hero_durations(&self, hero_id: i32) -> Result<Vec<HeroDuration>> {
+        let url = format!("{}/heroes/{hero_id}/durations", self.base_url);
+        self.get_json(url, None).await
+    }
+
+    pub async fn fetch_pro_matches(&self) -> Result<Vec<ProMatch>> {
+        let url = format!("{}/proMatches", self.base_url);
+        self.get_json(url, None).await
+    }
+
+    pub async fn fetch_pro_players(&self) -> Result<Vec<ProPlayer>> {
+        let url = format!("{}/proPlayers", self.base_url);
+        self.get_json(url, None).await
+    }
+
+    pub async fn fetch_teams(&self) -> Result<Vec<ProTeam>> {
+        let url = format!("{}/teams", self.base_url);
+        self.get_json(url, None).await
+    }
+
+    pub async fn fetch_team_matches(&self, team_id: i64) -> Result<Vec<TeamMatch>> {
+        let url = format!("{}/teams/{team_id}/matches", self.base_url);
+        self.get_json(url, None).await
+    }
+
+    pub async fn fetch_team_players(&self, team_id: i64) -> Result<Vec<TeamPlayer>> {
+        let url = format!("{}/teams/{team_id}/players", self.base_url);
+        self.get_json(url, None).await
+    }
+
+    pub async fn fetch_leagues(&self) -> Result<Vec<League>> {
+        let url = format!("{}/leagues", self.base_url);
+        self.get_json(url, None).await
+    }
+
+    pub async fn fetch_league_matches(&self, league_id: i32) -> Result<Vec<ProMatch>> {
+        let url = format!("{}/leagues/{league_id}/matches", self.base_url);
+        self.get_json(url, None).await
+    }
+
+    pub async fn fetch_live(&self) -> Result<Vec<LiveGame>> {
+        let url = format!("{}/live", self.base_url);
+        self.get_json(url, None).await
+    }
+
+    pub async fn fetch_public_matches(&self, query: Vec<(&str, String)>) -> Result<Vec<PublicMatch>> {
+        let url = format!("{}/publicMatches", self.base_url);
+        self.get_json(url, Some(query)).await
+    }
+
+    pub async fn fetch_distributions(&self) -> Result<Distributions> {
+        let url = format!("{}/distributions", self.base_url);
+        self.get_json(url, None).await
+    }
+
+    pub async fn fetch_explorer(&self, sql: &str) -> Result<ExplorerResult> {
+        let url = format!("{}/explorer", self.base_url);
+        self.get_json(url, Some(vec![("sql", sql.to_string())])).await
     }
 
     pub async fn fetch_match_detail(&self, match_id: u64) -> Result<MatchDetail> {
@@ -92,12 +211,43 @@ impl ApiClient {
         let url = format!("{}/constants/items", self.base_url);
         let raw: HashMap<String, ItemConstant> = self.get_json(url, None).await?;
         Ok(raw
-            .into_values()
-            .filter(|item| item.id != 0)
-            .map(|item| (item.id, item))
+            .into_iter()
+            .filter(|(_, item)| item.id != 0)
+            .map(|(key, mut item)| {
+                item.key = key;
+                (item.id, item)
+            })
             .collect())
     }
 
+    pub async fn fetch_game_constants(&self) -> Result<GameConstants> {
+        let (game_modes, lobby_types, regions, clusters, patches) = tokio::try_join!(
+            self.get_json(format!("{}/constants/game_mode", self.base_url), None),
+            self.get_json(format!("{}/constants/lobby_type", self.base_url), None),
+            self.get_json(format!("{}/constants/region", self.base_url), None),
+            self.get_json(format!("{}/constants/cluster", self.base_url), None),
+            self.get_json::<Vec<PatchConstant>>(format!("{}/constants/patch", self.base_url), None),
+        )?;
+        let patch_dates = patch_dates(&patches);
+        Ok(GameConstants {
+            game_modes,
+            lobby_types,
+            regions,
+            clusters,
+            patches,
+            patch_dates,
+        })
+    }
+
+    pub async fn clear_cache(&self) {
+        self.cache.lock().await.clear();
+    }
+
+    pub async fn invalidate_prefix(&self, path: &str) {
+        let prefix = format!("{}{path}", self.base_url);
+        self.cache.lock().await.remove_prefix(&prefix);
+    }
+
     pub async fn fetch_bytes(&self, url: &str) -> Result<Vec<u8>> {
         let response = self
             .client
@@ -148,6 +298,10 @@ impl ApiClient {
                     if !status.is_success() {
                         let elapsed = started.elapsed().as_millis();
                         self.log_line(format!("GET {} status={} elapsed_ms={}", cache_key, status, elapsed));
+                        if status.is_client_error() && status != reqwest::StatusCode::TOO_MANY_REQUESTS {
+                            let body = resp.bytes().await.unwrap_or_default();
+                            return Err(status_error(status, &body));
+                        }
                         if attempt < 2 {
                             tokio::time::sleep(backoff(attempt)).await;
                             continue;
@@ -208,6 +362,31 @@ fn backoff(attempt: u32) -> Duration {
     }
 }
 
+fn status_error(status: reqwest::StatusCode, body: &[u8]) -> anyhow::Error {
+    let message = serde_json::from_slice::<serde_json::Value>(body).ok().and_then(|value| {
+        ["err", "error"]
+            .into_iter()
+            .find_map(|key| value.get(key)?.as_str().map(str::to_string))
+    });
+    match message {
+        Some(message) => anyhow::anyhow!("HTTP {status}: {message}"),
+        None => anyhow::anyhow!("HTTP {status}"),
+    }
+}
+
+pub(crate) fn patch_dates(patches: &[PatchConstant]) -> Vec<(chrono::DateTime<chrono::Utc>, usize)> {
+    let mut dates: Vec<(chrono::DateTime<chrono::Utc>, usize)> = patches
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, patch)| {
+            let date = chrono::DateTime::parse_from_rfc3339(&patch.date).ok()?;
+            Some((date.to_utc(), idx))
+        })
+        .collect();
+    dates.sort_by_key(|(date, idx)| (*date, patches[*idx].id));
+    dates
+}
+
 fn build_cache_key(url: &str, query: Option<&Vec<(&str, String)>>) -> String {
     if let Some(params) = query {
         let mut pairs: Vec<String> = params
@@ -251,6 +430,16 @@ impl Cache {
         None
     }
 
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+
+    fn remove_prefix(&mut self, prefix: &str) {
+        self.entries.retain(|key, _| !key.starts_with(prefix));
+        self.order.retain(|key| !key.starts_with(prefix));
+    }
+
     fn set(&mut self, key: String, payload: Vec<u8>) {
         if self.entries.contains_key(&key) {
             self.order.retain(|k| k != &key);
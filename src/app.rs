@@ -4,19 +4,485 @@ use std::io::{BufRead, BufReader, Write};
 use std::time::{Duration, Instant};
 
 use anyhow::Result;
+use ratatui::layout::Rect;
 use ratatui::widgets::ListState;
 use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
 
 use crate::api::ApiClient;
-use crate::config::{cache_dir, recent_log_path};
+use crate::config::{cache_dir, favorites_path, modified_at, queries_path, recent_log_path, KeyCombo};
+use crate::editor::TextEditor;
 use crate::i18n::I18n;
-use crate::models::{MatchDetail, PlayerMatch, PlayerResponse};
+use crate::models::{
+    ExplorerResult, GameConstants, HeroDuration, HeroMatchup, HeroStat, HistogramBucket, ItemConstant, ItemPopularity,
+    League, LiveGame, MatchDetail, MatchPlayer, PatchConstant, PlayerCounts, PlayerMatch, PlayerResponse, PlayerTotal,
+    ProMatch, ProPlayer, ProTeam, PublicMatch, RankBin, TeamMatch, TeamPlayer, WardMap, WinLoss, WordCloud,
+};
+
+pub const TAB_COUNT: usize = 10;
+pub const TAB_CHARTS: usize = 3;
+pub const TAB_HEROES: usize = 4;
+pub const TAB_PRO: usize = 5;
+pub const TAB_LIVE: usize = 6;
+pub const TAB_PUBLIC: usize = 7;
+pub const TAB_EXPLORER: usize = 8;
+pub const TAB_COMPARE: usize = 9;
+pub const LIVE_REFRESH_TICKS: u64 = 150;
+pub const HERO_ROLES: [&str; 9] = [
+    "Carry", "Support", "Nuker", "Disabler", "Jungler", "Durable", "Escape", "Pusher", "Initiator",
+];
+pub const HERO_BRACKETS: usize = 8;
+pub const HISTOGRAM_FIELDS: [&str; 12] = [
+    "kills",
+    "deaths",
+    "assists",
+    "kda",
+    "gold_per_min",
+    "xp_per_min",
+    "last_hits",
+    "denies",
+    "duration",
+    "hero_damage",
+    "tower_damage",
+    "hero_healing",
+];
+const CONSTANTS_TTL: Duration = Duration::from_secs(24 * 60 * 60);
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum InputMode {
     Normal,
     Editing,
+    Alias,
+    Compare,
+    Palette,
+    HeroFilter,
+    Sql,
+    QueryName,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PendingCommand {
+    CycleTheme,
+    CycleLanguage,
+    ReloadConfig,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum MatchKind {
+    Ranked,
+    Unranked,
+    Turbo,
+}
+
+impl MatchKind {
+    pub const ALL: [MatchKind; 3] = [MatchKind::Ranked, MatchKind::Unranked, MatchKind::Turbo];
+
+    pub fn of(m: &PlayerMatch) -> MatchKind {
+        if m.game_mode == Some(23) {
+            MatchKind::Turbo
+        } else if matches!(m.lobby_type, Some(5..=7)) {
+            MatchKind::Ranked
+        } else {
+            MatchKind::Unranked
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            MatchKind::Ranked => "ranked",
+            MatchKind::Unranked => "unranked",
+            MatchKind::Turbo => "turbo",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PatchScope {
+    All,
+    Current,
+    Patch(i32),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ChartView {
+    Histogram,
+    Wardmap,
+    Wordcloud,
+}
+
+impl ChartView {
+    pub fn next(self) -> ChartView {
+        match self {
+            ChartView::Histogram => ChartView::Wardmap,
+            ChartView::Wardmap => ChartView::Wordcloud,
+            ChartView::Wordcloud => ChartView::Histogram,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum WardKind {
+    Observer,
+    Sentry,
+}
+
+impl WardKind {
+    pub fn next(self) -> WardKind {
+        match self {
+            WardKind::Observer => WardKind::Sentry,
+            WardKind::Sentry => WardKind::Observer,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            WardKind::Observer => "observer",
+            WardKind::Sentry => "sentry",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum HeroSort {
+    Winrate,
+    Picks,
+    ProPicks,
+    ProBans,
+    Name,
+}
+
+impl HeroSort {
+    pub fn next(self) -> HeroSort {
+        match self {
+            HeroSort::Winrate => HeroSort::Picks,
+            HeroSort::Picks => HeroSort::ProPicks,
+            HeroSort::ProPicks => HeroSort::ProBans,
+            HeroSort::ProBans => HeroSort::Name,
+            HeroSort::Name => HeroSort::Winrate,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            HeroSort::Winrate => "winrate",
+            HeroSort::Picks => "picks",
+            HeroSort::ProPicks => "pro_picks",
+            HeroSort::ProBans => "pro_bans",
+            HeroSort::Name => "name",
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct PublicKey {
+    pub min_rank: Option<usize>,
+    pub max_rank: Option<usize>,
+    pub less_than: Option<u64>,
+}
+
+impl PublicKey {
+    pub fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(medal) = self.min_rank {
+            query.push(("min_rank", (medal * 10).to_string()));
+        }
+        if let Some(medal) = self.max_rank {
+            query.push(("max_rank", (medal * 10 + 9).to_string()));
+        }
+        if let Some(match_id) = self.less_than {
+            query.push(("less_than_match_id", match_id.to_string()));
+        }
+        query
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ProView {
+    Matches,
+    Players,
+    Teams,
+    Leagues,
+}
+
+impl ProView {
+    pub fn next(self) -> ProView {
+        match self {
+            ProView::Matches => ProView::Players,
+            ProView::Players => ProView::Teams,
+            ProView::Teams => ProView::Leagues,
+            ProView::Leagues => ProView::Matches,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ProView::Matches => "matches",
+            ProView::Players => "players",
+            ProView::Teams => "teams",
+            ProView::Leagues => "leagues",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProPage {
+    Team(i64),
+    League(i32),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProRequest {
+    Matches,
+    Players,
+    Teams,
+    Leagues,
+    Page(ProPage),
+}
+
+pub enum ProData {
+    Matches(Vec<ProMatch>),
+    Players(Vec<ProPlayer>),
+    Teams(Vec<ProTeam>),
+    Leagues(Vec<League>),
+    Team(TeamDetail),
+    League(LeagueDetail),
+}
+
+pub struct TeamDetail {
+    pub team_id: i64,
+    pub matches: Vec<TeamMatch>,
+    pub players: Vec<TeamPlayer>,
+}
+
+pub struct LeagueDetail {
+    pub league_id: i32,
+    pub matches: Vec<ProMatch>,
+}
+
+#[derive(Default)]
+pub struct ProScene {
+    pub matches: Option<Vec<ProMatch>>,
+    pub players: Option<Vec<ProPlayer>>,
+    pub player_index: HashMap<u32, usize>,
+    pub teams: Option<Vec<ProTeam>>,
+    pub leagues: Option<Vec<League>>,
+    pub team: Option<TeamDetail>,
+    pub league: Option<LeagueDetail>,
+    pub requested: HashSet<ProRequest>,
+}
+
+impl ProScene {
+    pub fn player(&self, account_id: u32) -> Option<&ProPlayer> {
+        self.player_index
+            .get(&account_id)
+            .and_then(|idx| self.players.as_ref()?.get(*idx))
+    }
+
+    pub fn team(&self, team_id: i64) -> Option<&TeamDetail> {
+        self.team.as_ref().filter(|team| team.team_id == team_id)
+    }
+
+    pub fn league(&self, league_id: i32) -> Option<&LeagueDetail> {
+        self.league.as_ref().filter(|league| league.league_id == league_id)
+    }
+
+    fn has(&self, request: ProRequest) -> bool {
+        match request {
+            ProRequest::Matches => self.matches.is_some(),
+            ProRequest::Players => self.players.is_some(),
+            ProRequest::Teams => self.teams.is_some(),
+            ProRequest::Leagues => self.leagues.is_some(),
+            ProRequest::Page(ProPage::Team(team_id)) => self.team(team_id).is_some(),
+            ProRequest::Page(ProPage::League(league_id)) => self.league(league_id).is_some(),
+        }
+    }
+
+    fn reset(&mut self, request: ProRequest) {
+        self.requested.remove(&request);
+        match request {
+            ProRequest::Matches => self.matches = None,
+            ProRequest::Players => {
+                self.players = None;
+                self.player_index.clear();
+            }
+            ProRequest::Teams => self.teams = None,
+            ProRequest::Leagues => self.leagues = None,
+            ProRequest::Page(ProPage::Team(_)) => self.team = None,
+            ProRequest::Page(ProPage::League(_)) => self.league = None,
+        }
+    }
+
+    fn store(&mut self, data: ProData) {
+        match data {
+            ProData::Matches(matches) => self.matches = Some(matches),
+            ProData::Players(mut players) => {
+                players.sort_by_cached_key(|player| {
+                    (
+                        player.team_name.is_none(),
+                        player.team_name.as_deref().unwrap_or_default().to_lowercase(),
+                        player.name.as_deref().unwrap_or_default().to_lowercase(),
+                    )
+                });
+                self.player_index = players
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, player)| (player.account_id, idx))
+                    .collect();
+                self.players = Some(players);
+            }
+            ProData::Teams(mut teams) => {
+                teams.sort_by(|a, b| b.rating.unwrap_or_default().total_cmp(&a.rating.unwrap_or_default()));
+                self.teams = Some(teams);
+            }
+            ProData::Leagues(mut leagues) => {
+                leagues.sort_by_key(|league| std::cmp::Reverse(league.leagueid));
+                self.leagues = Some(leagues);
+            }
+            ProData::Team(mut team) => {
+                team.players.sort_by_key(|player| {
+                    (player.is_current_team_member != Some(true), std::cmp::Reverse(player.games_played))
+                });
+                self.team = Some(team);
+            }
+            ProData::League(league) => self.league = Some(league),
+        }
+    }
+}
+
+pub struct HeroDetail {
+    pub hero_id: i32,
+    pub matchups: Vec<HeroMatchup>,
+    pub items: ItemPopularity,
+    pub durations: Vec<HeroDuration>,
+}
+
+pub fn bracket_records(hero: &HeroStat) -> [(i64, i64); HERO_BRACKETS] {
+    [
+        (hero.pick_1, hero.win_1),
+        (hero.pick_2, hero.win_2),
+        (hero.pick_3, hero.win_3),
+        (hero.pick_4, hero.win_4),
+        (hero.pick_5, hero.win_5),
+        (hero.pick_6, hero.win_6),
+        (hero.pick_7, hero.win_7),
+        (hero.pick_8, hero.win_8),
+    ]
+}
+
+pub fn bracket_record(hero: &HeroStat, bracket: Option<usize>) -> (i64, i64) {
+    let records = bracket_records(hero);
+    match bracket {
+        Some(bracket) => records[(bracket.clamp(1, HERO_BRACKETS)) - 1],
+        None => records
+            .iter()
+            .fold((0, 0), |(picks, wins), (p, w)| (picks + p, wins + w)),
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct ChartKey {
+    pub view: ChartView,
+    pub account_id: u32,
+    pub field: &'static str,
+    pub query: Vec<(&'static str, String)>,
+}
+
+pub struct Histogram {
+    pub key: ChartKey,
+    pub buckets: Vec<HistogramBucket>,
+}
+
+pub struct Wardmap {
+    pub key: ChartKey,
+    pub wards: WardMap,
+}
+
+pub struct Wordcloud {
+    pub key: ChartKey,
+    pub words: WordCloud,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SearchTarget {
+    Primary,
+    Compare(usize),
+}
+
+pub struct LoadedPlayer {
+    pub account_id: u32,
+    pub profile: Option<PlayerResponse>,
+    pub matches: Vec<PlayerMatch>,
+    pub loading: bool,
+    pub error: Option<String>,
+}
+
+impl LoadedPlayer {
+    pub fn pending(account_id: u32) -> Self {
+        Self {
+            account_id,
+            profile: None,
+            matches: Vec::new(),
+            loading: true,
+            error: None,
+        }
+    }
+
+    pub fn personaname(&self) -> Option<&str> {
+        self.profile
+            .as_ref()
+            .and_then(|p| p.profile.as_ref())
+            .and_then(|p| p.personaname.as_deref())
+    }
+
+    pub fn avatar_url(&self) -> Option<&str> {
+        self.profile
+            .as_ref()
+            .and_then(|p| p.profile.as_ref())
+            .and_then(|p| {
+                p.avatarfull
+                    .as_deref()
+                    .or(p.avatarmedium.as_deref())
+                    .or(p.avatar.as_deref())
+            })
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SavedQuery {
+    pub name: String,
+    pub sql: String,
+}
+
+pub struct ExplorerTable {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl ExplorerTable {
+    fn from_result(result: ExplorerResult) -> Self {
+        let mut columns: Vec<String> = result.fields.into_iter().map(|field| field.name).collect();
+        if columns.is_empty()
+            && let Some(first) = result.rows.first()
+        {
+            columns = first.keys().cloned().collect();
+            columns.sort();
+        }
+        let rows = result
+            .rows
+            .iter()
+            .map(|row| {
+                columns
+                    .iter()
+                    .map(|column| match row.get(column) {
+                        None | Some(serde_json::Value::Null) => String::new(),
+                        Some(serde_json::Value::String(value)) => value.clone(),
+                        Some(value) => value.to_string(),
+                    })
+                    .collect()
+            })
+            .collect();
+        Self { columns, rows }
+    }
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -26,6 +492,66 @@ pub struct SearchEntry {
     pub avatar_url: Option<String>,
 }
 
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FavoriteEntry {
+    pub account_id: u32,
+    pub personaname: String,
+    #[serde(default)]
+    pub alias: Option<String>,
+    pub avatar_url: Option<String>,
+}
+
+impl FavoriteEntry {
+    pub fn label(&self) -> String {
+        match self.alias.as_deref() {
+            Some(alias) => format!("{alias} · {} ({})", self.personaname, self.account_id),
+            None => format!("{} ({})", self.personaname, self.account_id),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct ListHit {
+    pub area: Rect,
+    pub first_row_y: u16,
+    pub row_height: u16,
+    pub offset: usize,
+    pub len: usize,
+}
+
+impl ListHit {
+    pub fn contains(&self, x: u16, y: u16) -> bool {
+        x >= self.area.x
+            && x < self.area.x + self.area.width
+            && y >= self.area.y
+            && y < self.area.y + self.area.height
+    }
+
+    pub fn row_at(&self, x: u16, y: u16) -> Option<usize> {
+        if !self.contains(x, y) || y < self.first_row_y || self.row_height == 0 {
+            return None;
+        }
+        let idx = self.offset + usize::from((y - self.first_row_y) / self.row_height);
+        (idx < self.len).then_some(idx)
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct HitAreas {
+    pub tabs: Vec<(Rect, usize)>,
+    pub matches: Option<ListHit>,
+    pub recent: Option<ListHit>,
+    pub favorites: Option<ListHit>,
+    pub radiant: Option<ListHit>,
+    pub dire: Option<ListHit>,
+    pub chat: Option<ListHit>,
+    pub heroes: Option<ListHit>,
+    pub pro: Option<ListHit>,
+    pub live: Option<ListHit>,
+    pub public: Option<ListHit>,
+    pub explorer: Option<ListHit>,
+}
+
 pub struct App {
     pub input: String,
     pub input_mode: InputMode,
@@ -34,12 +560,74 @@ pub struct App {
     pub account_id: Option<u32>,
     pub profile: Option<PlayerResponse>,
     pub matches: Vec<PlayerMatch>,
+    pub all_matches: Vec<PlayerMatch>,
+    pub hero_filter: String,
+    pub kind_filter: Option<MatchKind>,
+    pub region_filter: Option<i32>,
+    pub stats_patch: PatchScope,
+    pub aggregates: Option<PlayerAggregates>,
+    pub chart_view: ChartView,
+    pub chart_field: usize,
+    pub ward_kind: WardKind,
+    pub histogram: Option<Histogram>,
+    pub wardmap: Option<Wardmap>,
+    pub wordcloud: Option<Wordcloud>,
+    pub chat_scroll: u16,
+    pub show_laning: bool,
+    pub hero_sort: HeroSort,
+    pub hero_role: Option<usize>,
+    pub hero_bracket: Option<usize>,
+    pub hero_index: usize,
+    pub hero_page: Option<i32>,
+    pub hero_detail: Option<HeroDetail>,
+    pub hero_requested: Option<i32>,
+    pub pro: ProScene,
+    pub pro_view: ProView,
+    pub pro_index: usize,
+    pub pro_page: Option<ProPage>,
+    pub pro_page_index: usize,
+    pub live: Option<Vec<LiveGame>>,
+    pub live_index: usize,
+    pub live_loading: bool,
+    pub live_stale: bool,
+    pub live_updated: Option<Instant>,
+    pub live_tracked: Vec<u32>,
+    pub public_matches: Option<Vec<PublicMatch>>,
+    pub public_index: usize,
+    pub public_min_rank: Option<usize>,
+    pub public_max_rank: Option<usize>,
+    pub public_requested: Option<PublicKey>,
+    pub public_exhausted: bool,
+    pub distribution: Option<Vec<RankBin>>,
+    pub distribution_requested: bool,
+    pub explorer: TextEditor,
+    pub explorer_result: Option<ExplorerTable>,
+    pub explorer_loading: bool,
+    pub explorer_index: usize,
+    pub explorer_column: usize,
+    pub saved_queries: Vec<SavedQuery>,
+    pub query_index: Option<usize>,
+    pub query_name_input: String,
+    pub chart_requested: Option<ChartKey>,
     pub match_state: ListState,
     pub match_detail: Option<MatchDetail>,
     pub recent_searches: Vec<SearchEntry>,
     pub recent_index: Option<usize>,
     pub recent_active: bool,
-    pub heroes: HashMap<i32, String>,
+    pub favorites: Vec<FavoriteEntry>,
+    pub favorite_index: Option<usize>,
+    pub favorite_active: bool,
+    pub alias_input: String,
+    pub compare_players: Vec<LoadedPlayer>,
+    pub palette_query: String,
+    pub palette_index: usize,
+    pub pending_commands: Vec<PendingCommand>,
+    pub hit_areas: HitAreas,
+    pub last_click: Option<(Instant, u16, u16)>,
+    pub team_selection: Option<(bool, usize)>,
+    pub heroes: HashMap<i32, HeroStat>,
+    pub items: HashMap<i32, ItemConstant>,
+    pub constants: GameConstants,
     pub hero_images: HashMap<i32, String>,
     pub item_images: HashMap<i32, String>,
     pub image_cache: HashMap<String, Vec<u8>>,
@@ -56,11 +644,14 @@ pub struct App {
     pub tab_index: usize,
     pub tick: u64,
     pub show_help: bool,
+    pub help_scroll: u16,
     pub banner_shimmer: u8,
     pub requested_hero_images: bool,
     pub requested_item_images: bool,
     pub image_reset: bool,
     pub last_nav: Instant,
+    pub pending_keys: Vec<KeyCombo>,
+    pub last_key: Instant,
     pub net_total: usize,
     pub net_done: usize,
     pub net_inflight: usize,
@@ -79,12 +670,74 @@ impl App {
             account_id: None,
             profile: None,
             matches: Vec::new(),
+            all_matches: Vec::new(),
+            hero_filter: String::new(),
+            kind_filter: None,
+            region_filter: None,
+            stats_patch: PatchScope::All,
+            aggregates: None,
+            chart_view: ChartView::Histogram,
+            chart_field: 0,
+            ward_kind: WardKind::Observer,
+            histogram: None,
+            wardmap: None,
+            wordcloud: None,
+            chat_scroll: 0,
+            show_laning: false,
+            hero_sort: HeroSort::Winrate,
+            hero_role: None,
+            hero_bracket: None,
+            hero_index: 0,
+            hero_page: None,
+            hero_detail: None,
+            hero_requested: None,
+            pro: ProScene::default(),
+            pro_view: ProView::Matches,
+            pro_index: 0,
+            pro_page: None,
+            pro_page_index: 0,
+            live: None,
+            live_index: 0,
+            live_loading: false,
+            live_stale: false,
+            live_updated: None,
+            live_tracked: Vec::new(),
+            public_matches: None,
+            public_index: 0,
+            public_min_rank: None,
+            public_max_rank: None,
+            public_requested: None,
+            public_exhausted: false,
+            distribution: None,
+            distribution_requested: false,
+            explorer: TextEditor::default(),
+            explorer_result: None,
+            explorer_loading: false,
+            explorer_index: 0,
+            explorer_column: 0,
+            saved_queries: Vec::new(),
+            query_index: None,
+            query_name_input: String::new(),
+            chart_requested: None,
             match_state: state,
             match_detail: None,
             recent_searches: Vec::new(),
             recent_index: None,
             recent_active: false,
+            favorites: Vec::new(),
+            favorite_index: None,
+            favorite_active: false,
+            alias_input: String::new(),
+            compare_players: Vec::new(),
+            palette_query: String::new(),
+            palette_index: 0,
+            pending_commands: Vec::new(),
+            hit_areas: HitAreas::default(),
+            last_click: None,
+            team_selection: None,
             heroes: HashMap::new(),
+            items: HashMap::new(),
+            constants: GameConstants::default(),
             hero_images: HashMap::new(),
             item_images: HashMap::new(),
             image_cache: HashMap::new(),
@@ -101,11 +754,14 @@ impl App {
             tab_index: 0,
             tick: 0,
             show_help: false,
+            help_scroll: 0,
             banner_shimmer: 24,
             requested_hero_images: false,
             requested_item_images: false,
             image_reset: false,
             last_nav: Instant::now().checked_sub(Duration::from_secs(1)).unwrap_or_else(Instant::now),
+            pending_keys: Vec::new(),
+            last_key: Instant::now(),
             net_total: 0,
             net_done: 0,
             net_inflight: 0,
@@ -113,6 +769,10 @@ impl App {
         }
     }
 
+    pub fn overlay_open(&self) -> bool {
+        self.show_help || self.input_mode == InputMode::Palette
+    }
+
     pub fn selected_match(&self) -> Option<&PlayerMatch> {
         self.match_state
             .selected()
@@ -122,26 +782,568 @@ impl App {
     pub fn hero_name(&self, hero_id: i32, i18n: &I18n) -> String {
         self.heroes
             .get(&hero_id)
-            .map(|s| s.as_str())
+            .map(|hero| i18n.hero_name(hero))
             .unwrap_or(i18n.unknown())
             .to_string()
     }
 
+    pub fn item_name(&self, item_id: i32, i18n: &I18n) -> Option<String> {
+        self.items
+            .get(&item_id)
+            .map(|item| i18n.item_name(item).to_string())
+    }
+
+    pub fn game_mode_name(&self, game_mode: Option<i32>, i18n: &I18n) -> String {
+        let Some(id) = game_mode else {
+            return i18n.placeholder_dash().to_string();
+        };
+        self.constants
+            .game_modes
+            .get(&id)
+            .map(|mode| i18n.game_mode(&mode.name))
+            .unwrap_or_else(|| id.to_string())
+    }
+
+    pub fn lobby_type_name(&self, lobby_type: Option<i32>, i18n: &I18n) -> String {
+        let Some(id) = lobby_type else {
+            return i18n.placeholder_dash().to_string();
+        };
+        self.constants
+            .lobby_types
+            .get(&id)
+            .map(|lobby| i18n.lobby_type(&lobby.name))
+            .unwrap_or_else(|| id.to_string())
+    }
+
+    pub fn match_region(&self, m: &PlayerMatch) -> Option<i32> {
+        m.cluster
+            .and_then(|cluster| self.constants.clusters.get(&cluster))
+            .copied()
+    }
+
+    pub fn region_name(&self, region: Option<i32>, i18n: &I18n) -> String {
+        let Some(id) = region else {
+            return i18n.placeholder_dash().to_string();
+        };
+        i18n.region(id, self.constants.regions.get(&id).map(String::as_str))
+    }
+
+    pub fn patch_at(&self, start_time: Option<i64>) -> Option<&PatchConstant> {
+        let start_time = start_time?;
+        let dates = &self.constants.patch_dates;
+        let count = dates.partition_point(|(date, _)| date.timestamp() <= start_time);
+        let (_, idx) = dates.get(count.checked_sub(1)?)?;
+        self.constants.patches.get(*idx)
+    }
+
+    pub fn match_patch(&self, m: &PlayerMatch) -> Option<&PatchConstant> {
+        self.patch_at(m.start_time)
+    }
+
+    pub fn detail_patch(&self, detail: &MatchDetail) -> Option<&PatchConstant> {
+        detail
+            .patch
+            .and_then(|id| self.constants.patches.iter().find(|patch| patch.id == id))
+            .or_else(|| self.patch_at(detail.start_time))
+    }
+
+    pub fn current_patch(&self) -> Option<&PatchConstant> {
+        self.constants.patches.iter().max_by_key(|patch| patch.id)
+    }
+
+    pub fn match_patches(&self) -> Vec<i32> {
+        let mut patches: Vec<i32> = self
+            .all_matches
+            .iter()
+            .filter_map(|m| self.match_patch(m).map(|patch| patch.id))
+            .collect();
+        patches.sort_unstable_by(|a, b| b.cmp(a));
+        patches.dedup();
+        patches
+    }
+
+    pub fn stats_patch_id(&self) -> Option<i32> {
+        match self.stats_patch {
+            PatchScope::All => None,
+            PatchScope::Current => self.current_patch().map(|patch| patch.id),
+            PatchScope::Patch(id) => Some(id),
+        }
+    }
+
+    pub fn stats_matches(&self) -> Vec<PlayerMatch> {
+        let scope = self.stats_patch_id();
+        self.matches
+            .iter()
+            .filter(|m| scope.is_none_or(|id| self.match_patch(m).is_some_and(|patch| patch.id == id)))
+            .cloned()
+            .collect()
+    }
+
+    pub fn stats_patch_label(&self, i18n: &I18n) -> String {
+        let name = |id: i32| {
+            self.constants
+                .patches
+                .iter()
+                .find(|patch| patch.id == id)
+                .map(|patch| patch.name.clone())
+                .unwrap_or_else(|| id.to_string())
+        };
+        match self.stats_patch {
+            PatchScope::All => i18n.patch_all().to_string(),
+            PatchScope::Current => match self.current_patch() {
+                Some(patch) => i18n.patch_current(&patch.name),
+                None => i18n.patch_all().to_string(),
+            },
+            PatchScope::Patch(id) => i18n.patch_named(&name(id)),
+        }
+    }
+
+    pub fn cycle_stats_patch(&mut self) {
+        let current = self.current_patch().map(|patch| patch.id);
+        let others: Vec<i32> = self
+            .match_patches()
+            .into_iter()
+            .filter(|id| Some(*id) != current)
+            .collect();
+        self.stats_patch = match self.stats_patch {
+            PatchScope::All if current.is_some() => PatchScope::Current,
+            PatchScope::All | PatchScope::Current => {
+                others.first().map_or(PatchScope::All, |id| PatchScope::Patch(*id))
+            }
+            PatchScope::Patch(id) => others
+                .iter()
+                .position(|candidate| *candidate == id)
+                .and_then(|index| others.get(index + 1))
+                .map_or(PatchScope::All, |id| PatchScope::Patch(*id)),
+        };
+    }
+
+    // OpenDota can't exclude Turbo from lobby_type 0, so Unranked only filters the match list.
+    pub fn chart_kind_filter(&self) -> Option<MatchKind> {
+        self.kind_filter.filter(|kind| *kind != MatchKind::Unranked)
+    }
+
+    pub fn match_query(&self, i18n: &I18n) -> Vec<(&'static str, String)> {
+        let mut query = vec![("significant", "0".to_string())];
+        let hero = self.hero_filter.trim();
+        if !hero.is_empty() {
+            let mut ids: Vec<i32> = self
+                .heroes
+                .values()
+                .filter(|stat| i18n.hero_matches(stat, hero))
+                .map(|stat| stat.id)
+                .collect();
+            ids.sort_unstable();
+            if ids.is_empty() {
+                ids.push(0);
+            }
+            query.extend(ids.into_iter().map(|id| ("hero_id", id.to_string())));
+        }
+        match self.chart_kind_filter() {
+            Some(MatchKind::Ranked) => {
+                query.extend((5..=7).map(|id: i32| ("lobby_type", id.to_string())));
+            }
+            Some(MatchKind::Turbo) => query.push(("game_mode", "23".to_string())),
+            Some(MatchKind::Unranked) | None => {}
+        }
+        if let Some(region) = self.region_filter {
+            query.push(("region", region.to_string()));
+        }
+        if let Some(patch) = self.stats_patch_id() {
+            query.push(("patch", patch.to_string()));
+        }
+        query
+    }
+
+    pub fn hero_rows(&self, i18n: &I18n) -> Vec<&HeroStat> {
+        let role = self.hero_role.and_then(|idx| HERO_ROLES.get(idx));
+        let mut rows: Vec<&HeroStat> = self
+            .heroes
+            .values()
+            .filter(|hero| role.is_none_or(|role| hero.roles.iter().any(|r| r == role)))
+            .collect();
+        let winrate = |hero: &HeroStat| {
+            let (picks, wins) = bracket_record(hero, self.hero_bracket);
+            wins as f64 / picks.max(1) as f64
+        };
+        match self.hero_sort {
+            HeroSort::Winrate => rows.sort_by(|a, b| winrate(b).total_cmp(&winrate(a))),
+            HeroSort::Picks => rows.sort_by_key(|hero| std::cmp::Reverse(bracket_record(hero, self.hero_bracket).0)),
+            HeroSort::ProPicks => rows.sort_by_key(|hero| std::cmp::Reverse(hero.pro_pick)),
+            HeroSort::ProBans => rows.sort_by_key(|hero| std::cmp::Reverse(hero.pro_ban)),
+            HeroSort::Name => rows.sort_by_cached_key(|hero| i18n.hero_name(hero).to_lowercase()),
+        }
+        rows
+    }
+
+    fn pro_view_request(&self) -> ProRequest {
+        match self.pro_page {
+            Some(page) => ProRequest::Page(page),
+            None => match self.pro_view {
+                ProView::Matches => ProRequest::Matches,
+                ProView::Players => ProRequest::Players,
+                ProView::Teams => ProRequest::Teams,
+                ProView::Leagues => ProRequest::Leagues,
+            },
+        }
+    }
+
+    pub fn refresh_pro(&mut self) {
+        let request = self.pro_view_request();
+        self.pro.reset(request);
+    }
+
+    pub fn pro_request(&mut self) -> Option<ProRequest> {
+        let request = if self.tab_index == TAB_PRO {
+            self.pro_view_request()
+        } else if self.match_detail.is_some() || self.tab_index == TAB_LIVE {
+            ProRequest::Players
+        } else {
+            return None;
+        };
+        if self.pro.has(request) || self.pro.requested.contains(&request) {
+            return None;
+        }
+        if matches!(request, ProRequest::Page(_)) {
+            self.pro.requested.retain(|request| !matches!(request, ProRequest::Page(_)));
+        }
+        self.pro.requested.insert(request);
+        Some(request)
+    }
+
+    pub fn pro_list_len(&self) -> usize {
+        match self.pro_page {
+            Some(ProPage::Team(team_id)) => self.pro.team(team_id).map_or(0, |team| team.matches.len()),
+            Some(ProPage::League(league_id)) => self.pro.league(league_id).map_or(0, |league| league.matches.len()),
+            None => match self.pro_view {
+                ProView::Matches => self.pro.matches.as_ref().map_or(0, Vec::len),
+                ProView::Players => self.pro.players.as_ref().map_or(0, Vec::len),
+                ProView::Teams => self.pro.teams.as_ref().map_or(0, Vec::len),
+                ProView::Leagues => self.pro.leagues.as_ref().map_or(0, Vec::len),
+            },
+        }
+    }
+
+    pub fn pro_label(&self, account_id: u32) -> Option<String> {
+        let player = self.pro.player(account_id)?;
+        let name = player.name.as_deref()?;
+        Some(match player.team_tag.as_deref().filter(|tag| !tag.is_empty()) {
+            Some(tag) => format!("{tag}.{name}"),
+            None => name.to_string(),
+        })
+    }
+
+    pub fn live_request(&mut self) -> bool {
+        if self.tab_index != TAB_LIVE || self.live_loading || (self.live.is_some() && !self.live_stale) {
+            return false;
+        }
+        self.live_loading = true;
+        self.live_stale = false;
+        true
+    }
+
+    pub fn public_request(&mut self) -> Option<PublicKey> {
+        if self.tab_index != TAB_PUBLIC {
+            return None;
+        }
+        let less_than = match &self.public_matches {
+            None => None,
+            Some(matches) if !self.public_exhausted && self.public_index + 1 >= matches.len() => {
+                Some(matches.last()?.match_id)
+            }
+            Some(_) => return None,
+        };
+        let key = PublicKey {
+            min_rank: self.public_min_rank,
+            max_rank: self.public_max_rank,
+            less_than,
+        };
+        if self.public_requested.as_ref() == Some(&key) {
+            return None;
+        }
+        self.public_requested = Some(key.clone());
+        Some(key)
+    }
+
+    pub fn reset_public_matches(&mut self) {
+        self.public_matches = None;
+        self.public_index = 0;
+        self.public_requested = None;
+        self.public_exhausted = false;
+    }
+
+    pub fn distribution_request(&mut self) -> bool {
+        if self.tab_index != TAB_PUBLIC || self.distribution.is_some() || self.distribution_requested {
+            return false;
+        }
+        self.distribution_requested = true;
+        true
+    }
+
+    pub fn is_tracked(&self, account_id: u32) -> bool {
+        self.account_id == Some(account_id) || self.favorites.iter().any(|entry| entry.account_id == account_id)
+    }
+
+    pub fn tracked_label(&self, account_id: u32) -> Option<String> {
+        if let Some(entry) = self.favorites.iter().find(|entry| entry.account_id == account_id) {
+            return Some(entry.alias.clone().unwrap_or_else(|| entry.personaname.clone()));
+        }
+        self.profile
+            .as_ref()
+            .filter(|_| self.account_id == Some(account_id))
+            .and_then(|profile| profile.profile.as_ref())
+            .and_then(|profile| profile.personaname.clone())
+    }
+
+    pub fn selected_hero(&self, i18n: &I18n) -> Option<i32> {
+        let rows = self.hero_rows(i18n);
+        rows.get(self.hero_index.min(rows.len().saturating_sub(1)))
+            .map(|hero| hero.id)
+    }
+
+    pub fn hero_request(&mut self) -> Option<i32> {
+        let hero_id = self.hero_page?;
+        if self.hero_requested == Some(hero_id) {
+            return None;
+        }
+        self.hero_requested = Some(hero_id);
+        Some(hero_id)
+    }
+
+    pub fn current_hero_detail(&self) -> Option<&HeroDetail> {
+        self.hero_detail
+            .as_ref()
+            .filter(|detail| self.hero_page == Some(detail.hero_id))
+    }
+
+    pub fn chart_field(&self) -> &'static str {
+        HISTOGRAM_FIELDS[self.chart_field % HISTOGRAM_FIELDS.len()]
+    }
+
+    pub fn chart_key(&self, i18n: &I18n) -> Option<ChartKey> {
+        let field = match self.chart_view {
+            ChartView::Histogram => self.chart_field(),
+            ChartView::Wardmap => "wardmap",
+            ChartView::Wordcloud => "wordcloud",
+        };
+        Some(ChartKey {
+            view: self.chart_view,
+            account_id: self.account_id?,
+            field,
+            query: self.match_query(i18n),
+        })
+    }
+
+    pub fn chart_request(&mut self, i18n: &I18n) -> Option<ChartKey> {
+        if self.loading || self.input_mode == InputMode::HeroFilter {
+            return None;
+        }
+        let key = self.chart_key(i18n)?;
+        if self.chart_requested.as_ref() == Some(&key) {
+            return None;
+        }
+        self.chart_requested = Some(key.clone());
+        Some(key)
+    }
+
+    pub fn current_histogram(&self) -> Option<&Histogram> {
+        self.histogram
+            .as_ref()
+            .filter(|histogram| self.chart_requested.as_ref() == Some(&histogram.key))
+    }
+
+    pub fn current_wardmap(&self) -> Option<&Wardmap> {
+        self.wardmap
+            .as_ref()
+            .filter(|wardmap| self.chart_requested.as_ref() == Some(&wardmap.key))
+    }
+
+    pub fn current_wordcloud(&self) -> Option<&Wordcloud> {
+        self.wordcloud
+            .as_ref()
+            .filter(|wordcloud| self.chart_requested.as_ref() == Some(&wordcloud.key))
+    }
+
+    pub fn match_regions(&self) -> Vec<i32> {
+        let mut regions: Vec<i32> = self
+            .all_matches
+            .iter()
+            .filter_map(|m| self.match_region(m))
+            .collect();
+        regions.sort_unstable();
+        regions.dedup();
+        regions
+    }
+
+    pub fn has_match_filter(&self) -> bool {
+        !self.hero_filter.trim().is_empty() || self.kind_filter.is_some() || self.region_filter.is_some()
+    }
+
+    pub fn apply_match_filter(&mut self, i18n: &I18n) {
+        let query = self.hero_filter.trim();
+        self.matches = self
+            .all_matches
+            .iter()
+            .filter(|m| {
+                query.is_empty()
+                    || self
+                        .heroes
+                        .get(&m.hero_id)
+                        .is_some_and(|hero| i18n.hero_matches(hero, query))
+            })
+            .filter(|m| self.kind_filter.is_none_or(|kind| MatchKind::of(m) == kind))
+            .filter(|m| self.region_filter.is_none_or(|region| self.match_region(m) == Some(region)))
+            .cloned()
+            .collect();
+        let selected = self.match_state.selected().unwrap_or(0);
+        self.match_state.select(if self.matches.is_empty() {
+            None
+        } else {
+            Some(selected.min(self.matches.len() - 1))
+        });
+    }
+
     pub fn set_status(&mut self, msg: impl Into<String>) {
         self.status = msg.into();
     }
 
-    pub fn advance_tick(&mut self) {
-        self.tick = self.tick.wrapping_add(1);
-        if self.banner_shimmer > 0 {
-            self.banner_shimmer = self.banner_shimmer.saturating_sub(1);
-        }
+    pub fn advance_tick(&mut self) {
+        self.tick = self.tick.wrapping_add(1);
+        if self.banner_shimmer > 0 {
+            self.banner_shimmer = self.banner_shimmer.saturating_sub(1);
+        }
+    }
+
+    pub fn clear_matches(&mut self) {
+        self.matches.clear();
+        self.all_matches.clear();
+        self.match_state.select(Some(0));
+        self.match_detail = None;
+        self.team_selection = None;
+    }
+
+    pub fn selected_team_player(&self) -> Option<&MatchPlayer> {
+        let (radiant, idx) = self.team_selection?;
+        self.team_players(radiant).get(idx).copied()
+    }
+
+    pub fn team_players(&self, radiant: bool) -> Vec<&MatchPlayer> {
+        self.match_detail
+            .as_ref()
+            .map(|detail| {
+                detail
+                    .players
+                    .iter()
+                    .filter(|p| p.player_slot.map(|s| s < 128).unwrap_or(false) == radiant)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn selected_favorite(&self) -> Option<&FavoriteEntry> {
+        if !self.favorite_active {
+            return None;
+        }
+        self.favorite_index.and_then(|idx| self.favorites.get(idx))
+    }
+
+    pub fn toggle_favorite(&mut self, entry: FavoriteEntry) -> bool {
+        if let Some(pos) = self
+            .favorites
+            .iter()
+            .position(|item| item.account_id == entry.account_id)
+        {
+            self.favorites.remove(pos);
+            self.clamp_favorite_index();
+            save_favorites(&self.favorites);
+            return false;
+        }
+        self.favorites.push(entry);
+        save_favorites(&self.favorites);
+        true
+    }
+
+    pub fn remove_selected_favorite(&mut self) -> Option<FavoriteEntry> {
+        let idx = self.favorite_index.filter(|_| self.favorite_active)?;
+        if idx >= self.favorites.len() {
+            return None;
+        }
+        let removed = self.favorites.remove(idx);
+        self.clamp_favorite_index();
+        save_favorites(&self.favorites);
+        Some(removed)
+    }
+
+    pub fn move_selected_favorite(&mut self, up: bool) {
+        let Some(idx) = self.favorite_index.filter(|_| self.favorite_active) else {
+            return;
+        };
+        let target = if up {
+            idx.checked_sub(1)
+        } else {
+            Some(idx + 1).filter(|next| *next < self.favorites.len())
+        };
+        if let Some(target) = target {
+            self.favorites.swap(idx, target);
+            self.favorite_index = Some(target);
+            save_favorites(&self.favorites);
+        }
+    }
+
+    pub fn set_selected_favorite_alias(&mut self, alias: &str) -> bool {
+        let Some(idx) = self.favorite_index.filter(|_| self.favorite_active) else {
+            return false;
+        };
+        let Some(entry) = self.favorites.get_mut(idx) else {
+            return false;
+        };
+        let alias = alias.trim();
+        entry.alias = if alias.is_empty() {
+            None
+        } else {
+            Some(alias.to_string())
+        };
+        save_favorites(&self.favorites);
+        true
+    }
+
+    pub fn save_query(&mut self, name: &str) -> bool {
+        let name = name.trim();
+        if name.is_empty() {
+            return false;
+        }
+        let query = SavedQuery {
+            name: name.to_string(),
+            sql: self.explorer.text.clone(),
+        };
+        match self.saved_queries.iter().position(|saved| saved.name == name) {
+            Some(idx) => {
+                self.saved_queries[idx] = query;
+                self.query_index = Some(idx);
+            }
+            None => {
+                self.saved_queries.push(query);
+                self.query_index = Some(self.saved_queries.len() - 1);
+            }
+        }
+        save_saved_queries(&self.saved_queries);
+        true
+    }
+
+    pub fn remove_selected_query(&mut self) -> Option<SavedQuery> {
+        let idx = self.query_index.take().filter(|idx| *idx < self.saved_queries.len())?;
+        let removed = self.saved_queries.remove(idx);
+        save_saved_queries(&self.saved_queries);
+        Some(removed)
     }
 
-    pub fn clear_matches(&mut self) {
-        self.matches.clear();
-        self.match_state.select(Some(0));
-        self.match_detail = None;
+    fn clamp_favorite_index(&mut self) {
+        if self.favorites.is_empty() {
+            self.favorite_index = None;
+            self.favorite_active = false;
+        } else if let Some(idx) = self.favorite_index {
+            self.favorite_index = Some(idx.min(self.favorites.len() - 1));
+        }
     }
 
     pub fn cache_image(&mut self, url: String, bytes: Vec<u8>) {
@@ -159,10 +1361,22 @@ impl App {
 }
 
 pub enum Message {
-    HeroesLoaded(Result<HashMap<i32, String>>),
+    HeroesLoaded(Result<HashMap<i32, HeroStat>>),
     HeroImagesLoaded(Result<HashMap<i32, String>>),
+    ItemsLoaded(HashMap<i32, ItemConstant>),
+    ConstantsLoaded(Result<GameConstants>),
     ItemImagesLoaded(Result<HashMap<i32, String>>),
     SearchLoaded(Result<SearchPayload>),
+    AggregatesLoaded { account_id: u32, result: Result<PlayerAggregates> },
+    HistogramLoaded { key: ChartKey, result: Result<Vec<HistogramBucket>> },
+    HeroDetailLoaded { hero_id: i32, result: Result<HeroDetail> },
+    ProLoaded { request: ProRequest, result: Result<ProData> },
+    LiveLoaded(Result<Vec<LiveGame>>),
+    PublicMatchesLoaded { key: PublicKey, result: Result<Vec<PublicMatch>> },
+    DistributionLoaded(Result<Vec<RankBin>>),
+    ExplorerLoaded(Result<ExplorerResult>),
+    WardmapLoaded { key: ChartKey, result: Result<WardMap> },
+    WordcloudLoaded { key: ChartKey, result: Result<WordCloud> },
     MatchDetailLoaded(Result<MatchDetail>),
     ImageLoaded { url: String, result: Result<Vec<u8>> },
     PlayerAvatarLoaded { account_id: u32, result: Result<Option<String>> },
@@ -170,6 +1384,7 @@ pub enum Message {
 }
 
 pub struct SearchPayload {
+    pub target: SearchTarget,
     pub account_id: u32,
     pub profile: Option<PlayerResponse>,
     pub matches: Vec<PlayerMatch>,
@@ -177,12 +1392,28 @@ pub struct SearchPayload {
     pub match_error: Option<String>,
 }
 
+pub struct PlayerAggregates {
+    pub totals: Vec<PlayerTotal>,
+    pub counts: PlayerCounts,
+    pub wl: WinLoss,
+}
+
+impl PlayerAggregates {
+    pub fn average(&self, field: &str) -> Option<f64> {
+        self.totals
+            .iter()
+            .find(|total| total.field == field && total.n > 0)
+            .map(|total| total.sum / total.n as f64)
+    }
+}
+
 pub fn handle_message(msg: Message, app: &mut App, i18n: &I18n) -> Option<String> {
     let mut avatar_request = None;
     match msg {
         Message::HeroesLoaded(result) => match result {
             Ok(heroes) => {
                 app.heroes = heroes;
+                app.apply_match_filter(i18n);
                 app.set_status(i18n.status_hero_loaded());
             }
             Err(err) => {
@@ -197,6 +1428,18 @@ pub fn handle_message(msg: Message, app: &mut App, i18n: &I18n) -> Option<String
                 app.set_status(i18n.status_hero_failed(&err.to_string()));
             }
         },
+        Message::ItemsLoaded(items) => {
+            app.items = items;
+        }
+        Message::ConstantsLoaded(result) => match result {
+            Ok(constants) => {
+                app.constants = constants;
+                app.apply_match_filter(i18n);
+            }
+            Err(err) => {
+                app.set_status(i18n.status_constants_failed(&err.to_string()));
+            }
+        },
         Message::ItemImagesLoaded(result) => match result {
             Ok(images) => {
                 app.item_images = images;
@@ -205,13 +1448,17 @@ pub fn handle_message(msg: Message, app: &mut App, i18n: &I18n) -> Option<String
                 app.set_status(i18n.status_hero_failed(&err.to_string()));
             }
         },
+        Message::SearchLoaded(Ok(payload)) if payload.target != SearchTarget::Primary => {
+            apply_compare_payload(app, payload, i18n);
+        }
         Message::SearchLoaded(result) => {
             app.loading = false;
             match result {
                 Ok(payload) => {
                     app.account_id = Some(payload.account_id);
                     app.profile = payload.profile;
-                    app.matches = payload.matches;
+                    app.all_matches = payload.matches;
+                    app.apply_match_filter(i18n);
                     app.avatar_loading = false;
                     app.avatar_url = app
                         .profile
@@ -221,16 +1468,15 @@ pub fn handle_message(msg: Message, app: &mut App, i18n: &I18n) -> Option<String
                             profile
                                 .avatarfull
                                 .as_deref()
-                                .or_else(|| profile.avatarmedium.as_deref())
-                                .or_else(|| profile.avatar.as_deref())
+                                .or(profile.avatarmedium.as_deref())
+                                .or(profile.avatar.as_deref())
                         })
                         .map(|url| url.to_string());
-                    if app.avatar_url.is_none() {
-                        if let Some(account_id) = app.account_id {
-                            if let Some(url) = app.player_avatars.get(&account_id) {
-                                app.avatar_url = Some(url.clone());
-                            }
-                        }
+                    if app.avatar_url.is_none()
+                        && let Some(account_id) = app.account_id
+                        && let Some(url) = app.player_avatars.get(&account_id)
+                    {
+                        app.avatar_url = Some(url.clone());
                     }
                     if let (Some(account_id), Some(url)) = (app.account_id, app.avatar_url.clone()) {
                         app.player_avatars.insert(account_id, url);
@@ -265,6 +1511,7 @@ pub fn handle_message(msg: Message, app: &mut App, i18n: &I18n) -> Option<String
                             .or_else(|| profile.avatarmedium.clone())
                             .or_else(|| profile.avatar.clone());
                         if let Some(account_id) = app.account_id {
+                            refresh_favorite(&mut app.favorites, account_id, &name, avatar.as_deref());
                             push_recent_search(&mut app.recent_searches, account_id, name, avatar);
                         }
                     }
@@ -274,11 +1521,124 @@ pub fn handle_message(msg: Message, app: &mut App, i18n: &I18n) -> Option<String
                 }
             }
         }
+        Message::AggregatesLoaded { account_id, result } => {
+            if app.account_id == Some(account_id) {
+                match result {
+                    Ok(aggregates) => app.aggregates = Some(aggregates),
+                    Err(err) => app.set_status(i18n.status_aggregates_failed(&err.to_string())),
+                }
+            }
+        }
+        Message::ProLoaded { request, result } => match result {
+            Ok(data) => app.pro.store(data),
+            Err(err) => {
+                app.pro.requested.remove(&request);
+                if !matches!(request, ProRequest::Players) || app.tab_index == TAB_PRO {
+                    app.set_status(i18n.status_pro_failed(&err.to_string()));
+                }
+            }
+        },
+        Message::LiveLoaded(result) => {
+            app.live_loading = false;
+            match result {
+                Ok(games) => {
+                    let mut tracked: Vec<u32> = games
+                        .iter()
+                        .flat_map(|game| game.players.iter())
+                        .filter_map(|player| player.account_id)
+                        .filter(|id| app.is_tracked(*id))
+                        .collect();
+                    tracked.sort_unstable();
+                    tracked.dedup();
+                    if !tracked.is_empty() && tracked != app.live_tracked {
+                        let names: Vec<String> = tracked
+                            .iter()
+                            .map(|id| app.tracked_label(*id).unwrap_or_else(|| id.to_string()))
+                            .collect();
+                        app.set_status(i18n.status_live_tracked(&names.join(", ")));
+                    }
+                    app.live_tracked = tracked;
+                    app.live_index = app.live_index.min(games.len().saturating_sub(1));
+                    app.live = Some(games);
+                    app.live_updated = Some(Instant::now());
+                }
+                Err(err) => app.set_status(i18n.status_live_failed(&err.to_string())),
+            }
+        }
+        Message::PublicMatchesLoaded { key, result } => {
+            if app.public_requested.as_ref() == Some(&key) {
+                match result {
+                    Ok(matches) => match (key.less_than, app.public_matches.as_mut()) {
+                        (Some(_), Some(existing)) => {
+                            app.public_exhausted = matches.is_empty();
+                            existing.extend(matches);
+                        }
+                        _ => app.public_matches = Some(matches),
+                    },
+                    Err(err) => app.set_status(i18n.status_public_failed(&err.to_string())),
+                }
+            }
+        }
+        Message::DistributionLoaded(result) => match result {
+            Ok(bins) => app.distribution = Some(bins),
+            Err(err) => {
+                app.distribution_requested = false;
+                app.set_status(i18n.status_distribution_failed(&err.to_string()));
+            }
+        },
+        Message::ExplorerLoaded(result) => {
+            app.explorer_loading = false;
+            match result {
+                Ok(ExplorerResult { err: Some(err), .. }) => app.set_status(i18n.status_explorer_failed(&err)),
+                Ok(result) => {
+                    let table = ExplorerTable::from_result(result);
+                    app.set_status(i18n.status_explorer_rows(table.rows.len()));
+                    app.explorer_result = Some(table);
+                    app.explorer_index = 0;
+                    app.explorer_column = 0;
+                }
+                Err(err) => app.set_status(i18n.status_explorer_failed(&err.to_string())),
+            }
+        }
+        Message::HeroDetailLoaded { hero_id, result } => {
+            if app.hero_requested == Some(hero_id) {
+                match result {
+                    Ok(detail) => app.hero_detail = Some(detail),
+                    Err(err) => app.set_status(i18n.status_hero_page_failed(&err.to_string())),
+                }
+            }
+        }
+        Message::HistogramLoaded { key, result } => {
+            if app.chart_requested.as_ref() == Some(&key) {
+                match result {
+                    Ok(buckets) => app.histogram = Some(Histogram { key, buckets }),
+                    Err(err) => app.set_status(i18n.status_histogram_failed(&err.to_string())),
+                }
+            }
+        }
+        Message::WardmapLoaded { key, result } => {
+            if app.chart_requested.as_ref() == Some(&key) {
+                match result {
+                    Ok(wards) => app.wardmap = Some(Wardmap { key, wards }),
+                    Err(err) => app.set_status(i18n.status_wardmap_failed(&err.to_string())),
+                }
+            }
+        }
+        Message::WordcloudLoaded { key, result } => {
+            if app.chart_requested.as_ref() == Some(&key) {
+                match result {
+                    Ok(words) => app.wordcloud = Some(Wordcloud { key, words }),
+                    Err(err) => app.set_status(i18n.status_wordcloud_failed(&err.to_string())),
+                }
+            }
+        }
         Message::MatchDetailLoaded(result) => {
             app.detail_loading = false;
             match result {
                 Ok(detail) => {
                     app.match_detail = Some(detail);
+                    app.team_selection = None;
+                    app.chat_scroll = 0;
                     app.set_status(i18n.status_match_loaded());
                     if let Some(detail) = app.match_detail.as_ref() {
                         let disk_map = load_avatar_map();
@@ -338,6 +1698,24 @@ pub fn handle_message(msg: Message, app: &mut App, i18n: &I18n) -> Option<String
     avatar_request
 }
 
+fn apply_compare_payload(app: &mut App, payload: SearchPayload, i18n: &I18n) {
+    if let SearchTarget::Compare(slot) = payload.target
+        && let Some(player) = app
+            .compare_players
+            .get_mut(slot)
+            .filter(|player| player.account_id == payload.account_id)
+    {
+        player.loading = false;
+        player.profile = payload.profile;
+        player.matches = payload.matches;
+        player.error = payload.profile_error.or(payload.match_error);
+    }
+    if app.compare_players.iter().all(|player| !player.loading) {
+        app.image_reset = true;
+        app.set_status(i18n.status_compare_loaded());
+    }
+}
+
 pub fn spawn_hero_load(tx: mpsc::Sender<Message>, api: ApiClient) {
     tokio::spawn(async move {
         let started = Instant::now();
@@ -351,6 +1729,28 @@ pub fn spawn_hero_load(tx: mpsc::Sender<Message>, api: ApiClient) {
     });
 }
 
+pub fn spawn_constants_load(tx: mpsc::Sender<Message>, api: ApiClient) {
+    tokio::spawn(async move {
+        let started = Instant::now();
+        let result = match load_constants_cache(Some(CONSTANTS_TTL)) {
+            Some(cached) => Ok(cached),
+            None => match api.fetch_game_constants().await {
+                Ok(constants) => {
+                    save_constants_cache(&constants);
+                    Ok(constants)
+                }
+                Err(err) => load_constants_cache(None).ok_or(err),
+            },
+        };
+        let _ = tx.send(Message::ConstantsLoaded(result)).await;
+        let _ = tx
+            .send(Message::NetEvent {
+                elapsed_ms: started.elapsed().as_millis(),
+            })
+            .await;
+    });
+}
+
 pub fn spawn_hero_images(tx: mpsc::Sender<Message>, api: ApiClient, cdn_base: String) {
     tokio::spawn(async move {
         let started = Instant::now();
@@ -370,10 +1770,11 @@ pub fn spawn_hero_images(tx: mpsc::Sender<Message>, api: ApiClient, cdn_base: St
 pub fn spawn_item_images(tx: mpsc::Sender<Message>, api: ApiClient, cdn_base: String) {
     tokio::spawn(async move {
         let started = Instant::now();
-        let result = api
-            .fetch_item_constants()
-            .await
-            .map(|items| build_asset_map(items, &cdn_base, |item| item.img.clone()));
+        let result = api.fetch_item_constants().await;
+        if let Ok(items) = &result {
+            let _ = tx.send(Message::ItemsLoaded(items.clone())).await;
+        }
+        let result = result.map(|items| build_asset_map(items, &cdn_base, |item| item.img.clone()));
         let _ = tx.send(Message::ItemImagesLoaded(result)).await;
         let _ = tx
             .send(Message::NetEvent {
@@ -383,28 +1784,202 @@ pub fn spawn_item_images(tx: mpsc::Sender<Message>, api: ApiClient, cdn_base: St
     });
 }
 
-pub fn spawn_search(tx: mpsc::Sender<Message>, api: ApiClient, account_id: u32) {
+pub fn spawn_search(
+    tx: mpsc::Sender<Message>,
+    api: ApiClient,
+    account_id: u32,
+    target: SearchTarget,
+) {
     tokio::spawn(async move {
         let started = Instant::now();
-        let profile_task = api.fetch_profile(account_id);
-        let matches_task = api.fetch_matches(account_id);
-        let (profile_result, matches_result) = tokio::join!(profile_task, matches_task);
-        let (profile, profile_error) = match profile_result {
-            Ok(profile) => (Some(profile), None),
-            Err(err) => (None, Some(err.to_string())),
+        let player = async {
+            let payload = Ok(load_player(&api, account_id, target).await);
+            let _ = tx.send(Message::SearchLoaded(payload)).await;
         };
-        let (matches, match_error) = match matches_result {
-            Ok(matches) => (matches, None),
-            Err(err) => (Vec::new(), Some(err.to_string())),
+        if target == SearchTarget::Primary {
+            tokio::join!(player, send_aggregates(&tx, &api, account_id));
+        } else {
+            player.await;
+        }
+        let _ = tx
+            .send(Message::NetEvent {
+                elapsed_ms: started.elapsed().as_millis(),
+            })
+            .await;
+    });
+}
+
+pub fn spawn_refresh(tx: mpsc::Sender<Message>, api: ApiClient, account_id: u32) {
+    tokio::spawn(async move {
+        let started = Instant::now();
+        api.invalidate_prefix(&format!("/players/{account_id}")).await;
+        let player = async {
+            let payload = Ok(load_player(&api, account_id, SearchTarget::Primary).await);
+            let _ = tx.send(Message::SearchLoaded(payload)).await;
         };
-        let payload = Ok(SearchPayload {
-            account_id,
-            profile,
-            matches,
-            profile_error,
-            match_error,
-        });
-        let _ = tx.send(Message::SearchLoaded(payload)).await;
+        tokio::join!(player, send_aggregates(&tx, &api, account_id));
+        let _ = tx
+            .send(Message::NetEvent {
+                elapsed_ms: started.elapsed().as_millis(),
+            })
+            .await;
+    });
+}
+
+async fn load_player(api: &ApiClient, account_id: u32, target: SearchTarget) -> SearchPayload {
+    let profile_task = api.fetch_profile(account_id);
+    let matches_task = api.fetch_matches(account_id);
+    let (profile_result, matches_result) = tokio::join!(profile_task, matches_task);
+    let (profile, profile_error) = match profile_result {
+        Ok(profile) => (Some(profile), None),
+        Err(err) => (None, Some(err.to_string())),
+    };
+    let (matches, match_error) = match matches_result {
+        Ok(matches) => (matches, None),
+        Err(err) => (Vec::new(), Some(err.to_string())),
+    };
+    SearchPayload {
+        target,
+        account_id,
+        profile,
+        matches,
+        profile_error,
+        match_error,
+    }
+}
+
+async fn send_aggregates(tx: &mpsc::Sender<Message>, api: &ApiClient, account_id: u32) {
+    let result = tokio::try_join!(
+        api.fetch_totals(account_id),
+        api.fetch_counts(account_id),
+        api.fetch_wl(account_id),
+    )
+    .map(|(totals, counts, wl)| PlayerAggregates { totals, counts, wl });
+    let _ = tx
+        .send(Message::AggregatesLoaded { account_id, result })
+        .await;
+}
+
+pub fn spawn_chart(tx: mpsc::Sender<Message>, api: ApiClient, key: ChartKey) {
+    tokio::spawn(async move {
+        let started = Instant::now();
+        let message = match key.view {
+            ChartView::Histogram => {
+                let result = api
+                    .fetch_histogram(key.account_id, key.field, key.query.clone())
+                    .await;
+                Message::HistogramLoaded { key, result }
+            }
+            ChartView::Wardmap => {
+                let result = api.fetch_wardmap(key.account_id, key.query.clone()).await;
+                Message::WardmapLoaded { key, result }
+            }
+            ChartView::Wordcloud => {
+                let result = api.fetch_wordcloud(key.account_id, key.query.clone()).await;
+                Message::WordcloudLoaded { key, result }
+            }
+        };
+        let _ = tx.send(message).await;
+        let _ = tx
+            .send(Message::NetEvent {
+                elapsed_ms: started.elapsed().as_millis(),
+            })
+            .await;
+    });
+}
+
+pub fn spawn_pro(tx: mpsc::Sender<Message>, api: ApiClient, request: ProRequest) {
+    tokio::spawn(async move {
+        let started = Instant::now();
+        let result = match request {
+            ProRequest::Matches => api.fetch_pro_matches().await.map(ProData::Matches),
+            ProRequest::Players => api.fetch_pro_players().await.map(ProData::Players),
+            ProRequest::Teams => api.fetch_teams().await.map(ProData::Teams),
+            ProRequest::Leagues => api.fetch_leagues().await.map(ProData::Leagues),
+            ProRequest::Page(ProPage::Team(team_id)) => {
+                tokio::try_join!(api.fetch_team_matches(team_id), api.fetch_team_players(team_id))
+                    .map(|(matches, players)| ProData::Team(TeamDetail { team_id, matches, players }))
+            }
+            ProRequest::Page(ProPage::League(league_id)) => api
+                .fetch_league_matches(league_id)
+                .await
+                .map(|matches| ProData::League(LeagueDetail { league_id, matches })),
+        };
+        let _ = tx.send(Message::ProLoaded { request, result }).await;
+        let _ = tx
+            .send(Message::NetEvent {
+                elapsed_ms: started.elapsed().as_millis(),
+            })
+            .await;
+    });
+}
+
+pub fn spawn_live(tx: mpsc::Sender<Message>, api: ApiClient) {
+    tokio::spawn(async move {
+        let started = Instant::now();
+        api.invalidate_prefix("/live").await;
+        let result = api.fetch_live().await;
+        let _ = tx.send(Message::LiveLoaded(result)).await;
+        let _ = tx
+            .send(Message::NetEvent {
+                elapsed_ms: started.elapsed().as_millis(),
+            })
+            .await;
+    });
+}
+
+pub fn spawn_public_matches(tx: mpsc::Sender<Message>, api: ApiClient, key: PublicKey) {
+    tokio::spawn(async move {
+        let started = Instant::now();
+        if key.less_than.is_none() {
+            api.invalidate_prefix("/publicMatches").await;
+        }
+        let result = api.fetch_public_matches(key.query()).await;
+        let _ = tx.send(Message::PublicMatchesLoaded { key, result }).await;
+        let _ = tx
+            .send(Message::NetEvent {
+                elapsed_ms: started.elapsed().as_millis(),
+            })
+            .await;
+    });
+}
+
+pub fn spawn_distribution(tx: mpsc::Sender<Message>, api: ApiClient) {
+    tokio::spawn(async move {
+        let started = Instant::now();
+        let result = api.fetch_distributions().await.map(|distributions| distributions.ranks.rows);
+        let _ = tx.send(Message::DistributionLoaded(result)).await;
+        let _ = tx
+            .send(Message::NetEvent {
+                elapsed_ms: started.elapsed().as_millis(),
+            })
+            .await;
+    });
+}
+
+pub fn spawn_explorer(tx: mpsc::Sender<Message>, api: ApiClient, sql: String) {
+    tokio::spawn(async move {
+        let started = Instant::now();
+        let result = api.fetch_explorer(&sql).await;
+        let _ = tx.send(Message::ExplorerLoaded(result)).await;
+        let _ = tx
+            .send(Message::NetEvent {
+                elapsed_ms: started.elapsed().as_millis(),
+            })
+            .await;
+    });
+}
+
+pub fn spawn_hero_detail(tx: mpsc::Sender<Message>, api: ApiClient, hero_id: i32) {
+    tokio::spawn(async move {
+        let started = Instant::now();
+        let result = tokio::try_join!(
+            api.fetch_hero_matchups(hero_id),
+            api.fetch_hero_item_popularity(hero_id),
+            api.fetch_hero_durations(hero_id),
+        )
+        .map(|(matchups, items, durations)| HeroDetail { hero_id, matchups, items, durations });
+        let _ = tx.send(Message::HeroDetailLoaded { hero_id, result }).await;
         let _ = tx
             .send(Message::NetEvent {
                 elapsed_ms: started.elapsed().as_millis(),
@@ -446,8 +2021,8 @@ pub fn spawn_player_avatars(tx: mpsc::Sender<Message>, api: ApiClient, account_i
                         .and_then(|p| {
                             p.avatarfull
                                 .as_deref()
-                                .or_else(|| p.avatarmedium.as_deref())
-                                .or_else(|| p.avatar.as_deref())
+                                .or(p.avatarmedium.as_deref())
+                                .or(p.avatar.as_deref())
                         })
                         .map(|value| value.to_string());
                     Ok(url)
@@ -472,7 +2047,7 @@ pub fn load_recent_searches(max_entries: usize) -> Vec<SearchEntry> {
     };
     let reader = BufReader::new(file);
     let mut entries = Vec::new();
-    for line in reader.lines().flatten() {
+    for line in reader.lines().map_while(Result::ok) {
         if let Ok(entry) = serde_json::from_str::<SearchEntry>(&line) {
             entries.push(entry);
         }
@@ -522,6 +2097,56 @@ pub fn save_avatar_map(map: &HashMap<u32, String>) {
     }
 }
 
+pub fn load_favorites() -> Vec<FavoriteEntry> {
+    let path = match favorites_path() {
+        Ok(path) => path,
+        Err(_) => return Vec::new(),
+    };
+    let bytes = match std::fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(_) => return Vec::new(),
+    };
+    serde_json::from_slice(&bytes).unwrap_or_default()
+}
+
+pub fn save_favorites(favorites: &[FavoriteEntry]) {
+    let path = match favorites_path() {
+        Ok(path) => path,
+        Err(_) => return,
+    };
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    if let Ok(bytes) = serde_json::to_vec_pretty(favorites) {
+        let _ = std::fs::write(path, bytes);
+    }
+}
+
+pub fn load_saved_queries() -> Vec<SavedQuery> {
+    let path = match queries_path() {
+        Ok(path) => path,
+        Err(_) => return Vec::new(),
+    };
+    let bytes = match std::fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(_) => return Vec::new(),
+    };
+    serde_json::from_slice(&bytes).unwrap_or_default()
+}
+
+pub fn save_saved_queries(queries: &[SavedQuery]) {
+    let path = match queries_path() {
+        Ok(path) => path,
+        Err(_) => return,
+    };
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    if let Ok(bytes) = serde_json::to_vec_pretty(queries) {
+        let _ = std::fs::write(path, bytes);
+    }
+}
+
 pub fn append_recent_search(entry: &SearchEntry) {
     let path = match recent_log_path() {
         Ok(path) => path,
@@ -539,6 +2164,37 @@ pub fn append_recent_search(entry: &SearchEntry) {
     }
 }
 
+fn load_constants_cache(max_age: Option<Duration>) -> Option<GameConstants> {
+    let path = constants_cache_path().ok()?;
+    if let Some(max_age) = max_age {
+        let age = modified_at(&path)?.elapsed().ok()?;
+        if age > max_age {
+            return None;
+        }
+    }
+    let bytes = std::fs::read(path).ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+fn save_constants_cache(constants: &GameConstants) {
+    let path = match constants_cache_path() {
+        Ok(path) => path,
+        Err(_) => return,
+    };
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    if let Ok(bytes) = serde_json::to_vec(constants) {
+        let _ = std::fs::write(path, bytes);
+    }
+}
+
+fn constants_cache_path() -> Result<std::path::PathBuf> {
+    let mut base = cache_dir()?;
+    base.push("constants.json");
+    Ok(base)
+}
+
 fn avatar_map_path() -> Result<std::path::PathBuf> {
     let mut base = cache_dir()?;
     base.push("avatar_map.json");
@@ -563,6 +2219,27 @@ fn build_asset_map<T: Clone>(
         .collect()
 }
 
+fn refresh_favorite(
+    favorites: &mut [FavoriteEntry],
+    account_id: u32,
+    name: &str,
+    avatar_url: Option<&str>,
+) {
+    let Some(entry) = favorites.iter_mut().find(|item| item.account_id == account_id) else {
+        return;
+    };
+    let changed = entry.personaname != name
+        || (avatar_url.is_some() && entry.avatar_url.as_deref() != avatar_url);
+    if !changed {
+        return;
+    }
+    entry.personaname = name.to_string();
+    if let Some(url) = avatar_url {
+        entry.avatar_url = Some(url.to_string());
+    }
+    save_favorites(favorites);
+}
+
 fn push_recent_search(
     recent: &mut Vec<SearchEntry>,
     account_id: u32,
@@ -581,3 +2258,56 @@ fn push_recent_search(
     recent.insert(0, entry);
     recent.truncate(5);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::patch_dates;
+    use crate::models::PatchConstant;
+
+    fn timestamp(date: &str) -> i64 {
+        chrono::DateTime::parse_from_rfc3339(date).unwrap().timestamp()
+    }
+
+    fn app_with_patches() -> App {
+        let patches: Vec<PatchConstant> = [
+            (3, "7.02", "2017-02-08T00:00:00Z"),
+            (1, "7.00", "2016-12-12T00:00:00Z"),
+            (4, "broken", "not a date"),
+            (2, "7.01", "2016-12-21T00:00:00Z"),
+            (5, "7.02b", "2017-02-08T00:00:00Z"),
+        ]
+        .into_iter()
+        .map(|(id, name, date)| PatchConstant {
+            id,
+            name: name.to_string(),
+            date: date.to_string(),
+        })
+        .collect();
+        let mut app = App::new();
+        app.constants.patch_dates = patch_dates(&patches);
+        app.constants.patches = patches;
+        app
+    }
+
+    fn patch_name(app: &App, start_time: Option<i64>) -> Option<&str> {
+        app.patch_at(start_time).map(|patch| patch.name.as_str())
+    }
+
+    #[test]
+    fn finds_the_patch_a_match_was_played_on() {
+        let app = app_with_patches();
+        assert_eq!(patch_name(&app, Some(timestamp("2016-12-15T12:00:00Z"))), Some("7.00"));
+        assert_eq!(patch_name(&app, Some(timestamp("2016-12-21T00:00:00Z"))), Some("7.01"));
+        assert_eq!(patch_name(&app, Some(timestamp("2016-12-20T23:59:59Z"))), Some("7.00"));
+        assert_eq!(patch_name(&app, Some(timestamp("2024-01-01T00:00:00Z"))), Some("7.02b"));
+    }
+
+    #[test]
+    fn matches_older_than_the_first_patch_or_without_a_start_have_none() {
+        let app = app_with_patches();
+        assert_eq!(patch_name(&app, Some(timestamp("2016-12-11T23:59:59Z"))), None);
+        assert_eq!(patch_name(&app, None), None);
+        assert_eq!(patch_name(&App::new(), Some(timestamp("2024-01-01T00:00:00Z"))), None);
+    }
+}
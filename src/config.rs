@@ -1,12 +1,16 @@
+use std::collections::BTreeMap;
+use std::fmt;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::SystemTime;
 
 use anyhow::{anyhow, Context, Result};
 use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::style::Color;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
 pub struct Config {
     pub theme: ThemeConfig,
@@ -20,24 +24,98 @@ pub struct Config {
 #[serde(default)]
 pub struct ThemeConfig {
     pub name: String,
+    pub dark: String,
+    pub light: String,
+    pub color_depth: String,
+    pub palettes: BTreeMap<String, ThemePalette>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemePalette {
+    pub extends: Option<String>,
+    pub base: Option<String>,
+    pub text: Option<String>,
+    pub accent: Option<String>,
+    pub warn: Option<String>,
+    pub success: Option<String>,
+    pub win: Option<String>,
+    pub loss: Option<String>,
+    pub highlight: Option<String>,
+    pub border: Option<String>,
+    pub overlay: Option<String>,
+    pub gauge: Option<String>,
+    pub banner: Option<String>,
+    pub banner_text: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct Keybinds {
-    pub search: String,
-    pub quit: String,
-    pub up: String,
-    pub down: String,
-    pub select: String,
-    pub top: String,
-    pub bottom: String,
-    pub clear_input: String,
-    pub tab_next: String,
-    pub tab_prev: String,
-    pub help: String,
+    pub search: Binding,
+    pub quit: Binding,
+    pub up: Binding,
+    pub down: Binding,
+    pub select: Binding,
+    pub top: Binding,
+    pub bottom: Binding,
+    pub tab_next: Binding,
+    pub tab_prev: Binding,
+    pub help: Binding,
+    pub favorite: Binding,
+    pub favorite_next: Binding,
+    pub favorite_prev: Binding,
+    pub favorite_move_up: Binding,
+    pub favorite_move_down: Binding,
+    pub favorite_remove: Binding,
+    pub favorite_alias: Binding,
+    pub compare: Binding,
+    pub refresh: Binding,
+    pub export: Binding,
+    pub filter_hero: Binding,
+    pub filter_type: Binding,
+    pub filter_region: Binding,
+    pub stats_patch: Binding,
+    pub chart_view: Binding,
+    pub chart_next: Binding,
+    pub chart_prev: Binding,
+    pub laning: Binding,
+    pub hero_sort: Binding,
+    pub hero_role: Binding,
+    pub hero_bracket: Binding,
+    pub pro_view: Binding,
+    pub public_min_rank: Binding,
+    pub public_max_rank: Binding,
+    pub explorer_edit: Binding,
+    pub explorer_query: Binding,
+    pub explorer_save: Binding,
+    pub explorer_left: Binding,
+    pub explorer_right: Binding,
+    pub palette: Binding,
+    pub recent_next: Binding,
+    pub recent_prev: Binding,
+    pub cancel: Binding,
+    #[serde(skip_serializing)]
+    pub clear_input: Option<Binding>,
+    pub editing: EditingKeybinds,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EditingKeybinds {
+    pub submit: Binding,
+    pub cancel: Binding,
+    pub clear_input: Binding,
+    pub complete: Binding,
+    pub next: Binding,
+    pub prev: Binding,
+    pub help: Binding,
+    pub run: Binding,
 }
 
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Binding(pub Vec<String>);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct ApiConfig {
@@ -63,6 +141,17 @@ pub struct UiConfig {
     pub language: String,
 }
 
+pub const THEME_NAMES: [&str; 8] = [
+    "catppuccin",
+    "catppuccin-latte",
+    "gruvbox",
+    "nord",
+    "dracula",
+    "tokyonight",
+    "solarized",
+    "default",
+];
+
 #[derive(Debug, Clone, Copy)]
 pub struct Theme {
     pub base: Color,
@@ -70,45 +159,435 @@ pub struct Theme {
     pub accent: Color,
     pub warn: Color,
     pub success: Color,
+    pub win: Color,
+    pub loss: Color,
+    pub highlight: Color,
+    pub border: Color,
+    pub overlay: Color,
+    pub gauge: Color,
+    pub banner: Color,
+    pub banner_text: Color,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorDepth {
+    TrueColor,
+    Ansi256,
+    Ansi16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Background {
+    Dark,
+    Light,
+}
+
+type Rgb = (u8, u8, u8);
+
+const ANSI16: [(Color, Rgb); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+impl Theme {
+    pub fn builtin(name: &str) -> Self {
+        match name {
+            "catppuccin" => Theme {
+                banner_text: Color::Rgb(248, 250, 252),
+                ..Theme::from_rgb(
+                    (30, 30, 46),
+                    (205, 214, 244),
+                    (137, 180, 250),
+                    (243, 139, 168),
+                    (166, 227, 161),
+                    (49, 50, 68),
+                    (12, 12, 18),
+                )
+            },
+            "catppuccin-latte" => Theme::from_rgb(
+                (239, 241, 245),
+                (76, 79, 105),
+                (30, 102, 245),
+                (210, 15, 57),
+                (64, 160, 43),
+                (204, 208, 218),
+                (220, 224, 232),
+            ),
+            "gruvbox" => Theme::from_rgb(
+                (40, 40, 40),
+                (235, 219, 178),
+                (250, 189, 47),
+                (251, 73, 52),
+                (184, 187, 38),
+                (80, 73, 69),
+                (29, 32, 33),
+            ),
+            "nord" => Theme::from_rgb(
+                (46, 52, 64),
+                (216, 222, 233),
+                (136, 192, 208),
+                (191, 97, 106),
+                (163, 190, 140),
+                (67, 76, 94),
+                (36, 41, 51),
+            ),
+            "dracula" => Theme::from_rgb(
+                (40, 42, 54),
+                (248, 248, 242),
+                (189, 147, 249),
+                (255, 85, 85),
+                (80, 250, 123),
+                (68, 71, 90),
+                (24, 25, 33),
+            ),
+            "tokyonight" => Theme::from_rgb(
+                (26, 27, 38),
+                (192, 202, 245),
+                (122, 162, 247),
+                (247, 118, 142),
+                (158, 206, 106),
+                (41, 46, 66),
+                (16, 16, 24),
+            ),
+            "solarized" => Theme::from_rgb(
+                (0, 43, 54),
+                (131, 148, 150),
+                (38, 139, 210),
+                (220, 50, 47),
+                (133, 153, 0),
+                (7, 54, 66),
+                (0, 30, 38),
+            ),
+            _ => Theme {
+                base: Color::Black,
+                text: Color::White,
+                accent: Color::Cyan,
+                warn: Color::Red,
+                success: Color::Green,
+                win: Color::Green,
+                loss: Color::Red,
+                highlight: Color::DarkGray,
+                border: Color::Cyan,
+                overlay: Color::Black,
+                gauge: Color::Green,
+                banner: Color::Red,
+                banner_text: Color::White,
+            },
+        }
+    }
+
+    fn from_rgb(base: Rgb, text: Rgb, accent: Rgb, warn: Rgb, success: Rgb, highlight: Rgb, overlay: Rgb) -> Self {
+        let rgb = |(r, g, b): Rgb| Color::Rgb(r, g, b);
+        Theme {
+            base: rgb(base),
+            text: rgb(text),
+            accent: rgb(accent),
+            warn: rgb(warn),
+            success: rgb(success),
+            win: rgb(success),
+            loss: rgb(warn),
+            highlight: rgb(highlight),
+            border: rgb(accent),
+            overlay: rgb(overlay),
+            gauge: rgb(success),
+            banner: Color::Rgb(220, 38, 38),
+            banner_text: rgb(text),
+        }
+    }
+}
+
+impl Theme {
+    pub fn with_depth(self, depth: ColorDepth) -> Self {
+        if depth == ColorDepth::TrueColor {
+            return self;
+        }
+        let map = |color: Color| reduce_color(color, depth);
+        let mut theme = Theme {
+            base: map(self.base),
+            text: map(self.text),
+            accent: map(self.accent),
+            warn: map(self.warn),
+            success: map(self.success),
+            win: map(self.win),
+            loss: map(self.loss),
+            highlight: map(self.highlight),
+            border: map(self.border),
+            overlay: map(self.overlay),
+            gauge: map(self.gauge),
+            banner: map(self.banner),
+            banner_text: map(self.banner_text),
+        };
+        if theme.highlight == theme.base {
+            theme.highlight = if theme.base == Color::Black {
+                Color::DarkGray
+            } else {
+                Color::Gray
+            };
+        }
+        theme
+    }
+}
+
+fn reduce_color(color: Color, depth: ColorDepth) -> Color {
+    let Color::Rgb(r, g, b) = color else {
+        return color;
+    };
+    match depth {
+        ColorDepth::TrueColor => color,
+        ColorDepth::Ansi256 => Color::Indexed(nearest_ansi256((r, g, b))),
+        ColorDepth::Ansi16 => ANSI16
+            .iter()
+            .min_by_key(|(_, rgb)| color_distance((r, g, b), *rgb))
+            .map(|(color, _)| *color)
+            .unwrap_or(color),
+    }
+}
+
+fn nearest_ansi256((r, g, b): Rgb) -> u8 {
+    let step = |v: u8| -> u8 {
+        match v {
+            0..=47 => 0,
+            48..=114 => 1,
+            _ => (v - 35) / 40,
+        }
+    };
+    let level = |i: u8| if i == 0 { 0 } else { 55 + i * 40 };
+    let (cr, cg, cb) = (step(r), step(g), step(b));
+    let cube_index = 16 + 36 * cr + 6 * cg + cb;
+    let cube_distance = color_distance((r, g, b), (level(cr), level(cg), level(cb)));
+
+    let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    let gray = (average.saturating_sub(3) / 10).min(23) as u8;
+    let gray_level = 8 + gray * 10;
+    let gray_distance = color_distance((r, g, b), (gray_level, gray_level, gray_level));
+
+    if gray_distance < cube_distance {
+        232 + gray
+    } else {
+        cube_index
+    }
+}
+
+fn color_distance(a: Rgb, b: Rgb) -> u32 {
+    let d = |x: u8, y: u8| (i32::from(x) - i32::from(y)).unsigned_abs().pow(2);
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+impl ThemePalette {
+    fn apply(&self, mut theme: Theme) -> Result<Theme> {
+        let slots = [
+            ("base", &self.base, &mut theme.base),
+            ("text", &self.text, &mut theme.text),
+            ("accent", &self.accent, &mut theme.accent),
+            ("warn", &self.warn, &mut theme.warn),
+            ("success", &self.success, &mut theme.success),
+            ("win", &self.win, &mut theme.win),
+            ("loss", &self.loss, &mut theme.loss),
+            ("highlight", &self.highlight, &mut theme.highlight),
+            ("border", &self.border, &mut theme.border),
+            ("overlay", &self.overlay, &mut theme.overlay),
+            ("gauge", &self.gauge, &mut theme.gauge),
+            ("banner", &self.banner, &mut theme.banner),
+            ("banner_text", &self.banner_text, &mut theme.banner_text),
+        ];
+        for (name, value, slot) in slots {
+            if let Some(value) = value {
+                *slot = parse_color(value).with_context(|| format!("Invalid `{name}` color"))?;
+            }
+        }
+        Ok(theme)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct KeyCombo {
     pub code: KeyCode,
     pub modifiers: KeyModifiers,
 }
 
-#[derive(Debug, Clone, Copy)]
+pub type KeySequence = Vec<KeyCombo>;
+
+#[derive(Debug, Clone)]
 pub struct ResolvedKeybinds {
-    pub search: KeyCombo,
-    pub quit: KeyCombo,
-    pub up: KeyCombo,
-    pub down: KeyCombo,
-    pub select: KeyCombo,
-    pub top: KeyCombo,
-    pub bottom: KeyCombo,
-    pub clear_input: KeyCombo,
-    pub tab_next: KeyCombo,
-    pub tab_prev: KeyCombo,
-    pub help: KeyCombo,
-}
-
-impl Default for Config {
-    fn default() -> Self {
-        Self {
-            theme: ThemeConfig::default(),
-            keybinds: Keybinds::default(),
-            api: ApiConfig::default(),
-            images: ImageConfig::default(),
-            ui: UiConfig::default(),
+    pub normal: Vec<(&'static str, KeySequence)>,
+    pub editing: Vec<(&'static str, KeyCombo)>,
+    pub conflicts: Vec<String>,
+    pub deprecated: Vec<String>,
+}
+
+impl KeyCombo {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        match code {
+            KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) => Self {
+                code: KeyCode::Char(c.to_ascii_uppercase()),
+                modifiers: modifiers - KeyModifiers::SHIFT,
+            },
+            KeyCode::BackTab => Self {
+                code,
+                modifiers: modifiers - KeyModifiers::SHIFT,
+            },
+            _ => Self { code, modifiers },
+        }
+    }
+}
+
+impl fmt::Display for KeyCombo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            f.write_str("Ctrl+")?;
         }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            f.write_str("Alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            f.write_str("Shift+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => f.write_str("Space"),
+            KeyCode::Char(c) => write!(f, "{c}"),
+            KeyCode::F(n) => write!(f, "F{n}"),
+            other => write!(f, "{other:?}"),
+        }
+    }
+}
+
+impl Binding {
+    fn new(keys: &[&str]) -> Self {
+        Self(keys.iter().map(|key| key.to_string()).collect())
+    }
+}
+
+impl fmt::Display for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0.join(" / "))
+    }
+}
+
+impl Serialize for Binding {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self.0.as_slice() {
+            [key] => serializer.serialize_str(key),
+            keys => keys.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Binding {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum RawBinding {
+            One(String),
+            Many(Vec<String>),
+        }
+        Ok(match RawBinding::deserialize(deserializer)? {
+            RawBinding::One(key) => Binding(vec![key]),
+            RawBinding::Many(keys) => Binding(keys),
+        })
+    }
+}
+
+impl Keybinds {
+    pub fn entries(&self) -> Vec<(&'static str, &Binding)> {
+        vec![
+            ("search", &self.search),
+            ("quit", &self.quit),
+            ("up", &self.up),
+            ("down", &self.down),
+            ("select", &self.select),
+            ("top", &self.top),
+            ("bottom", &self.bottom),
+            ("tab_next", &self.tab_next),
+            ("tab_prev", &self.tab_prev),
+            ("help", &self.help),
+            ("favorite", &self.favorite),
+            ("favorite_next", &self.favorite_next),
+            ("favorite_prev", &self.favorite_prev),
+            ("favorite_move_up", &self.favorite_move_up),
+            ("favorite_move_down", &self.favorite_move_down),
+            ("favorite_remove", &self.favorite_remove),
+            ("favorite_alias", &self.favorite_alias),
+            ("compare", &self.compare),
+            ("refresh", &self.refresh),
+            ("export", &self.export),
+            ("filter_hero", &self.filter_hero),
+            ("filter_type", &self.filter_type),
+            ("filter_region", &self.filter_region),
+            ("stats_patch", &self.stats_patch),
+            ("chart_view", &self.chart_view),
+            ("chart_next", &self.chart_next),
+            ("chart_prev", &self.chart_prev),
+            ("laning", &self.laning),
+            ("hero_sort", &self.hero_sort),
+            ("hero_role", &self.hero_role),
+            ("hero_bracket", &self.hero_bracket),
+            ("pro_view", &self.pro_view),
+            ("public_min_rank", &self.public_min_rank),
+            ("public_max_rank", &self.public_max_rank),
+            ("explorer_edit", &self.explorer_edit),
+            ("explorer_query", &self.explorer_query),
+            ("explorer_save", &self.explorer_save),
+            ("explorer_left", &self.explorer_left),
+            ("explorer_right", &self.explorer_right),
+            ("palette", &self.palette),
+            ("recent_next", &self.recent_next),
+            ("recent_prev", &self.recent_prev),
+            ("cancel", &self.cancel),
+        ]
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Binding> {
+        self.entries()
+            .into_iter()
+            .find(|(entry, _)| *entry == name)
+            .map(|(_, binding)| binding)
+    }
+}
+
+impl EditingKeybinds {
+    pub fn entries(&self) -> Vec<(&'static str, &Binding)> {
+        vec![
+            ("submit", &self.submit),
+            ("cancel", &self.cancel),
+            ("clear_input", &self.clear_input),
+            ("complete", &self.complete),
+            ("next", &self.next),
+            ("prev", &self.prev),
+            ("help", &self.help),
+            ("run", &self.run),
+        ]
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Binding> {
+        self.entries()
+            .into_iter()
+            .find(|(entry, _)| *entry == name)
+            .map(|(_, binding)| binding)
     }
 }
 
 impl Default for ThemeConfig {
     fn default() -> Self {
         Self {
-            name: "catppuccin".to_string(),
+            name: "auto".to_string(),
+            dark: "catppuccin".to_string(),
+            light: "catppuccin-latte".to_string(),
+            color_depth: "auto".to_string(),
+            palettes: BTreeMap::new(),
         }
     }
 }
@@ -116,17 +595,66 @@ impl Default for ThemeConfig {
 impl Default for Keybinds {
     fn default() -> Self {
         Self {
-            search: "/".to_string(),
-            quit: "q".to_string(),
-            up: "k".to_string(),
-            down: "j".to_string(),
-            select: "Enter".to_string(),
-            top: "g".to_string(),
-            bottom: "G".to_string(),
-            clear_input: "Ctrl+u".to_string(),
-            tab_next: "Right".to_string(),
-            tab_prev: "Left".to_string(),
-            help: "?".to_string(),
+            search: Binding::new(&["/"]),
+            quit: Binding::new(&["q"]),
+            up: Binding::new(&["k", "Up"]),
+            down: Binding::new(&["j", "Down"]),
+            select: Binding::new(&["Enter"]),
+            top: Binding::new(&["g g", "Home"]),
+            bottom: Binding::new(&["G", "End"]),
+            tab_next: Binding::new(&["Right"]),
+            tab_prev: Binding::new(&["Left"]),
+            help: Binding::new(&["?"]),
+            favorite: Binding::new(&["f"]),
+            favorite_next: Binding::new(&["]"]),
+            favorite_prev: Binding::new(&["["]),
+            favorite_move_up: Binding::new(&["K"]),
+            favorite_move_down: Binding::new(&["J"]),
+            favorite_remove: Binding::new(&["x"]),
+            favorite_alias: Binding::new(&["a"]),
+            compare: Binding::new(&["c"]),
+            refresh: Binding::new(&["r"]),
+            export: Binding::new(&["e"]),
+            filter_hero: Binding::new(&["F"]),
+            filter_type: Binding::new(&["t"]),
+            filter_region: Binding::new(&["R"]),
+            stats_patch: Binding::new(&["p"]),
+            chart_view: Binding::new(&["w"]),
+            chart_next: Binding::new(&["v"]),
+            chart_prev: Binding::new(&["V"]),
+            laning: Binding::new(&["L"]),
+            hero_sort: Binding::new(&["s"]),
+            hero_role: Binding::new(&["o"]),
+            hero_bracket: Binding::new(&["b"]),
+            pro_view: Binding::new(&["P"]),
+            public_min_rank: Binding::new(&["m"]),
+            public_max_rank: Binding::new(&["M"]),
+            explorer_edit: Binding::new(&["i"]),
+            explorer_query: Binding::new(&["n"]),
+            explorer_save: Binding::new(&["S"]),
+            explorer_left: Binding::new(&["h"]),
+            explorer_right: Binding::new(&["l"]),
+            palette: Binding::new(&[":", "Ctrl+p"]),
+            recent_next: Binding::new(&["Ctrl+Tab"]),
+            recent_prev: Binding::new(&["Ctrl+BackTab"]),
+            cancel: Binding::new(&["Esc"]),
+            clear_input: None,
+            editing: EditingKeybinds::default(),
+        }
+    }
+}
+
+impl Default for EditingKeybinds {
+    fn default() -> Self {
+        Self {
+            submit: Binding::new(&["Enter"]),
+            cancel: Binding::new(&["Esc"]),
+            clear_input: Binding::new(&["Ctrl+u"]),
+            complete: Binding::new(&["Tab"]),
+            next: Binding::new(&["Down", "Ctrl+n"]),
+            prev: Binding::new(&["Up", "Ctrl+p"]),
+            help: Binding::new(&["?"]),
+            run: Binding::new(&["Ctrl+r", "F5"]),
         }
     }
 }
@@ -168,7 +696,7 @@ impl Default for ImageConfig {
 impl Default for UiConfig {
     fn default() -> Self {
         Self {
-            language: "zh".to_string(),
+            language: "auto".to_string(),
         }
     }
 }
@@ -177,10 +705,7 @@ impl Config {
     pub fn load_or_create() -> Result<(Self, PathBuf)> {
         let path = config_path()?;
         if path.exists() {
-            let content = fs::read_to_string(&path)
-                .with_context(|| format!("Failed reading {}", path.display()))?;
-            let parsed: Config = toml::from_str(&content)
-                .with_context(|| format!("Invalid config at {}", path.display()))?;
+            let parsed = Config::load(&path)?;
             Ok((parsed, path))
         } else {
             if let Some(parent) = path.parent() {
@@ -194,107 +719,221 @@ impl Config {
         }
     }
 
-    pub fn resolve_theme(&self) -> Theme {
-        match self.theme.name.as_str() {
-            "catppuccin" => Theme {
-                base: Color::Rgb(30, 30, 46),
-                text: Color::Rgb(205, 214, 244),
-                accent: Color::Rgb(137, 180, 250),
-                warn: Color::Rgb(243, 139, 168),
-                success: Color::Rgb(166, 227, 161),
-            },
-            "gruvbox" => Theme {
-                base: Color::Rgb(40, 40, 40),
-                text: Color::Rgb(235, 219, 178),
-                accent: Color::Rgb(250, 189, 47),
-                warn: Color::Rgb(251, 73, 52),
-                success: Color::Rgb(184, 187, 38),
-            },
-            _ => Theme {
-                base: Color::Black,
-                text: Color::White,
-                accent: Color::Cyan,
-                warn: Color::Red,
-                success: Color::Green,
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed reading {}", path.display()))?;
+        let mut config: Config =
+            toml::from_str(&content).with_context(|| format!("Invalid config at {}", path.display()))?;
+        if let Some(binding) = config.keybinds.clear_input.clone() {
+            config.keybinds.editing.clear_input = binding;
+        }
+        Ok(config)
+    }
+
+    pub fn resolve_theme(&self, detected: ColorDepth, background: Background) -> Result<Theme> {
+        let name = match self.theme.name.as_str() {
+            "auto" => match background {
+                Background::Dark => self.theme.dark.as_str(),
+                Background::Light => self.theme.light.as_str(),
             },
+            name => name,
+        };
+        let theme = match self.theme.palettes.get(name) {
+            Some(palette) => {
+                let parent = palette.extends.as_deref().unwrap_or(name);
+                palette
+                    .apply(Theme::builtin(parent))
+                    .with_context(|| format!("Invalid theme `{name}`"))?
+            }
+            None => Theme::builtin(name),
+        };
+        let depth = match self.theme.color_depth.to_ascii_lowercase().as_str() {
+            "truecolor" | "24bit" => ColorDepth::TrueColor,
+            "256" => ColorDepth::Ansi256,
+            "16" => ColorDepth::Ansi16,
+            "auto" => detected,
+            other => return Err(anyhow!("Unknown color_depth {other:?}, expected auto, truecolor, 256 or 16")),
+        };
+        Ok(theme.with_depth(depth))
+    }
+
+    pub fn theme_names(&self) -> Vec<String> {
+        let mut names = vec!["auto".to_string()];
+        names.extend(THEME_NAMES.iter().map(|name| name.to_string()));
+        for name in self.theme.palettes.keys() {
+            if !names.contains(name) {
+                names.push(name.clone());
+            }
         }
+        names
     }
 
     pub fn resolve_keybinds(&self) -> Result<ResolvedKeybinds> {
+        let mut normal = Vec::new();
+        for (name, binding) in self.keybinds.entries() {
+            for keys in &binding.0 {
+                let sequence = parse_key_sequence(keys)
+                    .with_context(|| format!("Invalid keybind `{name}`"))?;
+                normal.push((name, sequence));
+            }
+        }
+        let mut editing = Vec::new();
+        for (name, binding) in self.keybinds.editing.entries() {
+            for keys in &binding.0 {
+                let combo = parse_keycombo(keys)
+                    .with_context(|| format!("Invalid editing keybind `{name}`"))?;
+                editing.push((name, combo));
+            }
+        }
+
+        let mut conflicts = find_conflicts(&normal);
+        let editing_sequences: Vec<(&'static str, KeySequence)> = editing
+            .iter()
+            .map(|(name, combo)| (*name, vec![*combo]))
+            .collect();
+        conflicts.extend(
+            find_conflicts(&editing_sequences)
+                .into_iter()
+                .map(|conflict| format!("editing: {conflict}")),
+        );
+        let mut deprecated = Vec::new();
+        if self.keybinds.clear_input.is_some() {
+            deprecated.push("keybinds.clear_input → keybinds.editing.clear_input".to_string());
+        }
         Ok(ResolvedKeybinds {
-            search: parse_keycombo(&self.keybinds.search)?,
-            quit: parse_keycombo(&self.keybinds.quit)?,
-            up: parse_keycombo(&self.keybinds.up)?,
-            down: parse_keycombo(&self.keybinds.down)?,
-            select: parse_keycombo(&self.keybinds.select)?,
-            top: parse_keycombo(&self.keybinds.top)?,
-            bottom: parse_keycombo(&self.keybinds.bottom)?,
-            clear_input: parse_keycombo(&self.keybinds.clear_input)?,
-            tab_next: parse_keycombo(&self.keybinds.tab_next)?,
-            tab_prev: parse_keycombo(&self.keybinds.tab_prev)?,
-            help: parse_keycombo(&self.keybinds.help)?,
+            normal,
+            editing,
+            conflicts,
+            deprecated,
         })
     }
 }
 
+pub fn default_theme(detected: ColorDepth, background: Background) -> Theme {
+    let defaults = ThemeConfig::default();
+    let name = match background {
+        Background::Dark => defaults.dark,
+        Background::Light => defaults.light,
+    };
+    Theme::builtin(&name).with_depth(detected)
+}
+
+fn find_conflicts(bindings: &[(&'static str, KeySequence)]) -> Vec<String> {
+    let mut conflicts = Vec::new();
+    for (idx, (name, keys)) in bindings.iter().enumerate() {
+        for (other_name, other_keys) in &bindings[idx + 1..] {
+            let shared = keys.len().min(other_keys.len());
+            if name == other_name || keys[..shared] != other_keys[..shared] {
+                continue;
+            }
+            let shorter = if keys.len() <= other_keys.len() { keys } else { other_keys };
+            conflicts.push(format!("{} ({name}, {other_name})", format_sequence(shorter)));
+        }
+    }
+    conflicts
+}
+
+pub fn format_sequence(keys: &[KeyCombo]) -> String {
+    keys.iter()
+        .map(|combo| combo.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 pub fn config_path() -> Result<PathBuf> {
     let base = dirs::config_dir().ok_or_else(|| anyhow!("No config directory"))?;
     Ok(base.join("dota2_tui").join("config.toml"))
 }
 
+pub fn modified_at(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
 pub fn recent_log_path() -> Result<PathBuf> {
     let base = dirs::config_dir().ok_or_else(|| anyhow!("No config directory"))?;
     Ok(base.join("dota2_tui").join("recent.jsonl"))
 }
 
+pub fn favorites_path() -> Result<PathBuf> {
+    let base = dirs::config_dir().ok_or_else(|| anyhow!("No config directory"))?;
+    Ok(base.join("dota2_tui").join("favorites.json"))
+}
+
+pub fn queries_path() -> Result<PathBuf> {
+    let base = dirs::config_dir().ok_or_else(|| anyhow!("No config directory"))?;
+    Ok(base.join("dota2_tui").join("queries.json"))
+}
+
+pub fn locales_dir() -> Result<PathBuf> {
+    let base = dirs::config_dir().ok_or_else(|| anyhow!("No config directory"))?;
+    Ok(base.join("dota2_tui").join("locales"))
+}
+
+pub fn exports_dir() -> Result<PathBuf> {
+    let base = dirs::config_dir().ok_or_else(|| anyhow!("No config directory"))?;
+    Ok(base.join("dota2_tui").join("exports"))
+}
+
 pub fn cache_dir() -> Result<PathBuf> {
     let base = dirs::cache_dir().ok_or_else(|| anyhow!("No cache directory"))?;
     Ok(base.join("dota2_tui"))
 }
 
+fn parse_color(input: &str) -> Result<Color> {
+    Color::from_str(input.trim()).map_err(|_| anyhow!("Unknown color {input:?}, expected #rrggbb or a color name"))
+}
+
+fn parse_key_sequence(input: &str) -> Result<KeySequence> {
+    let keys: Vec<&str> = input.split_whitespace().collect();
+    if keys.is_empty() {
+        return Err(anyhow!("Empty keybind"));
+    }
+    keys.into_iter().map(parse_keycombo).collect()
+}
+
 fn parse_keycombo(input: &str) -> Result<KeyCombo> {
     let trimmed = input.trim();
     if trimmed.is_empty() {
         return Err(anyhow!("Empty keybind"));
     }
 
-    let parts: Vec<&str> = trimmed.split('+').collect();
-    let mut modifiers = KeyModifiers::empty();
-    let key_part = if parts.len() > 1 {
-        for part in &parts[..parts.len() - 1] {
-            match part.to_ascii_lowercase().as_str() {
-                "ctrl" | "control" => modifiers |= KeyModifiers::CONTROL,
-                "alt" => modifiers |= KeyModifiers::ALT,
-                "shift" => modifiers |= KeyModifiers::SHIFT,
-                other => return Err(anyhow!("Unknown modifier: {other}")),
-            }
-        }
-        parts[parts.len() - 1]
+    let (modifier_part, key_part) = if trimmed == "+" {
+        ("", "+")
+    } else if let Some(prefix) = trimmed.strip_suffix("++") {
+        (prefix, "+")
     } else {
-        parts[0]
+        trimmed.rsplit_once('+').unwrap_or(("", trimmed))
     };
-
-    let code = parse_keycode(key_part)?;
-
-    if parts.len() == 1 {
-        if let KeyCode::Char(c) = code {
-            if c.is_ascii_uppercase() {
-                modifiers |= KeyModifiers::SHIFT;
-            }
+    let mut modifiers = KeyModifiers::empty();
+    for part in modifier_part.split('+').filter(|part| !part.is_empty()) {
+        match part.trim().to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => modifiers |= KeyModifiers::CONTROL,
+            "alt" | "meta" => modifiers |= KeyModifiers::ALT,
+            "shift" => modifiers |= KeyModifiers::SHIFT,
+            other => return Err(anyhow!("Unknown modifier: {other}")),
         }
     }
 
-    Ok(KeyCombo { code, modifiers })
+    let code = parse_keycode(key_part)?;
+    Ok(KeyCombo::new(code, modifiers))
 }
 
 fn parse_keycode(input: &str) -> Result<KeyCode> {
     let trimmed = input.trim();
-    if trimmed.len() == 1 {
+    if trimmed.chars().count() == 1 {
         return Ok(KeyCode::Char(trimmed.chars().next().unwrap()));
     }
-    match trimmed.to_ascii_lowercase().as_str() {
-        "enter" => Ok(KeyCode::Enter),
+    let lower = trimmed.to_ascii_lowercase();
+    if let Some(number) = lower.strip_prefix('f')
+        && let Ok(n) = number.parse::<u8>()
+        && (1..=24).contains(&n)
+    {
+        return Ok(KeyCode::F(n));
+    }
+    match lower.as_str() {
+        "enter" | "return" => Ok(KeyCode::Enter),
         "tab" => Ok(KeyCode::Tab),
+        "backtab" => Ok(KeyCode::BackTab),
         "esc" | "escape" => Ok(KeyCode::Esc),
         "backspace" => Ok(KeyCode::Backspace),
         "up" => Ok(KeyCode::Up),
@@ -306,19 +945,116 @@ fn parse_keycode(input: &str) -> Result<KeyCode> {
         "pageup" => Ok(KeyCode::PageUp),
         "pagedown" => Ok(KeyCode::PageDown),
         "insert" => Ok(KeyCode::Insert),
-        "delete" => Ok(KeyCode::Delete),
+        "delete" | "del" => Ok(KeyCode::Delete),
         "space" => Ok(KeyCode::Char(' ')),
+        "plus" => Ok(KeyCode::Char('+')),
+        "minus" | "dash" => Ok(KeyCode::Char('-')),
+        "equal" | "equals" => Ok(KeyCode::Char('=')),
+        "comma" => Ok(KeyCode::Char(',')),
+        "period" | "dot" => Ok(KeyCode::Char('.')),
+        "slash" => Ok(KeyCode::Char('/')),
+        "backslash" => Ok(KeyCode::Char('\\')),
+        "semicolon" => Ok(KeyCode::Char(';')),
+        "colon" => Ok(KeyCode::Char(':')),
+        "quote" | "apostrophe" => Ok(KeyCode::Char('\'')),
+        "doublequote" => Ok(KeyCode::Char('"')),
+        "backtick" | "grave" => Ok(KeyCode::Char('`')),
+        "tilde" => Ok(KeyCode::Char('~')),
+        "pipe" | "bar" => Ok(KeyCode::Char('|')),
+        "less" => Ok(KeyCode::Char('<')),
+        "greater" => Ok(KeyCode::Char('>')),
+        "lbracket" => Ok(KeyCode::Char('[')),
+        "rbracket" => Ok(KeyCode::Char(']')),
+        "lbrace" => Ok(KeyCode::Char('{')),
+        "rbrace" => Ok(KeyCode::Char('}')),
+        "hash" => Ok(KeyCode::Char('#')),
         other => Err(anyhow!("Unknown key: {other}")),
     }
 }
 
 pub fn matches(combo: KeyCombo, code: KeyCode, modifiers: KeyModifiers) -> bool {
-    if combo.code != code {
-        return false;
+    combo == KeyCombo::new(code, modifiers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(c: char) -> KeyCombo {
+        KeyCombo::new(KeyCode::Char(c), KeyModifiers::empty())
     }
-    if combo.modifiers.is_empty() {
-        modifiers.is_empty()
-    } else {
-        combo.modifiers == modifiers
+
+    #[test]
+    fn parses_space_separated_sequences() {
+        assert_eq!(parse_key_sequence("g g").unwrap(), vec![key('g'), key('g')]);
+        assert_eq!(
+            parse_key_sequence("  Ctrl+w   j ").unwrap(),
+            vec![KeyCombo::new(KeyCode::Char('w'), KeyModifiers::CONTROL), key('j')]
+        );
+        assert_eq!(parse_key_sequence("Home").unwrap(), vec![KeyCombo::new(KeyCode::Home, KeyModifiers::empty())]);
+    }
+
+    #[test]
+    fn rejects_empty_unspaced_and_misspelled_sequences() {
+        assert!(parse_key_sequence("").is_err());
+        assert!(parse_key_sequence("   ").is_err());
+        assert!(parse_key_sequence("gg").is_err());
+        assert!(parse_key_sequence("entr").is_err());
+        assert!(parse_key_sequence("g entr").is_err());
+        assert!(parse_key_sequence("Hyper+g").is_err());
+    }
+
+    #[test]
+    fn parses_plus_and_shifted_keys() {
+        assert_eq!(parse_keycombo("+").unwrap(), key('+'));
+        assert_eq!(parse_keycombo("Ctrl++").unwrap(), KeyCombo::new(KeyCode::Char('+'), KeyModifiers::CONTROL));
+        assert_eq!(parse_keycombo("Shift+g").unwrap(), key('G'));
+        assert_eq!(parse_keycombo("F12").unwrap(), KeyCombo::new(KeyCode::F(12), KeyModifiers::empty()));
+        assert!(parse_keycombo("F25").is_err());
+    }
+
+    #[test]
+    fn reports_duplicate_and_prefix_conflicts() {
+        let bindings = vec![
+            ("top", vec![key('g'), key('g')]),
+            ("goto", vec![key('g')]),
+            ("down", vec![key('j')]),
+            ("next", vec![key('j')]),
+            ("up", vec![key('k')]),
+            ("up", vec![key('k')]),
+        ];
+        assert_eq!(find_conflicts(&bindings), vec!["g (top, goto)", "j (down, next)"]);
+    }
+
+    #[test]
+    fn default_keybinds_resolve_without_conflicts() {
+        let resolved = Config::default().resolve_keybinds().unwrap();
+        assert!(resolved.conflicts.is_empty(), "{:?}", resolved.conflicts);
+        assert!(resolved.deprecated.is_empty());
+        let top: Vec<&KeySequence> = resolved
+            .normal
+            .iter()
+            .filter(|(name, _)| *name == "top")
+            .map(|(_, keys)| keys)
+            .collect();
+        assert_eq!(top, [&vec![key('g'), key('g')], &vec![KeyCombo::new(KeyCode::Home, KeyModifiers::empty())]]);
+    }
+
+    #[test]
+    fn maps_rgb_to_the_nearest_ansi256_color() {
+        assert_eq!(nearest_ansi256((0, 0, 0)), 16);
+        assert_eq!(nearest_ansi256((255, 255, 255)), 231);
+        assert_eq!(nearest_ansi256((255, 0, 0)), 196);
+        assert_eq!(nearest_ansi256((95, 135, 175)), 67);
+        assert_eq!(nearest_ansi256((128, 128, 128)), 244);
+        assert_eq!(nearest_ansi256((48, 48, 48)), 236);
+        assert_eq!(nearest_ansi256((47, 0, 0)), 233);
+    }
+
+    #[test]
+    fn legacy_clear_input_is_reported_as_deprecated() {
+        let config: Config = toml::from_str("[keybinds]\nclear_input = \"Ctrl+u\"\n").unwrap();
+        let resolved = config.resolve_keybinds().unwrap();
+        assert_eq!(resolved.deprecated.len(), 1);
     }
 }
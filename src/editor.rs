@@ -0,0 +1,185 @@
+use unicode_width::UnicodeWidthStr;
+
+#[derive(Default)]
+pub struct TextEditor {
+    pub text: String,
+    pub cursor: usize,
+}
+
+impl TextEditor {
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+        self.cursor = self.text.len();
+    }
+
+    pub fn clear(&mut self) {
+        self.text.clear();
+        self.cursor = 0;
+    }
+
+    pub fn insert(&mut self, value: &str) {
+        self.text.insert_str(self.cursor, value);
+        self.cursor += value.len();
+    }
+
+    pub fn backspace(&mut self) -> bool {
+        let Some(prev) = self.text[..self.cursor].chars().next_back() else {
+            return false;
+        };
+        self.cursor -= prev.len_utf8();
+        self.text.remove(self.cursor);
+        true
+    }
+
+    pub fn delete(&mut self) -> bool {
+        if self.cursor >= self.text.len() {
+            return false;
+        }
+        self.text.remove(self.cursor);
+        true
+    }
+
+    pub fn move_left(&mut self) {
+        if let Some(prev) = self.text[..self.cursor].chars().next_back() {
+            self.cursor -= prev.len_utf8();
+        }
+    }
+
+    pub fn move_right(&mut self) {
+        if let Some(next) = self.text[self.cursor..].chars().next() {
+            self.cursor += next.len_utf8();
+        }
+    }
+
+    pub fn home(&mut self) {
+        self.cursor = self.line_start(self.cursor);
+    }
+
+    pub fn end(&mut self) {
+        self.cursor = self.line_end(self.cursor);
+    }
+
+    pub fn move_up(&mut self) {
+        let start = self.line_start(self.cursor);
+        if start == 0 {
+            self.cursor = 0;
+            return;
+        }
+        let column = self.text[start..self.cursor].chars().count();
+        let prev_start = self.line_start(start - 1);
+        self.cursor = self.offset_in_line(prev_start, column);
+    }
+
+    pub fn move_down(&mut self) {
+        let end = self.line_end(self.cursor);
+        if end >= self.text.len() {
+            self.cursor = self.text.len();
+            return;
+        }
+        let column = self.text[self.line_start(self.cursor)..self.cursor].chars().count();
+        self.cursor = self.offset_in_line(end + 1, column);
+    }
+
+    pub fn cursor_position(&self) -> (usize, usize) {
+        let before = &self.text[..self.cursor];
+        let row = before.matches('\n').count();
+        let column = before[self.line_start(self.cursor)..].width();
+        (row, column)
+    }
+
+    fn line_start(&self, offset: usize) -> usize {
+        self.text[..offset].rfind('\n').map_or(0, |idx| idx + 1)
+    }
+
+    fn line_end(&self, offset: usize) -> usize {
+        self.text[offset..].find('\n').map_or(self.text.len(), |idx| offset + idx)
+    }
+
+    fn offset_in_line(&self, start: usize, column: usize) -> usize {
+        let end = self.line_end(start);
+        self.text[start..end]
+            .char_indices()
+            .nth(column)
+            .map_or(end, |(idx, _)| start + idx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn editor(text: &str, cursor: usize) -> TextEditor {
+        TextEditor {
+            text: text.to_string(),
+            cursor,
+        }
+    }
+
+    #[test]
+    fn backspace_and_delete_remove_whole_characters() {
+        let mut editor = editor("日本語", "日本語".len());
+        assert!(editor.backspace());
+        assert_eq!((editor.text.as_str(), editor.cursor), ("日本", 6));
+        editor.cursor = 0;
+        assert!(!editor.backspace());
+        assert!(editor.delete());
+        assert_eq!((editor.text.as_str(), editor.cursor), ("本", 0));
+        editor.end();
+        assert!(!editor.delete());
+    }
+
+    #[test]
+    fn empty_editor_ignores_edits_and_moves() {
+        let mut editor = TextEditor::default();
+        assert!(!editor.backspace());
+        assert!(!editor.delete());
+        editor.move_left();
+        editor.move_right();
+        editor.move_up();
+        editor.move_down();
+        assert_eq!(editor.cursor, 0);
+        assert_eq!(editor.cursor_position(), (0, 0));
+    }
+
+    #[test]
+    fn horizontal_moves_step_over_multibyte_characters() {
+        let mut editor = editor("aé日", 0);
+        editor.move_right();
+        editor.move_right();
+        assert_eq!(editor.cursor, 3);
+        editor.move_right();
+        editor.move_right();
+        assert_eq!(editor.cursor, editor.text.len());
+        editor.move_left();
+        assert_eq!(editor.cursor, 3);
+        editor.insert("ß");
+        assert_eq!((editor.text.as_str(), editor.cursor), ("aéß日", 5));
+    }
+
+    #[test]
+    fn vertical_moves_keep_the_character_column() {
+        let mut editor = editor("héllo\nwörld\nx", "héllo\nwö".len());
+        editor.move_up();
+        assert_eq!(editor.cursor, "hé".len());
+        editor.move_up();
+        assert_eq!(editor.cursor, 0);
+        editor.cursor = "hé".len();
+        editor.move_down();
+        assert_eq!(editor.cursor, "héllo\nwö".len());
+        editor.move_down();
+        assert_eq!(editor.cursor, editor.text.len());
+        editor.move_down();
+        assert_eq!(editor.cursor, editor.text.len());
+    }
+
+    #[test]
+    fn cursor_position_counts_display_width() {
+        let editor = editor("SELECT\n日本 x", "SELECT\n日本".len());
+        assert_eq!(editor.cursor_position(), (1, 4));
+        let mut editor = editor;
+        editor.home();
+        assert_eq!(editor.cursor_position(), (1, 0));
+        editor.end();
+        assert_eq!(editor.cursor_position(), (1, 6));
+    }
+}
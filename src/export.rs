@@ -0,0 +1,136 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+
+use crate::app::App;
+use crate::config::exports_dir;
+use crate::i18n::I18n;
+use crate::ui::is_win;
+
+pub fn export_matches(app: &App, i18n: &I18n) -> Result<PathBuf> {
+    let account_id = app.account_id.ok_or_else(|| anyhow!("No player loaded"))?;
+    if app.matches.is_empty() {
+        return Err(anyhow!("No matches to export"));
+    }
+    let headers = [
+        "match_id",
+        "start_time",
+        "hero",
+        "result",
+        "game_mode",
+        "lobby_type",
+        "region",
+        "patch",
+        "duration_secs",
+        "kills",
+        "deaths",
+        "assists",
+        "gold_per_min",
+        "xp_per_min",
+    ];
+    let optional = |value: Option<i32>| value.map(|v| v.to_string()).unwrap_or_default();
+    let rows: Vec<Vec<String>> = app
+        .matches
+        .iter()
+        .map(|m| {
+            vec![
+                m.match_id.to_string(),
+                m.start_time
+                    .and_then(|ts| chrono::DateTime::from_timestamp(ts, 0))
+                    .map(|dt| dt.to_rfc3339())
+                    .unwrap_or_default(),
+                app.hero_name(m.hero_id, i18n),
+                if is_win(m) { "W" } else { "L" }.to_string(),
+                app.game_mode_name(m.game_mode, i18n),
+                app.lobby_type_name(m.lobby_type, i18n),
+                app.region_name(app.match_region(m), i18n),
+                app.match_patch(m).map(|patch| patch.name.clone()).unwrap_or_default(),
+                m.duration.to_string(),
+                optional(m.kills),
+                optional(m.deaths),
+                optional(m.assists),
+                optional(m.gold_per_min),
+                optional(m.xp_per_min),
+            ]
+        })
+        .collect();
+
+    let stamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+    let path = exports_dir()?.join(format!("matches_{account_id}_{stamp}.csv"));
+    write_csv(&path, &headers, &rows)?;
+    Ok(path)
+}
+
+pub fn export_explorer(app: &App, i18n: &I18n) -> Result<PathBuf> {
+    let table = app
+        .explorer_result
+        .as_ref()
+        .filter(|table| !table.rows.is_empty())
+        .ok_or_else(|| anyhow!(i18n.export_no_rows().to_string()))?;
+    let headers: Vec<&str> = table.columns.iter().map(String::as_str).collect();
+    let stamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+    let path = exports_dir()?.join(format!("explorer_{stamp}.csv"));
+    write_csv(&path, &headers, &table.rows)?;
+    Ok(path)
+}
+
+pub fn write_csv(path: &Path, headers: &[&str], rows: &[Vec<String>]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed creating {}", parent.display()))?;
+    }
+    let mut out = String::new();
+    out.push_str(
+        &headers
+            .iter()
+            .map(|h| csv_escape(h))
+            .collect::<Vec<_>>()
+            .join(","),
+    );
+    out.push('\n');
+    for row in rows {
+        out.push_str(&row.iter().map(|v| csv_escape(v)).collect::<Vec<_>>().join(","));
+        out.push('\n');
+    }
+    fs::write(path, out).with_context(|| format!("Failed writing {}", path.display()))
+}
+
+fn csv_escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_only_values_that_need_quoting() {
+        assert_eq!(csv_escape(""), "");
+        assert_eq!(csv_escape("Anti-Mage"), "Anti-Mage");
+        assert_eq!(csv_escape("天怒法师"), "天怒法师");
+        assert_eq!(csv_escape("a,b"), "\"a,b\"");
+        assert_eq!(csv_escape("say \"gg\""), "\"say \"\"gg\"\"\"");
+        assert_eq!(csv_escape("line\nbreak"), "\"line\nbreak\"");
+        assert_eq!(csv_escape("cr\r"), "\"cr\r\"");
+    }
+
+    #[test]
+    fn writes_headers_and_escaped_rows() {
+        let path = std::env::temp_dir()
+            .join(format!("dota2_tui_export_{}", std::process::id()))
+            .join("rows.csv");
+        let rows = vec![
+            vec!["1".to_string(), "gg, wp".to_string()],
+            vec!["2".to_string(), "\"quoted\"\nnext".to_string()],
+        ];
+        write_csv(&path, &["id", "chat"], &rows).unwrap();
+        let written = fs::read_to_string(&path).unwrap();
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+        assert_eq!(written, "id,chat\n1,\"gg, wp\"\n2,\"\"\"quoted\"\"\nnext\"\n");
+    }
+}
@@ -1,366 +1,494 @@
-#[derive(Clone, Copy)]
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Display;
+use std::fs;
+
+use serde::Deserialize;
+
+use crate::actions::{Action, ActionGroup, EditAction};
+use crate::app::{HeroSort, MatchKind, ProView, WardKind, HERO_ROLES};
+use crate::config::locales_dir;
+use crate::models::{HeroStat, ItemConstant};
+
+const MEDALS: [&str; 8] = [
+    "herald", "guardian", "crusader", "archon", "legend", "ancient", "divine", "immortal",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Language {
     En,
     Zh,
+    Ru,
+    PtBr,
+    Es,
+}
+
+impl Language {
+    pub const ALL: [Language; 5] = [
+        Language::En,
+        Language::Zh,
+        Language::Ru,
+        Language::PtBr,
+        Language::Es,
+    ];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::En => "en",
+            Language::Zh => "zh-CN",
+            Language::Ru => "ru",
+            Language::PtBr => "pt-BR",
+            Language::Es => "es",
+        }
+    }
+
+    pub fn next(self) -> Language {
+        let index = Language::ALL.iter().position(|lang| *lang == self).unwrap_or(0);
+        Language::ALL[(index + 1) % Language::ALL.len()]
+    }
+
+    fn catalog(self) -> &'static str {
+        match self {
+            Language::En => include_str!("../locales/en.toml"),
+            Language::Zh => include_str!("../locales/zh-CN.toml"),
+            Language::Ru => include_str!("../locales/ru.toml"),
+            Language::PtBr => include_str!("../locales/pt-BR.toml"),
+            Language::Es => include_str!("../locales/es.toml"),
+        }
+    }
+
+    fn names(self) -> Option<&'static str> {
+        match self {
+            Language::Zh => Some(include_str!("../locales/names/zh-CN.toml")),
+            Language::En | Language::Ru | Language::PtBr | Language::Es => None,
+        }
+    }
+
+    fn parse(value: &str) -> Option<Language> {
+        let value = value.split(['.', '@']).next().unwrap_or_default();
+        let value = value.to_ascii_lowercase().replace('_', "-");
+        let primary = value.split('-').next().unwrap_or_default();
+        match primary {
+            "en" => Some(Language::En),
+            "zh" | "cn" => Some(Language::Zh),
+            "ru" => Some(Language::Ru),
+            "pt" => Some(Language::PtBr),
+            "es" => Some(Language::Es),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct NameCatalog {
+    heroes: HashMap<String, HeroNames>,
+    items: HashMap<String, String>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct HeroNames {
+    name: Option<String>,
+    aliases: Vec<String>,
+}
+
+impl NameCatalog {
+    fn load(lang: Language) -> Self {
+        let mut catalog: NameCatalog = lang
+            .names()
+            .and_then(|contents| toml::from_str(contents).ok())
+            .unwrap_or_default();
+        if let Ok(dir) = locales_dir()
+            && let Ok(contents) =
+                fs::read_to_string(dir.join("names").join(format!("{}.toml", lang.code())))
+            && let Ok(user) = toml::from_str::<NameCatalog>(&contents)
+        {
+            catalog.heroes.extend(user.heroes);
+            catalog.items.extend(user.items);
+        }
+        catalog
+    }
 }
 
 pub struct I18n {
     lang: Language,
+    messages: HashMap<String, String>,
+    names: NameCatalog,
+    hero_terms: HashMap<String, Vec<String>>,
 }
 
 impl I18n {
     pub fn new(lang: Language) -> Self {
-        Self { lang }
+        let mut messages = HashMap::new();
+        merge_catalog(&mut messages, Language::En.catalog());
+        if lang != Language::En {
+            merge_catalog(&mut messages, lang.catalog());
+        }
+        if let Ok(dir) = locales_dir()
+            && let Ok(contents) = fs::read_to_string(dir.join(format!("{}.toml", lang.code())))
+        {
+            merge_catalog(&mut messages, &contents);
+        }
+        let mut names = NameCatalog::default();
+        let mut hero_terms: HashMap<String, Vec<String>> = HashMap::new();
+        for candidate in Language::ALL {
+            let catalog = NameCatalog::load(candidate);
+            for (key, hero) in &catalog.heroes {
+                hero_terms
+                    .entry(key.clone())
+                    .or_default()
+                    .extend(hero.name.iter().chain(&hero.aliases).map(|term| term.to_lowercase()));
+            }
+            if candidate == lang {
+                names = catalog;
+            }
+        }
+        Self {
+            lang,
+            messages,
+            names,
+            hero_terms,
+        }
     }
 
     pub fn language_from_config(value: &str) -> Language {
-        match value.to_ascii_lowercase().as_str() {
-            "zh" | "zh-cn" | "zh_cn" | "cn" => Language::Zh,
-            _ => Language::En,
+        let value = value.trim();
+        if !value.is_empty() && !value.eq_ignore_ascii_case("auto") {
+            return Language::parse(value).unwrap_or(Language::En);
         }
+        let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| env::var(name).ok())
+            .find(|value| !value.is_empty());
+        match locale.as_deref() {
+            None | Some("C") | Some("POSIX") => Language::Zh,
+            Some(locale) if locale.starts_with("C.") => Language::Zh,
+            Some(locale) => Language::parse(locale).unwrap_or(Language::En),
+        }
+    }
+
+    pub fn language(&self) -> Language {
+        self.lang
+    }
+
+    pub fn hero_name<'a>(&'a self, hero: &'a HeroStat) -> &'a str {
+        self.names
+            .heroes
+            .get(hero_key(&hero.name))
+            .and_then(|names| names.name.as_deref())
+            .unwrap_or(&hero.localized_name)
+    }
+
+    pub fn hero_matches(&self, hero: &HeroStat, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        let key = hero_key(&hero.name);
+        hero.localized_name.to_lowercase().contains(&query)
+            || key.replace('_', " ").contains(&query)
+            || self
+                .hero_terms
+                .get(key)
+                .is_some_and(|terms| terms.iter().any(|term| term.contains(&query)))
+    }
+
+    pub fn item_name<'a>(&'a self, item: &'a ItemConstant) -> &'a str {
+        self.names
+            .items
+            .get(&item.key)
+            .map(String::as_str)
+            .or(item.dname.as_deref())
+            .unwrap_or(&item.key)
+    }
+
+    fn text<'a>(&'a self, key: &'a str) -> &'a str {
+        self.messages.get(key).map(String::as_str).unwrap_or(key)
     }
 
-    pub fn is_zh(&self) -> bool {
-        matches!(self.lang, Language::Zh)
+    fn format(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        args.iter().fold(self.text(key).to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{name}}}"), &value.to_string())
+        })
     }
 
     pub fn title_results(&self) -> &str {
-        match self.lang {
-            Language::En => "Search Results",
-            Language::Zh => "搜索结果",
-        }
+        self.text("title_results")
     }
 
     pub fn title_matches(&self) -> &str {
-        match self.lang {
-            Language::En => "Matches",
-            Language::Zh => "比赛列表",
-        }
+        self.text("title_matches")
+    }
+
+    pub fn title_matches_filtered(&self, filter: &str) -> String {
+        self.format("title_matches_filtered", &[("filter", &filter)])
     }
 
     pub fn title_match_detail(&self) -> &str {
-        match self.lang {
-            Language::En => "Match Detail",
-            Language::Zh => "比赛详情",
-        }
+        self.text("title_match_detail")
     }
 
     pub fn title_recent(&self) -> &str {
-        match self.lang {
-            Language::En => "Recent",
-            Language::Zh => "最近搜索",
-        }
+        self.text("title_recent")
+    }
+
+    pub fn title_favorites(&self) -> &str {
+        self.text("title_favorites")
+    }
+
+    pub fn no_favorites(&self) -> &str {
+        self.text("no_favorites")
+    }
+
+    pub fn input_compare(&self) -> &str {
+        self.text("input_compare")
+    }
+
+    pub fn input_alias(&self) -> &str {
+        self.text("input_alias")
+    }
+
+    pub fn input_query_name(&self) -> &str {
+        self.text("input_query_name")
+    }
+
+    pub fn input_hero_filter(&self) -> &str {
+        self.text("input_hero_filter")
     }
 
     pub fn title_profile(&self) -> &str {
-        match self.lang {
-            Language::En => "Profile",
-            Language::Zh => "玩家信息",
-        }
+        self.text("title_profile")
     }
 
     pub fn title_status(&self) -> &str {
-        match self.lang {
-            Language::En => "Status",
-            Language::Zh => "状态",
-        }
+        self.text("title_status")
     }
 
     pub fn title_views(&self) -> &str {
-        match self.lang {
-            Language::En => "Views",
-            Language::Zh => "视图",
-        }
+        self.text("title_views")
     }
 
     pub fn tab_overview(&self) -> &str {
-        match self.lang {
-            Language::En => "Overview",
-            Language::Zh => "总览",
-        }
+        self.text("tab_overview")
     }
 
     pub fn tab_matches(&self) -> &str {
-        match self.lang {
-            Language::En => "Matches",
-            Language::Zh => "比赛",
-        }
+        self.text("tab_matches")
     }
 
     pub fn tab_stats(&self) -> &str {
-        match self.lang {
-            Language::En => "Stats",
-            Language::Zh => "统计",
-        }
+        self.text("tab_stats")
+    }
+
+    pub fn tab_charts(&self) -> &str {
+        self.text("tab_charts")
+    }
+
+    pub fn tab_heroes(&self) -> &str {
+        self.text("tab_heroes")
+    }
+
+    pub fn tab_pro(&self) -> &str {
+        self.text("tab_pro")
+    }
+
+    pub fn tab_live(&self) -> &str {
+        self.text("tab_live")
+    }
+
+    pub fn tab_public(&self) -> &str {
+        self.text("tab_public")
+    }
+
+    pub fn tab_explorer(&self) -> &str {
+        self.text("tab_explorer")
+    }
+
+    pub fn tab_compare(&self) -> &str {
+        self.text("tab_compare")
     }
 
     pub fn table_hero(&self) -> &str {
-        match self.lang {
-            Language::En => "Hero",
-            Language::Zh => "英雄",
-        }
+        self.text("table_hero")
     }
 
     pub fn table_player(&self) -> &str {
-        match self.lang {
-            Language::En => "Player",
-            Language::Zh => "玩家",
-        }
+        self.text("table_player")
     }
 
     pub fn table_result(&self) -> &str {
-        match self.lang {
-            Language::En => "W/L",
-            Language::Zh => "胜败",
-        }
+        self.text("table_result")
     }
 
     pub fn table_mode(&self) -> &str {
-        match self.lang {
-            Language::En => "Mode",
-            Language::Zh => "模式",
-        }
+        self.text("table_mode")
     }
 
     pub fn table_duration(&self) -> &str {
-        match self.lang {
-            Language::En => "Dur",
-            Language::Zh => "时长",
-        }
+        self.text("table_duration")
     }
 
     pub fn table_time(&self) -> &str {
-        match self.lang {
-            Language::En => "Time",
-            Language::Zh => "时间",
-        }
+        self.text("table_time")
     }
 
     pub fn table_k(&self) -> &str {
-        match self.lang {
-            Language::En => "K",
-            Language::Zh => "击杀",
-        }
+        self.text("table_k")
     }
 
     pub fn table_d(&self) -> &str {
-        match self.lang {
-            Language::En => "D",
-            Language::Zh => "死亡",
-        }
+        self.text("table_d")
     }
 
     pub fn table_a(&self) -> &str {
-        match self.lang {
-            Language::En => "A",
-            Language::Zh => "助攻",
-        }
+        self.text("table_a")
     }
 
     pub fn table_gpm(&self) -> &str {
-        match self.lang {
-            Language::En => "GPM",
-            Language::Zh => "金钱",
-        }
+        self.text("table_gpm")
     }
 
     pub fn table_xpm(&self) -> &str {
-        match self.lang {
-            Language::En => "XPM",
-            Language::Zh => "经验",
-        }
+        self.text("table_xpm")
     }
 
     pub fn table_net(&self) -> &str {
-        match self.lang {
-            Language::En => "NET",
-            Language::Zh => "净值",
-        }
+        self.text("table_net")
+    }
+
+    pub fn table_type(&self) -> &str {
+        self.text("table_type")
+    }
+
+    pub fn table_region(&self) -> &str {
+        self.text("table_region")
+    }
+
+    pub fn table_patch(&self) -> &str {
+        self.text("table_patch")
+    }
+
+    pub fn table_games(&self) -> &str {
+        self.text("table_games")
+    }
+
+    pub fn table_winrate(&self) -> &str {
+        self.text("table_winrate")
+    }
+
+    pub fn table_kda(&self) -> &str {
+        self.text("table_kda")
     }
 
     pub fn table_items(&self) -> &str {
-        match self.lang {
-            Language::En => "Items",
-            Language::Zh => "物品",
-        }
+        self.text("table_items")
     }
 
     pub fn result_win(&self) -> &str {
-        match self.lang {
-            Language::En => "W",
-            Language::Zh => "胜",
-        }
+        self.text("result_win")
     }
 
     pub fn result_loss(&self) -> &str {
-        match self.lang {
-            Language::En => "L",
-            Language::Zh => "败",
-        }
+        self.text("result_loss")
     }
 
     pub fn time_now(&self) -> &str {
-        match self.lang {
-            Language::En => "now",
-            Language::Zh => "刚刚",
-        }
+        self.text("time_now")
     }
 
     pub fn time_minutes(&self, value: i64) -> String {
-        match self.lang {
-            Language::En => format!("{value}m"),
-            Language::Zh => format!("{value}分钟"),
-        }
+        self.format("time_minutes", &[("value", &value)])
     }
 
     pub fn time_hours(&self, value: i64) -> String {
-        match self.lang {
-            Language::En => format!("{value}h"),
-            Language::Zh => format!("{value}小时"),
-        }
+        self.format("time_hours", &[("value", &value)])
     }
 
     pub fn time_days(&self, value: i64) -> String {
-        match self.lang {
-            Language::En => format!("{value}d"),
-            Language::Zh => format!("{value}天"),
-        }
+        self.format("time_days", &[("value", &value)])
     }
 
     pub fn match_wait(&self) -> &str {
-        match self.lang {
-            Language::En => "Select a match and press Enter",
-            Language::Zh => "选择一场比赛并回车查看",
-        }
+        self.text("match_wait")
     }
 
     pub fn loading_detail(&self) -> &str {
-        match self.lang {
-            Language::En => "Loading match details...",
-            Language::Zh => "加载比赛详情中...",
-        }
+        self.text("loading_detail")
     }
 
 
     pub fn no_recent(&self) -> &str {
-        match self.lang {
-            Language::En => "No recent searches",
-            Language::Zh => "暂无搜索记录",
-        }
+        self.text("no_recent")
     }
 
     pub fn keybind_title(&self) -> &str {
-        match self.lang {
-            Language::En => "Keybinds",
-            Language::Zh => "快捷键",
-        }
+        self.text("keybind_title")
     }
 
     pub fn search_hint(&self) -> &str {
-        match self.lang {
-            Language::En => "Search by account_id or SteamID64 only.\nExample: 135664392",
-            Language::Zh => "仅支持 account_id 或 SteamID64 搜索\n示例: 135664392",
-        }
+        self.text("search_hint")
     }
 
     pub fn banner_subtitle(&self) -> &str {
-        match self.lang {
-            Language::En => "TUI data explorer powered by OpenDota",
-            Language::Zh => "TUI 数据探索器 powered by OpenDota",
-        }
+        self.text("banner_subtitle")
     }
 
     pub fn input_search(&self, editing: bool) -> &str {
-        match (self.lang, editing) {
-            (Language::En, true) => "Search (editing)",
-            (Language::En, false) => "Search (/)",
-            (Language::Zh, true) => "搜索（输入中）",
-            (Language::Zh, false) => "搜索（/）",
+        if editing {
+            self.text("input_search_editing")
+        } else {
+            self.text("input_search")
         }
     }
 
     pub fn title_quick_stats(&self) -> &str {
-        match self.lang {
-            Language::En => "Quick stats",
-            Language::Zh => "快速统计",
-        }
+        self.text("title_quick_stats")
     }
 
     pub fn title_avatar(&self) -> &str {
-        match self.lang {
-            Language::En => "Avatar",
-            Language::Zh => "头像",
-        }
+        self.text("title_avatar")
     }
 
     pub fn title_loadout(&self) -> &str {
-        match self.lang {
-            Language::En => "Loadout",
-            Language::Zh => "装备",
-        }
+        self.text("title_loadout")
     }
 
     pub fn title_hints(&self) -> &str {
-        match self.lang {
-            Language::En => "Hints",
-            Language::Zh => "提示",
-        }
+        self.text("title_hints")
     }
 
     pub fn hint_tabs(&self) -> &str {
-        match self.lang {
-            Language::En => "Use arrow keys to switch tabs",
-            Language::Zh => "使用左右方向键切换标签",
-        }
+        self.text("hint_tabs")
     }
 
     pub fn title_winrate(&self) -> &str {
-        match self.lang {
-            Language::En => "Winrate",
-            Language::Zh => "胜率",
-        }
+        self.text("title_winrate")
     }
 
     pub fn title_recent_results(&self) -> &str {
-        match self.lang {
-            Language::En => "Recent results",
-            Language::Zh => "近期结果",
-        }
+        self.text("title_recent_results")
     }
 
     pub fn title_summary(&self) -> &str {
-        match self.lang {
-            Language::En => "Summary",
-            Language::Zh => "汇总",
-        }
+        self.text("title_summary")
+    }
+
+    pub fn title_heroes(&self) -> &str {
+        self.text("title_heroes")
     }
 
     pub fn label_name(&self) -> &str {
-        match self.lang {
-            Language::En => "Name",
-            Language::Zh => "昵称",
-        }
+        self.text("label_name")
     }
 
     pub fn label_steamid(&self) -> &str {
-        match self.lang {
-            Language::En => "SteamID64",
-            Language::Zh => "SteamID64",
-        }
+        self.text("label_steamid")
     }
 
     pub fn label_mmr(&self) -> &str {
-        match self.lang {
-            Language::En => "MMR estimate",
-            Language::Zh => "预估分数",
-        }
+        self.text("label_mmr")
     }
 
     pub fn unknown(&self) -> &str {
-        match self.lang {
-            Language::En => "Unknown",
-            Language::Zh => "未知",
-        }
+        self.text("unknown")
     }
 
     pub fn placeholder_dash(&self) -> &str {
@@ -368,263 +496,910 @@ impl I18n {
     }
 
     pub fn status_ready(&self) -> &str {
-        match self.lang {
-            Language::En => "Press / to search by SteamID64 or account_id",
-            Language::Zh => "按 / 输入 SteamID64 或 account_id 搜索",
-        }
+        self.text("status_ready")
     }
 
     pub fn loading_player(&self) -> &str {
-        match self.lang {
-            Language::En => "Loading...",
-            Language::Zh => "加载中...",
-        }
+        self.text("loading_player")
     }
 
     pub fn no_player_loaded(&self) -> &str {
-        match self.lang {
-            Language::En => "No player loaded",
-            Language::Zh => "未加载玩家",
-        }
+        self.text("no_player_loaded")
     }
 
     pub fn quick_stats_format(&self, total: usize, wins: usize) -> String {
-        match self.lang {
-            Language::En => format!(
-                "Recent matches: {total}\nWins: {wins}\nLosses: {}",
-                total.saturating_sub(wins)
-            ),
-            Language::Zh => format!(
-                "最近比赛: {total}\n胜场: {wins}\n败场: {}",
-                total.saturating_sub(wins)
-            ),
-        }
-    }
-
-    pub fn stats_summary_format(&self, total: usize, wins: usize, winrate: f64) -> String {
-        match self.lang {
-            Language::En => {
-                format!("Total: {total}\nWins: {wins}\nWinrate: {winrate:.1}%")
-            }
-            Language::Zh => {
-                format!("总场次: {total}\n胜场: {wins}\n胜率: {winrate:.1}%")
-            }
-        }
+        let losses = total.saturating_sub(wins);
+        self.format(
+            "quick_stats_format",
+            &[("total", &total), ("wins", &wins), ("losses", &losses)],
+        )
+    }
+
+    pub fn stats_summary_format(
+        &self,
+        total: usize,
+        wins: usize,
+        winrate: f64,
+        kda: f64,
+        kda_split: &str,
+    ) -> String {
+        let winrate = format!("{winrate:.1}");
+        let kda = format!("{kda:.2}");
+        self.format(
+            "stats_summary_format",
+            &[
+                ("total", &total),
+                ("wins", &wins),
+                ("winrate", &winrate),
+                ("kda", &kda),
+                ("kda_split", &kda_split),
+            ],
+        )
     }
 
     pub fn status_search_cancelled(&self) -> &str {
-        match self.lang {
-            Language::En => "Search cancelled",
-            Language::Zh => "已取消搜索",
-        }
+        self.text("status_search_cancelled")
     }
 
     pub fn status_need_id(&self) -> &str {
-        match self.lang {
-            Language::En => "Enter a SteamID64 or account_id",
-            Language::Zh => "请输入 SteamID64 或 account_id",
-        }
+        self.text("status_need_id")
     }
 
     pub fn status_invalid_id(&self) -> &str {
-        match self.lang {
-            Language::En => "Use account_id or SteamID64",
-            Language::Zh => "仅支持 account_id 或 SteamID64",
-        }
+        self.text("status_invalid_id")
     }
 
     pub fn status_loading_player(&self, account_id: u32) -> String {
-        match self.lang {
-            Language::En => format!("Loading player {account_id}..."),
-            Language::Zh => format!("加载玩家 {account_id}..."),
-        }
+        self.format("status_loading_player", &[("account_id", &account_id)])
     }
 
     pub fn status_loading_match(&self, match_id: u64) -> String {
-        match self.lang {
-            Language::En => format!("Loading match {match_id}..."),
-            Language::Zh => format!("加载比赛 {match_id}..."),
-        }
+        self.format("status_loading_match", &[("match_id", &match_id)])
     }
 
     pub fn status_hero_loaded(&self) -> &str {
-        match self.lang {
-            Language::En => "Hero data loaded",
-            Language::Zh => "英雄数据已加载",
-        }
+        self.text("status_hero_loaded")
     }
 
     pub fn status_hero_failed(&self, err: &str) -> String {
-        match self.lang {
-            Language::En => format!("Hero load failed: {err}"),
-            Language::Zh => format!("英雄数据加载失败: {err}"),
-        }
+        self.format("status_hero_failed", &[("err", &err)])
     }
 
     pub fn status_match_loaded(&self) -> &str {
-        match self.lang {
-            Language::En => "Match details loaded",
-            Language::Zh => "比赛详情已加载",
-        }
+        self.text("status_match_loaded")
     }
 
     pub fn status_match_failed(&self, err: &str) -> String {
-        match self.lang {
-            Language::En => format!("Match load failed: {err}"),
-            Language::Zh => format!("比赛加载失败: {err}"),
-        }
+        self.format("status_match_failed", &[("err", &err)])
     }
 
     pub fn status_search_failed(&self, err: &str) -> String {
-        match self.lang {
-            Language::En => format!("Search failed: {err}"),
-            Language::Zh => format!("搜索失败: {err}"),
-        }
+        self.format("status_search_failed", &[("err", &err)])
     }
 
     pub fn status_matches_loaded(&self) -> &str {
-        match self.lang {
-            Language::En => "Matches loaded. Use j/k and Enter for details",
-            Language::Zh => "比赛列表已加载，使用 j/k 和回车查看详情",
-        }
+        self.text("status_matches_loaded")
     }
 
     pub fn status_no_matches(&self) -> &str {
-        match self.lang {
-            Language::En => "No matches found",
-            Language::Zh => "未找到比赛",
-        }
+        self.text("status_no_matches")
     }
 
     pub fn status_matches_failed(&self, err: &str) -> String {
-        match self.lang {
-            Language::En => format!("Matches load failed: {err}"),
-            Language::Zh => format!("比赛列表加载失败: {err}"),
-        }
+        self.format("status_matches_failed", &[("err", &err)])
     }
 
     pub fn status_profile_failed(&self, err: &str) -> String {
-        match self.lang {
-            Language::En => format!("Profile load failed: {err}"),
-            Language::Zh => format!("玩家信息加载失败: {err}"),
-        }
+        self.format("status_profile_failed", &[("err", &err)])
     }
 
-    pub fn status_image_failed(&self, err: &str) -> String {
-        match self.lang {
-            Language::En => format!("Image load failed: {err}"),
-            Language::Zh => format!("图片加载失败: {err}"),
-        }
+    pub fn status_favorite_added(&self, name: &str) -> String {
+        self.format("status_favorite_added", &[("name", &name)])
     }
 
-    pub fn help_labels(&self) -> [&str; 10] {
-        match self.lang {
-            Language::En => [
-                "Search",
-                "Quit",
-                "Up/Down",
-                "Select",
-                "Top/Bottom",
-                "Clear input",
-                "Tab next/prev",
-                "Help",
-                "Keybinds",
-                "Example",
-            ],
-            Language::Zh => [
-                "搜索",
-                "退出",
-                "上/下",
-                "选择",
-                "顶部/底部",
-                "清空输入",
-                "切换标签",
-                "帮助",
-                "快捷键",
-                "示例",
-            ],
-        }
+    pub fn status_favorite_removed(&self, name: &str) -> String {
+        self.format("status_favorite_removed", &[("name", &name)])
+    }
+
+    pub fn status_favorite_alias_saved(&self) -> &str {
+        self.text("status_favorite_alias_saved")
+    }
+
+    pub fn status_no_favorite_target(&self) -> &str {
+        self.text("status_no_favorite_target")
+    }
+
+    pub fn status_select_favorite(&self) -> &str {
+        self.text("status_select_favorite")
+    }
+
+    pub fn status_compare_prompt(&self) -> &str {
+        self.text("status_compare_prompt")
+    }
+
+    pub fn status_loading_compare(&self, left: u32, right: u32) -> String {
+        self.format("status_loading_compare", &[("left", &left), ("right", &right)])
+    }
+
+    pub fn status_compare_loaded(&self) -> &str {
+        self.text("status_compare_loaded")
+    }
+
+    pub fn compare_empty(&self) -> &str {
+        self.text("compare_empty")
+    }
+
+    pub fn compare_metric(&self) -> &str {
+        self.text("compare_metric")
     }
 
-    pub fn help_group_search(&self) -> &str {
-        match self.lang {
-            Language::En => "Search",
-            Language::Zh => "搜索",
+    pub fn compare_labels(&self) -> [&str; 8] {
+        [
+            self.text("compare.rank"),
+            self.text("compare.matches"),
+            self.text("compare.winrate"),
+            self.text("compare.avg_kda_split"),
+            self.text("compare.avg_kda"),
+            self.text("compare.avg_gpm"),
+            self.text("compare.avg_xpm"),
+            self.text("compare.most_played"),
+        ]
+    }
+
+    pub fn format_rank_tier(&self, rank_tier: Option<i32>, leaderboard: Option<i32>) -> String {
+        let Some(tier) = rank_tier.filter(|tier| *tier > 0) else {
+            return self.placeholder_dash().to_string();
+        };
+        let medal = MEDALS
+            .get((tier / 10 - 1).clamp(0, 7) as usize)
+            .map(|medal| self.lookup("rank", medal))
+            .unwrap_or(self.unknown());
+        match (tier / 10, leaderboard) {
+            (8, Some(rank)) => format!("{medal} #{rank}"),
+            (8, None) => medal.to_string(),
+            _ => format!("{medal} {}", tier % 10),
         }
     }
 
-    pub fn help_group_navigation(&self) -> &str {
-        match self.lang {
-            Language::En => "Navigation",
-            Language::Zh => "导航",
+    pub fn status_hero_filter(&self, count: usize, filter: &str) -> String {
+        self.format("status_hero_filter", &[("count", &count), ("filter", &filter)])
+    }
+
+    pub fn status_hero_filter_cleared(&self) -> &str {
+        self.text("status_hero_filter_cleared")
+    }
+
+    pub fn filter_all(&self) -> &str {
+        self.text("filter_all")
+    }
+
+    pub fn patch_all(&self) -> &str {
+        self.text("patch_all")
+    }
+
+    pub fn patch_current(&self, patch: &str) -> String {
+        self.format("patch_current", &[("patch", &patch)])
+    }
+
+    pub fn patch_named(&self, patch: &str) -> String {
+        self.format("patch_named", &[("patch", &patch)])
+    }
+
+    pub fn status_stats_patch(&self, scope: &str) -> String {
+        self.format("status_stats_patch", &[("scope", &scope)])
+    }
+
+    pub fn status_kind_filter(&self, kind: &str) -> String {
+        self.format("status_kind_filter", &[("kind", &kind)])
+    }
+
+    pub fn chart_kind_ignored(&self, kind: &str) -> String {
+        self.format("chart_kind_ignored", &[("kind", &kind)])
+    }
+
+    pub fn status_region_filter(&self, region: &str) -> String {
+        self.format("status_region_filter", &[("region", &region)])
+    }
+
+    pub fn status_filters_cleared(&self) -> &str {
+        self.text("status_filters_cleared")
+    }
+
+    pub fn status_constants_failed(&self, err: &str) -> String {
+        self.format("status_constants_failed", &[("err", &err)])
+    }
+
+    pub fn status_histogram_failed(&self, err: &str) -> String {
+        self.format("status_histogram_failed", &[("err", &err)])
+    }
+
+    pub fn title_histogram(&self, field: &str) -> String {
+        self.format("title_histogram", &[("field", &field)])
+    }
+
+    pub fn histogram_empty(&self) -> &str {
+        self.text("histogram_empty")
+    }
+
+    pub fn title_wardmap(&self, kind: &str) -> String {
+        self.format("title_wardmap", &[("kind", &kind)])
+    }
+
+    pub fn wardmap_total(&self, total: i64) -> String {
+        self.format("wardmap_total", &[("total", &total)])
+    }
+
+    pub fn ward_kind(&self, kind: WardKind) -> &str {
+        self.lookup("ward_kind", kind.name())
+    }
+
+    pub fn title_wordcloud(&self) -> &str {
+        self.text("title_wordcloud")
+    }
+
+    pub fn wordcloud_total(&self, total: usize) -> String {
+        self.format("wordcloud_total", &[("total", &total)])
+    }
+
+    pub fn title_chat(&self) -> &str {
+        self.text("title_chat")
+    }
+
+    pub fn title_benchmarks(&self, hero: &str) -> String {
+        self.format("title_benchmarks", &[("hero", &hero)])
+    }
+
+    pub fn benchmark(&self, field: &'static str) -> &str {
+        self.lookup("benchmark", field)
+    }
+
+    pub fn title_hero_list(&self, bracket: &str, role: &str, sort: &str) -> String {
+        self.format("title_hero_list", &[("bracket", &bracket), ("role", &role), ("sort", &sort)])
+    }
+
+    pub fn heroes_empty(&self) -> &str {
+        self.text("heroes_empty")
+    }
+
+    pub fn title_matchups(&self) -> &str {
+        self.text("title_matchups")
+    }
+
+    pub fn title_best_matchups(&self) -> &str {
+        self.text("title_best_matchups")
+    }
+
+    pub fn title_worst_matchups(&self) -> &str {
+        self.text("title_worst_matchups")
+    }
+
+    pub fn title_durations(&self) -> &str {
+        self.text("title_durations")
+    }
+
+    pub fn item_phase(&self, phase: &'static str) -> &str {
+        self.lookup("item_phase", phase)
+    }
+
+    pub fn title_brackets(&self) -> &str {
+        self.text("title_brackets")
+    }
+
+    pub fn bracket_pro(&self) -> &str {
+        self.text("bracket_pro")
+    }
+
+    pub fn table_attr(&self) -> &str {
+        self.text("table_attr")
+    }
+
+    pub fn table_attack(&self) -> &str {
+        self.text("table_attack")
+    }
+
+    pub fn table_roles(&self) -> &str {
+        self.text("table_roles")
+    }
+
+    pub fn table_picks(&self) -> &str {
+        self.text("table_picks")
+    }
+
+    pub fn table_pro_picks(&self) -> &str {
+        self.text("table_pro_picks")
+    }
+
+    pub fn table_pro_bans(&self) -> &str {
+        self.text("table_pro_bans")
+    }
+
+    pub fn table_pro_winrate(&self) -> &str {
+        self.text("table_pro_winrate")
+    }
+
+    pub fn table_bracket(&self) -> &str {
+        self.text("table_bracket")
+    }
+
+    pub fn status_hero_sort(&self, sort: &str) -> String {
+        self.format("status_hero_sort", &[("sort", &sort)])
+    }
+
+    pub fn status_hero_role(&self, role: &str) -> String {
+        self.format("status_hero_role", &[("role", &role)])
+    }
+
+    pub fn status_hero_bracket(&self, bracket: &str) -> String {
+        self.format("status_hero_bracket", &[("bracket", &bracket)])
+    }
+
+    pub fn status_hero_page_failed(&self, err: &str) -> String {
+        self.format("status_hero_page_failed", &[("err", &err)])
+    }
+
+    pub fn status_pro_view(&self, view: &str) -> String {
+        self.format("status_pro_view", &[("view", &view)])
+    }
+
+    pub fn status_pro_failed(&self, err: &str) -> String {
+        self.format("status_pro_failed", &[("err", &err)])
+    }
+
+    pub fn title_pro(&self, view: &str) -> String {
+        self.format("title_pro", &[("view", &view)])
+    }
+
+    pub fn title_team_roster(&self, team: &str) -> String {
+        self.format("title_team_roster", &[("team", &team)])
+    }
+
+    pub fn pro_empty(&self) -> &str {
+        self.text("pro_empty")
+    }
+
+    pub fn table_league(&self) -> &str {
+        self.text("table_league")
+    }
+
+    pub fn table_score(&self) -> &str {
+        self.text("table_score")
+    }
+
+    pub fn table_team(&self) -> &str {
+        self.text("table_team")
+    }
+
+    pub fn table_tag(&self) -> &str {
+        self.text("table_tag")
+    }
+
+    pub fn table_rating(&self) -> &str {
+        self.text("table_rating")
+    }
+
+    pub fn table_last_match(&self) -> &str {
+        self.text("table_last_match")
+    }
+
+    pub fn table_country(&self) -> &str {
+        self.text("table_country")
+    }
+
+    pub fn table_tier(&self) -> &str {
+        self.text("table_tier")
+    }
+
+    pub fn table_id(&self) -> &str {
+        self.text("table_id")
+    }
+
+    pub fn table_opponent(&self) -> &str {
+        self.text("table_opponent")
+    }
+
+    pub fn title_live(&self, count: usize) -> String {
+        self.format("title_live", &[("count", &count)])
+    }
+
+    pub fn live_updated(&self, seconds: u64) -> String {
+        self.format("live_updated", &[("seconds", &seconds)])
+    }
+
+    pub fn live_empty(&self) -> &str {
+        self.text("live_empty")
+    }
+
+    pub fn table_avg_mmr(&self) -> &str {
+        self.text("table_avg_mmr")
+    }
+
+    pub fn table_lead(&self) -> &str {
+        self.text("table_lead")
+    }
+
+    pub fn table_spectators(&self) -> &str {
+        self.text("table_spectators")
+    }
+
+    pub fn status_live_tracked(&self, names: &str) -> String {
+        self.format("status_live_tracked", &[("names", &names)])
+    }
+
+    pub fn status_live_failed(&self, err: &str) -> String {
+        self.format("status_live_failed", &[("err", &err)])
+    }
+
+    pub fn status_live_refreshing(&self) -> &str {
+        self.text("status_live_refreshing")
+    }
+
+    pub fn title_public(&self, range: &str) -> String {
+        self.format("title_public", &[("range", &range)])
+    }
+
+    pub fn title_distribution(&self) -> &str {
+        self.text("title_distribution")
+    }
+
+    pub fn distribution_rank(&self, rank: &str, top: &str) -> String {
+        self.format("distribution_rank", &[("rank", &rank), ("top", &top)])
+    }
+
+    pub fn public_empty(&self) -> &str {
+        self.text("public_empty")
+    }
+
+    pub fn table_avg_rank(&self) -> &str {
+        self.text("table_avg_rank")
+    }
+
+    pub fn table_winner(&self) -> &str {
+        self.text("table_winner")
+    }
+
+    pub fn status_public_range(&self, range: &str) -> String {
+        self.format("status_public_range", &[("range", &range)])
+    }
+
+    pub fn status_public_failed(&self, err: &str) -> String {
+        self.format("status_public_failed", &[("err", &err)])
+    }
+
+    pub fn status_distribution_failed(&self, err: &str) -> String {
+        self.format("status_distribution_failed", &[("err", &err)])
+    }
+
+    pub fn status_public_refreshing(&self) -> &str {
+        self.text("status_public_refreshing")
+    }
+
+    pub fn status_pro_refreshing(&self) -> &str {
+        self.text("status_pro_refreshing")
+    }
+
+    pub fn title_explorer_editor(&self, editing: bool) -> &str {
+        if editing {
+            self.text("title_explorer_editor_active")
+        } else {
+            self.text("title_explorer_editor")
         }
     }
 
-    pub fn help_group_views(&self) -> &str {
-        match self.lang {
-            Language::En => "Views",
-            Language::Zh => "视图",
+    pub fn title_saved_queries(&self) -> &str {
+        self.text("title_saved_queries")
+    }
+
+    pub fn title_explorer_results(&self, rows: usize, columns: usize) -> String {
+        self.format("title_explorer_results", &[("rows", &rows), ("columns", &columns)])
+    }
+
+    pub fn explorer_empty(&self) -> &str {
+        self.text("explorer_empty")
+    }
+
+    pub fn explorer_no_rows(&self) -> &str {
+        self.text("explorer_no_rows")
+    }
+
+    pub fn no_saved_queries(&self) -> &str {
+        self.text("no_saved_queries")
+    }
+
+    pub fn status_explorer_editing(&self) -> &str {
+        self.text("status_explorer_editing")
+    }
+
+    pub fn status_explorer_running(&self) -> &str {
+        self.text("status_explorer_running")
+    }
+
+    pub fn status_explorer_rows(&self, rows: usize) -> String {
+        self.format("status_explorer_rows", &[("rows", &rows)])
+    }
+
+    pub fn status_explorer_failed(&self, err: &str) -> String {
+        self.format("status_explorer_failed", &[("err", &err)])
+    }
+
+    pub fn status_explorer_empty_sql(&self) -> &str {
+        self.text("status_explorer_empty_sql")
+    }
+
+    pub fn status_query_saved(&self, name: &str) -> String {
+        self.format("status_query_saved", &[("name", &name)])
+    }
+
+    pub fn status_query_loaded(&self, name: &str) -> String {
+        self.format("status_query_loaded", &[("name", &name)])
+    }
+
+    pub fn status_query_deleted(&self, name: &str) -> String {
+        self.format("status_query_deleted", &[("name", &name)])
+    }
+
+    pub fn status_no_saved_queries(&self) -> &str {
+        self.text("status_no_saved_queries")
+    }
+
+    pub fn title_laning(&self) -> &str {
+        self.text("title_laning")
+    }
+
+    pub fn laning_unparsed(&self) -> &str {
+        self.text("laning_unparsed")
+    }
+
+    pub fn table_role(&self) -> &str {
+        self.text("table_role")
+    }
+
+    pub fn table_lh_dn(&self, minute: usize) -> String {
+        self.format("table_lh_dn", &[("minute", &minute)])
+    }
+
+    pub fn table_gold_at(&self, minute: usize) -> String {
+        self.format("table_gold_at", &[("minute", &minute)])
+    }
+
+    pub fn table_efficiency(&self) -> &str {
+        self.text("table_efficiency")
+    }
+
+    pub fn lane(&self, name: &'static str) -> &str {
+        self.lookup("lane", name)
+    }
+
+    pub fn lane_role(&self, name: &'static str) -> &str {
+        self.lookup("lane_role", name)
+    }
+
+    pub fn lane_won(&self, side: &str) -> String {
+        self.format("lane_won", &[("side", &side)])
+    }
+
+    pub fn lane_even(&self) -> &str {
+        self.text("lane_even")
+    }
+
+    pub fn status_wordcloud_failed(&self, err: &str) -> String {
+        self.format("status_wordcloud_failed", &[("err", &err)])
+    }
+
+    pub fn status_wardmap_failed(&self, err: &str) -> String {
+        self.format("status_wardmap_failed", &[("err", &err)])
+    }
+
+    pub fn status_aggregates_failed(&self, err: &str) -> String {
+        self.format("status_aggregates_failed", &[("err", &err)])
+    }
+
+    pub fn title_lifetime(&self) -> &str {
+        self.text("title_lifetime")
+    }
+
+    pub fn title_breakdown(&self) -> &str {
+        self.text("title_breakdown")
+    }
+
+    pub fn table_average(&self) -> &str {
+        self.text("table_average")
+    }
+
+    pub fn lifetime_record(&self, wins: i64, losses: i64) -> String {
+        let total = wins + losses;
+        let winrate = if total > 0 {
+            format!("{:.1}", wins as f64 * 100.0 / total as f64)
+        } else {
+            "0.0".to_string()
+        };
+        self.format(
+            "lifetime_record",
+            &[("total", &total), ("wins", &wins), ("losses", &losses), ("winrate", &winrate)],
+        )
+    }
+
+    pub fn total_field(&self, field: &'static str) -> &str {
+        self.lookup("total", field)
+    }
+
+    pub fn breakdown(&self, group: &'static str) -> &str {
+        self.lookup("breakdown", group)
+    }
+
+    pub fn status_refreshing(&self, account_id: u32) -> String {
+        self.format("status_refreshing", &[("account_id", &account_id)])
+    }
+
+    pub fn status_exported(&self, path: &str) -> String {
+        self.format("status_exported", &[("path", &path)])
+    }
+
+    pub fn status_export_failed(&self, err: &str) -> String {
+        self.format("status_export_failed", &[("err", &err)])
+    }
+
+    pub fn export_no_rows(&self) -> &str {
+        self.text("export_no_rows")
+    }
+
+    pub fn status_cache_cleared(&self) -> &str {
+        self.text("status_cache_cleared")
+    }
+
+    pub fn status_theme_changed(&self, name: &str) -> String {
+        self.format("status_theme_changed", &[("name", &name)])
+    }
+
+    pub fn status_keybind_conflicts(&self, conflicts: &str) -> String {
+        self.format("status_keybind_conflicts", &[("conflicts", &conflicts)])
+    }
+
+    pub fn status_keybind_deprecated(&self, keys: &str) -> String {
+        self.format("status_keybind_deprecated", &[("keys", &keys)])
+    }
+
+    pub fn status_theme_invalid(&self, err: &str) -> String {
+        self.format("status_theme_invalid", &[("err", &err)])
+    }
+
+    pub fn status_language_changed(&self) -> &str {
+        self.text("status_language_changed")
+    }
+
+    pub fn status_config_reloaded(&self) -> &str {
+        self.text("status_config_reloaded")
+    }
+
+    pub fn status_config_invalid(&self, err: &str) -> String {
+        self.format("status_config_invalid", &[("err", &err)])
+    }
+
+    pub fn title_palette(&self) -> &str {
+        self.text("title_palette")
+    }
+
+    pub fn palette_empty(&self) -> &str {
+        self.text("palette_empty")
+    }
+
+    pub fn action_label(&self, action: Action) -> &str {
+        self.lookup("action", action.name())
+    }
+
+    pub fn status_image_failed(&self, err: &str) -> String {
+        self.format("status_image_failed", &[("err", &err)])
+    }
+
+    pub fn help_group(&self, group: ActionGroup) -> &str {
+        self.lookup("help_group", group.name())
+    }
+
+    pub fn help_palette_only(&self) -> &str {
+        self.text("help_palette_only")
+    }
+
+    pub fn edit_action_label(&self, action: EditAction) -> &str {
+        self.lookup("edit_action", action.keybind_name())
+    }
+
+    pub fn title_radiant(&self) -> &str {
+        self.text("title_radiant")
+    }
+
+    pub fn title_dire(&self) -> &str {
+        self.text("title_dire")
+    }
+
+    pub fn anonymous(&self) -> &str {
+        self.text("anonymous")
+    }
+
+    pub fn game_mode(&self, name: &str) -> String {
+        self.constant_label("game_mode", name.strip_prefix("game_mode_").unwrap_or(name))
+    }
+
+    pub fn lobby_type(&self, name: &str) -> String {
+        self.constant_label("lobby_type", name.strip_prefix("lobby_type_").unwrap_or(name))
+    }
+
+    pub fn region(&self, id: i32, name: Option<&str>) -> String {
+        self.messages
+            .get(&format!("region.{id}"))
+            .cloned()
+            .or_else(|| name.map(humanize))
+            .unwrap_or_else(|| id.to_string())
+    }
+
+    pub fn match_kind(&self, kind: MatchKind) -> &str {
+        self.lookup("match_kind", kind.name())
+    }
+
+    pub fn hero_sort(&self, sort: HeroSort) -> &str {
+        self.lookup("hero_sort", sort.name())
+    }
+
+    pub fn pro_view(&self, view: ProView) -> &str {
+        self.lookup("pro_view", view.name())
+    }
+
+    pub fn league_tier(&self, tier: &str) -> String {
+        self.constant_label("league_tier", tier)
+    }
+
+    pub fn hero_role(&self, role: &str) -> String {
+        self.constant_label("hero_role", &role.to_lowercase())
+    }
+
+    pub fn hero_role_label(&self, role: Option<usize>) -> String {
+        match role.and_then(|idx| HERO_ROLES.get(idx)) {
+            Some(role) => self.hero_role(role),
+            None => self.filter_all().to_string(),
         }
     }
 
-    pub fn help_group_misc(&self) -> &str {
-        match self.lang {
-            Language::En => "General",
-            Language::Zh => "通用",
+    pub fn hero_bracket_label(&self, bracket: Option<usize>) -> &str {
+        match bracket.and_then(|bracket| MEDALS.get(bracket.wrapping_sub(1))) {
+            Some(medal) => self.lookup("rank", medal),
+            None => self.text("bracket_all"),
         }
     }
 
-    pub fn title_radiant(&self) -> &str {
-        match self.lang {
-            Language::En => "Radiant",
-            Language::Zh => "天辉",
+    pub fn medal(&self, medal: usize) -> &str {
+        match MEDALS.get(medal.wrapping_sub(1)) {
+            Some(medal) => self.lookup("rank", medal),
+            None => self.unknown(),
         }
     }
 
-    pub fn title_dire(&self) -> &str {
-        match self.lang {
-            Language::En => "Dire",
-            Language::Zh => "夜魇",
+    pub fn rank_range(&self, min: Option<usize>, max: Option<usize>) -> String {
+        if min.is_none() && max.is_none() {
+            return self.text("bracket_all").to_string();
+        }
+        let low = self.medal(min.unwrap_or(1));
+        let high = self.medal(max.unwrap_or(MEDALS.len()));
+        if low == high {
+            low.to_string()
+        } else {
+            format!("{low} – {high}")
         }
     }
 
-    pub fn anonymous(&self) -> &str {
-        match self.lang {
-            Language::En => "Anonymous",
-            Language::Zh => "匿名",
+    pub fn attack_type(&self, kind: &str) -> String {
+        self.constant_label("attack_type", &kind.to_lowercase())
+    }
+
+    pub fn primary_attr(&self, attr: &str) -> String {
+        self.constant_label("primary_attr", attr)
+    }
+
+    fn constant_label(&self, section: &str, key: &str) -> String {
+        self.messages
+            .get(&format!("{section}.{key}"))
+            .cloned()
+            .unwrap_or_else(|| humanize(key))
+    }
+
+    fn lookup(&self, section: &str, name: &'static str) -> &str {
+        self.messages
+            .get(&format!("{section}.{name}"))
+            .map(String::as_str)
+            .unwrap_or(name)
+    }
+}
+
+fn humanize(value: &str) -> String {
+    value
+        .split(['_', ' '])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn hero_key(name: &str) -> &str {
+    name.strip_prefix("npc_dota_hero_").unwrap_or(name)
+}
+
+fn merge_catalog(messages: &mut HashMap<String, String>, contents: &str) {
+    if let Ok(table) = contents.parse::<toml::Table>() {
+        flatten_catalog(messages, "", &table);
+    }
+}
+
+fn flatten_catalog(messages: &mut HashMap<String, String>, prefix: &str, table: &toml::Table) {
+    for (key, value) in table {
+        let key = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        match value {
+            toml::Value::String(text) => {
+                messages.insert(key, text.clone());
+            }
+            toml::Value::Table(inner) => flatten_catalog(messages, &key, inner),
+            _ => {}
         }
     }
+}
 
-    pub fn format_game_mode(&self, game_mode: Option<i32>) -> String {
-        match game_mode {
-            Some(0) => if self.is_zh() { "未知".to_string() } else { "Unknown".to_string() },
-            Some(1) => if self.is_zh() { "全英雄选择".to_string() } else { "All Pick".to_string() },
-            Some(2) => if self.is_zh() { "队长模式".to_string() } else { "Captains Mode".to_string() },
-            Some(3) => if self.is_zh() { "随机征召".to_string() } else { "Random Draft".to_string() },
-            Some(4) => if self.is_zh() { "单一征召".to_string() } else { "Single Draft".to_string() },
-            Some(5) => if self.is_zh() { "全随机".to_string() } else { "All Random".to_string() },
-            Some(6) => if self.is_zh() { "新手教程".to_string() } else { "Intro".to_string() },
-            Some(7) => if self.is_zh() { "Diretide".to_string() } else { "Diretide".to_string() },
-            Some(8) => if self.is_zh() { "反向队长".to_string() } else { "Reverse Captains Mode".to_string() },
-            Some(9) => if self.is_zh() { "Greeviling".to_string() } else { "Greeviling".to_string() },
-            Some(10) => if self.is_zh() { "教程".to_string() } else { "Tutorial".to_string() },
-            Some(11) => if self.is_zh() { "中路1v1".to_string() } else { "Mid Only".to_string() },
-            Some(12) => if self.is_zh() { "最少使用".to_string() } else { "Least Played".to_string() },
-            Some(13) => if self.is_zh() { "限制英雄".to_string() } else { "Limited Heroes".to_string() },
-            Some(14) => if self.is_zh() { "宝典匹配".to_string() } else { "Compendium Matchmaking".to_string() },
-            Some(15) => if self.is_zh() { "自定义".to_string() } else { "Custom".to_string() },
-            Some(16) => if self.is_zh() { "队长征召".to_string() } else { "Captains Draft".to_string() },
-            Some(17) => if self.is_zh() { "平衡征召".to_string() } else { "Balanced Draft".to_string() },
-            Some(18) => if self.is_zh() { "技能征召".to_string() } else { "Ability Draft".to_string() },
-            Some(19) => if self.is_zh() { "活动".to_string() } else { "Event".to_string() },
-            Some(20) => if self.is_zh() { "全随机死斗".to_string() } else { "All Random Death Match".to_string() },
-            Some(21) => if self.is_zh() { "1v1 中路".to_string() } else { "1v1 Mid".to_string() },
-            Some(22) => if self.is_zh() { "全征召".to_string() } else { "All Draft".to_string() },
-            Some(23) => if self.is_zh() { "极速".to_string() } else { "Turbo".to_string() },
-            Some(24) => if self.is_zh() { "变异".to_string() } else { "Mutation".to_string() },
-            Some(25) => if self.is_zh() { "教练挑战".to_string() } else { "Coaches Challenge".to_string() },
-            Some(value) => value.to_string(),
-            None => self.placeholder_dash().to_string(),
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placeholders(text: &str) -> Vec<&str> {
+        let mut names: Vec<&str> = text
+            .split('{')
+            .skip(1)
+            .filter_map(|part| part.split_once('}').map(|(name, _)| name))
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    #[test]
+    fn parses_locale_tags() {
+        assert_eq!(Language::parse("en_US.UTF-8"), Some(Language::En));
+        assert_eq!(Language::parse("zh_CN.UTF-8"), Some(Language::Zh));
+        assert_eq!(Language::parse("zh-TW"), Some(Language::Zh));
+        assert_eq!(Language::parse("pt_BR"), Some(Language::PtBr));
+        assert_eq!(Language::parse("pt-PT"), Some(Language::PtBr));
+        assert_eq!(Language::parse("es_ES@euro"), Some(Language::Es));
+        assert_eq!(Language::parse("RU"), Some(Language::Ru));
+        assert_eq!(Language::parse("de_DE.UTF-8"), None);
+        assert_eq!(Language::parse(""), None);
+    }
+
+    #[test]
+    fn explicit_config_language_wins_and_unknown_falls_back_to_english() {
+        assert_eq!(I18n::language_from_config("ru"), Language::Ru);
+        assert_eq!(I18n::language_from_config(" pt-BR "), Language::PtBr);
+        assert_eq!(I18n::language_from_config("zh-CN"), Language::Zh);
+        assert_eq!(I18n::language_from_config("klingon"), Language::En);
+    }
+
+    #[test]
+    fn catalogs_match_english_keys_and_placeholders() {
+        let mut english = HashMap::new();
+        merge_catalog(&mut english, Language::En.catalog());
+        assert!(!english.is_empty());
+        for lang in Language::ALL {
+            let mut messages = HashMap::new();
+            merge_catalog(&mut messages, lang.catalog());
+            let mut missing: Vec<&String> = english.keys().filter(|key| !messages.contains_key(*key)).collect();
+            let mut extra: Vec<&String> = messages.keys().filter(|key| !english.contains_key(*key)).collect();
+            missing.sort();
+            extra.sort();
+            assert!(missing.is_empty() && extra.is_empty(), "{}: missing {missing:?}, extra {extra:?}", lang.code());
+            for (key, text) in &messages {
+                assert_eq!(placeholders(text), placeholders(&english[key]), "{}: {key}", lang.code());
+            }
         }
     }
 }
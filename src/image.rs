@@ -145,6 +145,17 @@ pub fn write_disk_cache(url: &str, bytes: &[u8]) -> io::Result<()> {
     Ok(())
 }
 
+pub fn clear_disk_cache() -> io::Result<()> {
+    let Ok(mut dir) = cache_dir() else {
+        return Ok(());
+    };
+    dir.push("images");
+    if dir.exists() {
+        std::fs::remove_dir_all(dir)?;
+    }
+    Ok(())
+}
+
 pub fn ensure_png(bytes: &[u8]) -> io::Result<Vec<u8>> {
     if bytes.starts_with(b"\\x89PNG") {
         return Ok(bytes.to_vec());
@@ -1,14 +1,26 @@
-use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
 use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
+use crate::actions::{filter_actions, Action, EditAction};
 use crate::api::ApiClient;
-use crate::app::{spawn_match_detail, spawn_search, App, InputMode, Message};
-use crate::config::{matches, ResolvedKeybinds};
+use crate::app::{
+    spawn_explorer, spawn_match_detail, spawn_refresh, spawn_search, App, ChartView, FavoriteEntry, HitAreas, InputMode,
+    LoadedPlayer, MatchKind, Message, PendingCommand, ProPage, ProView, SearchTarget, HERO_BRACKETS, HERO_ROLES,
+    HISTOGRAM_FIELDS, TAB_CHARTS, TAB_COMPARE, TAB_COUNT, TAB_EXPLORER, TAB_HEROES, TAB_LIVE, TAB_PRO,
+    TAB_PUBLIC,
+};
+use crate::export::{export_explorer, export_matches};
+use crate::image::clear_disk_cache;
+use crate::config::{matches, KeyCombo, ResolvedKeybinds};
 use crate::i18n::I18n;
 
 const STEAMID64_BASE: u64 = 76561197960265728;
 const NAV_DEBOUNCE_MS: u64 = 80;
+const DOUBLE_CLICK_MS: u64 = 400;
+const KEY_SEQUENCE_TIMEOUT_MS: u64 = 1000;
 
 pub fn handle_event(
     event: Event,
@@ -20,9 +32,17 @@ pub fn handle_event(
 ) {
     match event {
         Event::Key(key) if key.kind == KeyEventKind::Press => match app.input_mode {
-            InputMode::Editing => handle_editing_key(key, app, tx, api, keybinds, i18n),
+            InputMode::Editing | InputMode::Compare => {
+                handle_editing_key(key, app, tx, api, keybinds, i18n)
+            }
             InputMode::Normal => handle_normal_key(key, app, tx, api, keybinds, i18n),
+            InputMode::Alias => handle_alias_key(key, app, keybinds, i18n),
+            InputMode::Palette => handle_palette_key(key, app, tx, api, keybinds, i18n),
+            InputMode::HeroFilter => handle_hero_filter_key(key, app, keybinds, i18n),
+            InputMode::Sql => handle_sql_key(key, app, tx, api, keybinds, i18n),
+            InputMode::QueryName => handle_query_name_key(key, app, keybinds, i18n),
         },
+        Event::Mouse(mouse) => handle_mouse(mouse, app, tx, api, i18n),
         _ => {}
     }
 }
@@ -35,45 +55,185 @@ fn handle_editing_key(
     keybinds: &ResolvedKeybinds,
     i18n: &I18n,
 ) {
-    if matches(keybinds.help, key.code, key.modifiers) {
-        app.show_help = !app.show_help;
-        app.image_reset = true;
-        return;
+    match edit_action_for_key(key, keybinds) {
+        Some(EditAction::Help) => {
+            app.show_help = !app.show_help;
+            app.help_scroll = 0;
+            app.image_reset = true;
+        }
+        Some(EditAction::Complete) => {
+            if let Some(entry) = autocomplete_recent(app) {
+                app.input = entry.account_id.to_string();
+            }
+        }
+        Some(EditAction::Cancel) => {
+            app.input_mode = InputMode::Normal;
+            app.set_status(i18n.status_search_cancelled());
+        }
+        Some(EditAction::Submit) => {
+            let started = if app.input_mode == InputMode::Compare {
+                start_compare_from_input(app, tx, api, i18n)
+            } else {
+                start_search_from_input(app, tx, api, i18n)
+            };
+            if started.is_ok() {
+                app.input_mode = InputMode::Normal;
+            }
+        }
+        Some(EditAction::ClearInput) => app.input.clear(),
+        Some(EditAction::Next | EditAction::Prev | EditAction::Run) | None => {
+            edit_text(&mut app.input, key);
+        }
     }
-    if key.code == KeyCode::Tab {
-        if let Some(entry) = autocomplete_recent(app) {
-            app.input = entry.account_id.to_string();
+}
+
+fn handle_alias_key(key: KeyEvent, app: &mut App, keybinds: &ResolvedKeybinds, i18n: &I18n) {
+    match edit_action_for_key(key, keybinds) {
+        Some(EditAction::Cancel) => {
+            app.alias_input.clear();
+            app.input_mode = InputMode::Normal;
+        }
+        Some(EditAction::Submit) => {
+            let alias = std::mem::take(&mut app.alias_input);
+            if app.set_selected_favorite_alias(&alias) {
+                app.set_status(i18n.status_favorite_alias_saved());
+            }
+            app.input_mode = InputMode::Normal;
+        }
+        Some(EditAction::ClearInput) => app.alias_input.clear(),
+        _ => {
+            edit_text(&mut app.alias_input, key);
         }
-        return;
     }
-    if key.code == KeyCode::Esc {
-        app.input_mode = InputMode::Normal;
-        app.set_status(i18n.status_search_cancelled());
-        return;
+}
+
+fn handle_sql_key(
+    key: KeyEvent,
+    app: &mut App,
+    tx: &mpsc::Sender<Message>,
+    api: &ApiClient,
+    keybinds: &ResolvedKeybinds,
+    i18n: &I18n,
+) {
+    let editor = &mut app.explorer;
+    match edit_action_for_key(key, keybinds) {
+        Some(EditAction::Cancel) => app.input_mode = InputMode::Normal,
+        Some(EditAction::Run) => run_explorer(app, tx, api, i18n),
+        Some(EditAction::ClearInput) => editor.clear(),
+        Some(EditAction::Submit) => editor.insert("\n"),
+        Some(EditAction::Complete) => editor.insert("  "),
+        Some(EditAction::Next) => editor.move_down(),
+        Some(EditAction::Prev) => editor.move_up(),
+        Some(EditAction::Help) | None => match key.code {
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                editor.insert(c.encode_utf8(&mut [0; 4]));
+            }
+            KeyCode::Backspace => {
+                editor.backspace();
+            }
+            KeyCode::Delete => {
+                editor.delete();
+            }
+            KeyCode::Left => editor.move_left(),
+            KeyCode::Right => editor.move_right(),
+            KeyCode::Up => editor.move_up(),
+            KeyCode::Down => editor.move_down(),
+            KeyCode::Home => editor.home(),
+            KeyCode::End => editor.end(),
+            _ => {}
+        },
     }
+}
 
-    if matches(keybinds.select, key.code, key.modifiers) {
-        if start_search_from_input(app, tx, api, i18n).is_ok() {
+fn handle_query_name_key(key: KeyEvent, app: &mut App, keybinds: &ResolvedKeybinds, i18n: &I18n) {
+    match edit_action_for_key(key, keybinds) {
+        Some(EditAction::Cancel) => {
+            app.query_name_input.clear();
             app.input_mode = InputMode::Normal;
         }
-        return;
+        Some(EditAction::Submit) => {
+            let name = std::mem::take(&mut app.query_name_input);
+            if app.save_query(&name) {
+                app.set_status(i18n.status_query_saved(name.trim()));
+            }
+            app.input_mode = InputMode::Normal;
+        }
+        Some(EditAction::ClearInput) => app.query_name_input.clear(),
+        _ => {
+            edit_text(&mut app.query_name_input, key);
+        }
     }
+}
 
-    if matches(keybinds.clear_input, key.code, key.modifiers) {
-        app.input.clear();
-        return;
+fn handle_hero_filter_key(key: KeyEvent, app: &mut App, keybinds: &ResolvedKeybinds, i18n: &I18n) {
+    match edit_action_for_key(key, keybinds) {
+        Some(EditAction::Cancel) => {
+            app.hero_filter.clear();
+            app.apply_match_filter(i18n);
+            app.input_mode = InputMode::Normal;
+            app.set_status(i18n.status_hero_filter_cleared());
+        }
+        Some(EditAction::Submit) => {
+            app.input_mode = InputMode::Normal;
+            let filter = app.hero_filter.trim().to_string();
+            if filter.is_empty() {
+                app.set_status(i18n.status_hero_filter_cleared());
+            } else {
+                app.set_status(i18n.status_hero_filter(app.matches.len(), &filter));
+            }
+        }
+        Some(EditAction::ClearInput) => {
+            app.hero_filter.clear();
+            app.apply_match_filter(i18n);
+        }
+        _ => {
+            if edit_text(&mut app.hero_filter, key) {
+                app.apply_match_filter(i18n);
+            }
+        }
     }
+}
 
+fn edit_text(text: &mut String, key: KeyEvent) -> bool {
     match key.code {
-        KeyCode::Backspace => {
-            app.input.pop();
+        KeyCode::Backspace => text.pop().is_some(),
+        KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+            text.push(c);
+            true
         }
-        KeyCode::Char(c) => {
-            if !key.modifiers.contains(KeyModifiers::CONTROL) {
-                app.input.push(c);
-            }
+        _ => false,
+    }
+}
+
+fn edit_action_for_key(key: KeyEvent, keybinds: &ResolvedKeybinds) -> Option<EditAction> {
+    keybinds
+        .editing
+        .iter()
+        .find(|(_, combo)| matches(*combo, key.code, key.modifiers))
+        .and_then(|(name, _)| EditAction::from_keybind(name))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum SequenceMatch {
+    Exact(&'static str),
+    Prefix,
+    None,
+}
+
+fn lookup_sequence(keys: &[KeyCombo], keybinds: &ResolvedKeybinds) -> SequenceMatch {
+    let mut prefix = false;
+    for (name, sequence) in &keybinds.normal {
+        if sequence.as_slice() == keys {
+            return SequenceMatch::Exact(name);
+        }
+        if sequence.len() > keys.len() && sequence.starts_with(keys) {
+            prefix = true;
         }
-        _ => {}
+    }
+    if prefix {
+        SequenceMatch::Prefix
+    } else {
+        SequenceMatch::None
     }
 }
 
@@ -85,110 +245,781 @@ fn handle_normal_key(
     keybinds: &ResolvedKeybinds,
     i18n: &I18n,
 ) {
+    let combo = KeyCombo::new(key.code, key.modifiers);
+    let now = Instant::now();
+    if now.duration_since(app.last_key) > Duration::from_millis(KEY_SEQUENCE_TIMEOUT_MS) {
+        app.pending_keys.clear();
+    }
+    app.last_key = now;
+    app.pending_keys.push(combo);
+    let mut found = lookup_sequence(&app.pending_keys, keybinds);
+    if found == SequenceMatch::None && app.pending_keys.len() > 1 {
+        app.pending_keys = vec![combo];
+        found = lookup_sequence(&app.pending_keys, keybinds);
+    }
+    let name = match found {
+        SequenceMatch::Exact(name) => name,
+        SequenceMatch::Prefix => return,
+        SequenceMatch::None => {
+            app.pending_keys.clear();
+            return;
+        }
+    };
+    app.pending_keys.clear();
+
+    let Some(action) = Action::from_keybind(name) else {
+        return;
+    };
     if app.show_help {
-        if key.code == KeyCode::Esc || matches(keybinds.help, key.code, key.modifiers) {
+        handle_help_action(action, app);
+    } else {
+        dispatch_action(action, app, tx, api, i18n);
+    }
+}
+
+fn handle_help_action(action: Action, app: &mut App) {
+    match action {
+        Action::Cancel | Action::Help => {
             app.show_help = false;
             app.image_reset = true;
         }
-        return;
+        Action::Down => app.help_scroll = app.help_scroll.saturating_add(1),
+        Action::Up => app.help_scroll = app.help_scroll.saturating_sub(1),
+        Action::Top => app.help_scroll = 0,
+        Action::Bottom => app.help_scroll = u16::MAX,
+        _ => {}
     }
-    if matches(keybinds.quit, key.code, key.modifiers) {
-        app.should_quit = true;
-        return;
+}
+
+pub fn dispatch_action(
+    action: Action,
+    app: &mut App,
+    tx: &mpsc::Sender<Message>,
+    api: &ApiClient,
+    i18n: &I18n,
+) {
+    match action {
+        Action::Quit => {
+            app.should_quit = true;
+        }
+        Action::Help => {
+            app.show_help = true;
+            app.help_scroll = 0;
+            app.image_reset = true;
+        }
+        Action::OpenPalette => open_palette(app),
+        Action::Cancel => {
+            app.input_mode = InputMode::Normal;
+            app.team_selection = None;
+            if app.hero_page.take().is_some() {
+                app.hero_requested = None;
+                app.image_reset = true;
+            }
+            if app.pro_page.take().is_some() {
+                app.image_reset = true;
+            }
+            app.set_status(i18n.status_search_cancelled());
+        }
+        Action::Search => {
+            app.input_mode = InputMode::Editing;
+            app.set_status(i18n.status_need_id());
+        }
+        Action::Compare => {
+            app.input = app
+                .account_id
+                .map(|account_id| format!("{account_id} "))
+                .unwrap_or_default();
+            app.input_mode = InputMode::Compare;
+            app.set_status(i18n.status_compare_prompt());
+        }
+        Action::Refresh if app.tab_index == TAB_LIVE => {
+            app.live_stale = true;
+            app.set_status(i18n.status_live_refreshing());
+        }
+        Action::Refresh if app.tab_index == TAB_PRO => {
+            app.refresh_pro();
+            app.set_status(i18n.status_pro_refreshing());
+        }
+        Action::Refresh if app.tab_index == TAB_PUBLIC => {
+            app.reset_public_matches();
+            app.distribution_requested = false;
+            app.set_status(i18n.status_public_refreshing());
+        }
+        Action::Refresh => match app.account_id {
+            Some(account_id) => {
+                reset_player_state(app);
+                app.set_status(i18n.status_refreshing(account_id));
+                spawn_refresh(tx.clone(), api.clone(), account_id);
+            }
+            None => app.set_status(i18n.status_need_id()),
+        },
+        Action::Export if app.tab_index == TAB_EXPLORER => match export_explorer(app, i18n) {
+            Ok(path) => app.set_status(i18n.status_exported(&path.display().to_string())),
+            Err(err) => app.set_status(i18n.status_export_failed(&err.to_string())),
+        },
+        Action::Export => match export_matches(app, i18n) {
+            Ok(path) => app.set_status(i18n.status_exported(&path.display().to_string())),
+            Err(err) => app.set_status(i18n.status_export_failed(&err.to_string())),
+        },
+        Action::FilterHero => app.input_mode = InputMode::HeroFilter,
+        Action::CycleMatchType => {
+            app.kind_filter = match app.kind_filter {
+                None => MatchKind::ALL.first().copied(),
+                Some(kind) => MatchKind::ALL
+                    .iter()
+                    .position(|candidate| *candidate == kind)
+                    .and_then(|index| MatchKind::ALL.get(index + 1).copied()),
+            };
+            app.apply_match_filter(i18n);
+            let label = app
+                .kind_filter
+                .map(|kind| i18n.match_kind(kind).to_string())
+                .unwrap_or_else(|| i18n.filter_all().to_string());
+            app.set_status(i18n.status_kind_filter(&label));
+        }
+        Action::CycleRegion => {
+            let regions = app.match_regions();
+            app.region_filter = match app.region_filter {
+                None => regions.first().copied(),
+                Some(region) => regions
+                    .iter()
+                    .position(|candidate| *candidate == region)
+                    .and_then(|index| regions.get(index + 1).copied()),
+            };
+            app.apply_match_filter(i18n);
+            let label = match app.region_filter {
+                Some(region) => app.region_name(Some(region), i18n),
+                None => i18n.filter_all().to_string(),
+            };
+            app.set_status(i18n.status_region_filter(&label));
+        }
+        Action::CycleStatsPatch => {
+            app.cycle_stats_patch();
+            switch_tab(app, 2);
+            app.set_status(i18n.status_stats_patch(&app.stats_patch_label(i18n)));
+        }
+        Action::CycleHeroSort => {
+            app.hero_sort = app.hero_sort.next();
+            show_hero_list(app);
+            app.set_status(i18n.status_hero_sort(i18n.hero_sort(app.hero_sort)));
+        }
+        Action::CycleHeroRole => {
+            app.hero_role = match app.hero_role {
+                None => Some(0),
+                Some(idx) if idx + 1 < HERO_ROLES.len() => Some(idx + 1),
+                Some(_) => None,
+            };
+            show_hero_list(app);
+            app.set_status(i18n.status_hero_role(&i18n.hero_role_label(app.hero_role)));
+        }
+        Action::CycleHeroBracket => {
+            app.hero_bracket = next_medal(app.hero_bracket);
+            show_hero_list(app);
+            app.set_status(i18n.status_hero_bracket(i18n.hero_bracket_label(app.hero_bracket)));
+        }
+        Action::CycleProView => {
+            app.pro_view = app.pro_view.next();
+            app.pro_index = 0;
+            app.pro_page = None;
+            switch_tab(app, TAB_PRO);
+            app.set_status(i18n.status_pro_view(i18n.pro_view(app.pro_view)));
+        }
+        Action::CyclePublicMinRank => {
+            app.public_min_rank = next_medal(app.public_min_rank);
+            if let (Some(min), Some(max)) = (app.public_min_rank, app.public_max_rank)
+                && min > max
+            {
+                app.public_max_rank = Some(min);
+            }
+            show_public_matches(app, i18n);
+        }
+        Action::CyclePublicMaxRank => {
+            app.public_max_rank = next_medal(app.public_max_rank);
+            if let (Some(min), Some(max)) = (app.public_min_rank, app.public_max_rank)
+                && min > max
+            {
+                app.public_min_rank = Some(max);
+            }
+            show_public_matches(app, i18n);
+        }
+        Action::EditQuery => {
+            switch_tab(app, TAB_EXPLORER);
+            app.input_mode = InputMode::Sql;
+            app.set_status(i18n.status_explorer_editing());
+        }
+        Action::CycleSavedQuery => {
+            if app.saved_queries.is_empty() {
+                app.set_status(i18n.status_no_saved_queries());
+            } else {
+                let next = match app.query_index {
+                    Some(idx) if idx + 1 < app.saved_queries.len() => idx + 1,
+                    _ => 0,
+                };
+                let query = app.saved_queries[next].clone();
+                app.query_index = Some(next);
+                app.explorer.set_text(&query.sql);
+                switch_tab(app, TAB_EXPLORER);
+                app.set_status(i18n.status_query_loaded(&query.name));
+            }
+        }
+        Action::SaveQuery => {
+            if app.explorer.text.trim().is_empty() {
+                app.set_status(i18n.status_explorer_empty_sql());
+            } else {
+                app.query_name_input = app
+                    .query_index
+                    .and_then(|idx| app.saved_queries.get(idx))
+                    .map(|query| query.name.clone())
+                    .unwrap_or_default();
+                switch_tab(app, TAB_EXPLORER);
+                app.input_mode = InputMode::QueryName;
+            }
+        }
+        Action::DeleteQuery => match app.remove_selected_query() {
+            Some(query) => app.set_status(i18n.status_query_deleted(&query.name)),
+            None => app.set_status(i18n.status_no_saved_queries()),
+        },
+        Action::ExplorerLeft => app.explorer_column = app.explorer_column.saturating_sub(1),
+        Action::ExplorerRight => {
+            let columns = app.explorer_result.as_ref().map_or(0, |table| table.columns.len());
+            if app.explorer_column + 1 < columns {
+                app.explorer_column += 1;
+            }
+        }
+        Action::ToggleLaning => {
+            app.show_laning = !app.show_laning;
+            switch_tab(app, 1);
+        }
+        Action::ClearFilters => {
+            app.hero_filter.clear();
+            app.kind_filter = None;
+            app.region_filter = None;
+            app.apply_match_filter(i18n);
+            app.set_status(i18n.status_filters_cleared());
+        }
+        Action::Down => {
+            if can_navigate(app) {
+                if let Some(total) = tab_list_len(app, i18n) {
+                    select_next_row(app, total);
+                } else {
+                    select_next_match(app);
+                }
+            }
+            clear_list_focus(app);
+        }
+        Action::Up => {
+            if can_navigate(app) {
+                if let Some(total) = tab_list_len(app, i18n) {
+                    select_prev_row(app, total);
+                } else {
+                    select_prev_match(app);
+                }
+            }
+            clear_list_focus(app);
+        }
+        Action::Top => {
+            if can_navigate(app) {
+                if tab_list_len(app, i18n).is_some() {
+                    *list_cursor(app) = 0;
+                } else if !app.matches.is_empty() {
+                    app.match_state.select(Some(0));
+                }
+            }
+            clear_list_focus(app);
+        }
+        Action::Bottom => {
+            if can_navigate(app) {
+                if let Some(total) = tab_list_len(app, i18n) {
+                    *list_cursor(app) = total.saturating_sub(1);
+                } else if !app.matches.is_empty() {
+                    app.match_state.select(Some(app.matches.len() - 1));
+                }
+            }
+            clear_list_focus(app);
+        }
+        Action::ToggleFavorite => toggle_favorite(app, i18n),
+        Action::FavoriteNext => select_next_favorite(app),
+        Action::FavoritePrev => select_prev_favorite(app),
+        Action::FavoriteMoveUp => app.move_selected_favorite(true),
+        Action::FavoriteMoveDown => app.move_selected_favorite(false),
+        Action::FavoriteRemove => {
+            match app.remove_selected_favorite() {
+                Some(entry) => app.set_status(i18n.status_favorite_removed(&entry.personaname)),
+                None => app.set_status(i18n.status_select_favorite()),
+            }
+            app.image_reset = true;
+        }
+        Action::RecentNext => select_next_recent(app),
+        Action::RecentPrev => select_prev_recent(app),
+        Action::FavoriteAlias => match app.selected_favorite() {
+            Some(entry) => {
+                app.alias_input = entry.alias.clone().unwrap_or_default();
+                app.input_mode = InputMode::Alias;
+            }
+            None => app.set_status(i18n.status_select_favorite()),
+        },
+        Action::Select => select_current(app, tx, api, i18n),
+        Action::NextTab => switch_tab(app, (app.tab_index + 1) % TAB_COUNT),
+        Action::PrevTab => switch_tab(app, (app.tab_index + TAB_COUNT - 1) % TAB_COUNT),
+        Action::TabOverview => switch_tab(app, 0),
+        Action::TabMatches => switch_tab(app, 1),
+        Action::TabStats => switch_tab(app, 2),
+        Action::TabCharts => switch_tab(app, TAB_CHARTS),
+        Action::TabHeroes => switch_tab(app, TAB_HEROES),
+        Action::TabPro => switch_tab(app, TAB_PRO),
+        Action::TabLive => switch_tab(app, TAB_LIVE),
+        Action::TabPublic => switch_tab(app, TAB_PUBLIC),
+        Action::TabExplorer => switch_tab(app, TAB_EXPLORER),
+        Action::TabCompare => switch_tab(app, TAB_COMPARE),
+        Action::CycleChartView => {
+            app.chart_view = app.chart_view.next();
+            switch_tab(app, TAB_CHARTS);
+        }
+        Action::ChartNext => {
+            match app.chart_view {
+                ChartView::Histogram => app.chart_field = (app.chart_field + 1) % HISTOGRAM_FIELDS.len(),
+                ChartView::Wardmap => app.ward_kind = app.ward_kind.next(),
+                ChartView::Wordcloud => {}
+            }
+            switch_tab(app, TAB_CHARTS);
+        }
+        Action::ChartPrev => {
+            match app.chart_view {
+                ChartView::Histogram => {
+                    app.chart_field = (app.chart_field + HISTOGRAM_FIELDS.len() - 1) % HISTOGRAM_FIELDS.len()
+                }
+                ChartView::Wardmap => app.ward_kind = app.ward_kind.next(),
+                ChartView::Wordcloud => {}
+            }
+            switch_tab(app, TAB_CHARTS);
+        }
+        Action::CycleTheme => app.pending_commands.push(PendingCommand::CycleTheme),
+        Action::CycleLanguage => app.pending_commands.push(PendingCommand::CycleLanguage),
+        Action::ReloadConfig => app.pending_commands.push(PendingCommand::ReloadConfig),
+        Action::ClearCache => {
+            app.image_cache.clear();
+            app.image_cache_order.clear();
+            app.image_reset = true;
+            let _ = clear_disk_cache();
+            let api = api.clone();
+            tokio::spawn(async move {
+                api.clear_cache().await;
+            });
+            app.set_status(i18n.status_cache_cleared());
+        }
     }
-    if matches(keybinds.help, key.code, key.modifiers) {
-        app.show_help = true;
-        app.image_reset = true;
+}
+
+fn handle_mouse(
+    mouse: MouseEvent,
+    app: &mut App,
+    tx: &mpsc::Sender<Message>,
+    api: &ApiClient,
+    i18n: &I18n,
+) {
+    if app.show_help {
+        match mouse.kind {
+            MouseEventKind::ScrollDown => app.help_scroll = app.help_scroll.saturating_add(1),
+            MouseEventKind::ScrollUp => app.help_scroll = app.help_scroll.saturating_sub(1),
+            _ => {}
+        }
         return;
     }
-    if matches(keybinds.search, key.code, key.modifiers) {
-        app.input_mode = InputMode::Editing;
-        app.set_status(i18n.status_need_id());
+    if app.input_mode != InputMode::Normal || app.overlay_open() {
         return;
     }
-    if matches(keybinds.down, key.code, key.modifiers) {
-        if can_navigate(app) {
-            select_next_match(app);
+    let (x, y) = (mouse.column, mouse.row);
+    let hits = app.hit_areas.clone();
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            let double = register_click(app, x, y);
+            if let Some((_, idx)) = hits.tabs.iter().find(|(rect, _)| {
+                x >= rect.x && x < rect.x + rect.width && y >= rect.y && y < rect.y + rect.height
+            }) {
+                switch_tab(app, *idx);
+            } else if let Some(idx) = hits.matches.and_then(|hit| hit.row_at(x, y)) {
+                clear_list_focus(app);
+                app.match_state.select(Some(idx));
+                if double {
+                    select_current(app, tx, api, i18n);
+                }
+            } else if let Some(idx) = hits.favorites.and_then(|hit| hit.row_at(x, y)) {
+                app.favorite_active = true;
+                app.recent_active = false;
+                app.favorite_index = Some(idx);
+                if double {
+                    select_current(app, tx, api, i18n);
+                }
+            } else if let Some(idx) = hits.recent.and_then(|hit| hit.row_at(x, y)) {
+                app.recent_active = true;
+                app.favorite_active = false;
+                app.recent_index = Some(idx);
+                if double {
+                    select_current(app, tx, api, i18n);
+                }
+            } else if let Some(idx) = hits.heroes.and_then(|hit| hit.row_at(x, y)) {
+                app.hero_index = idx;
+                if double {
+                    open_hero_page(app, i18n);
+                }
+            } else if let Some(idx) = hits.live.and_then(|hit| hit.row_at(x, y)) {
+                app.live_index = idx;
+            } else if let Some(idx) = hits.explorer.and_then(|hit| hit.row_at(x, y)) {
+                app.explorer_index = idx;
+            } else if let Some(idx) = hits.public.and_then(|hit| hit.row_at(x, y)) {
+                app.public_index = idx;
+                if double {
+                    select_current(app, tx, api, i18n);
+                }
+            } else if let Some(idx) = hits.pro.and_then(|hit| hit.row_at(x, y)) {
+                *list_cursor(app) = idx;
+                if double {
+                    select_pro(app, tx, api, i18n);
+                }
+            } else if let Some((radiant, idx)) = team_row_at(&hits, x, y) {
+                app.team_selection = Some((radiant, idx));
+                if double {
+                    open_team_player(app, tx, api, i18n);
+                }
+            }
         }
-        app.recent_active = false;
+        MouseEventKind::ScrollDown | MouseEventKind::ScrollUp => {
+            let down = mouse.kind == MouseEventKind::ScrollDown;
+            if hits.matches.is_some_and(|hit| hit.contains(x, y)) {
+                clear_list_focus(app);
+                if down {
+                    select_next_match(app);
+                } else {
+                    select_prev_match(app);
+                }
+            } else if hits.favorites.is_some_and(|hit| hit.contains(x, y)) {
+                if down {
+                    select_next_favorite(app);
+                } else {
+                    select_prev_favorite(app);
+                }
+            } else if hits.recent.is_some_and(|hit| hit.contains(x, y)) {
+                if down {
+                    select_next_recent(app);
+                } else {
+                    select_prev_recent(app);
+                }
+            } else if let Some(total) = [hits.heroes, hits.pro, hits.live, hits.public, hits.explorer]
+                .into_iter()
+                .flatten()
+                .find(|hit| hit.contains(x, y))
+                .and_then(|_| tab_list_len(app, i18n))
+            {
+                if down {
+                    select_next_row(app, total);
+                } else {
+                    select_prev_row(app, total);
+                }
+            } else if hits.chat.is_some_and(|hit| hit.contains(x, y)) {
+                app.chat_scroll = if down {
+                    app.chat_scroll.saturating_add(1)
+                } else {
+                    app.chat_scroll.saturating_sub(1)
+                };
+            } else if let Some(radiant) = [(true, hits.radiant), (false, hits.dire)]
+                .into_iter()
+                .find(|(_, hit)| hit.is_some_and(|hit| hit.contains(x, y)))
+                .map(|(radiant, _)| radiant)
+            {
+                scroll_team_selection(app, radiant, down);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn register_click(app: &mut App, x: u16, y: u16) -> bool {
+    let now = Instant::now();
+    let double = app.last_click.is_some_and(|(at, last_x, last_y)| {
+        last_y == y
+            && last_x.abs_diff(x) <= 2
+            && now.duration_since(at) < Duration::from_millis(DOUBLE_CLICK_MS)
+    });
+    app.last_click = if double { None } else { Some((now, x, y)) };
+    double
+}
+
+fn team_row_at(hits: &HitAreas, x: u16, y: u16) -> Option<(bool, usize)> {
+    if let Some(idx) = hits.radiant.and_then(|hit| hit.row_at(x, y)) {
+        return Some((true, idx));
+    }
+    hits.dire
+        .and_then(|hit| hit.row_at(x, y))
+        .map(|idx| (false, idx))
+}
+
+fn scroll_team_selection(app: &mut App, radiant: bool, down: bool) {
+    let total = app.team_players(radiant).len();
+    if total == 0 {
         return;
     }
-    if matches(keybinds.up, key.code, key.modifiers) {
-        if can_navigate(app) {
-            select_prev_match(app);
+    let next = match app.team_selection {
+        Some((side, idx)) if side == radiant => {
+            if down {
+                (idx + 1) % total
+            } else {
+                (idx + total - 1) % total
+            }
         }
-        app.recent_active = false;
+        _ => 0,
+    };
+    app.team_selection = Some((radiant, next));
+}
+
+fn open_team_player(
+    app: &mut App,
+    tx: &mpsc::Sender<Message>,
+    api: &ApiClient,
+    i18n: &I18n,
+) {
+    let Some((radiant, idx)) = app.team_selection else {
         return;
+    };
+    let account_id = app
+        .team_players(radiant)
+        .get(idx)
+        .and_then(|player| player.account_id);
+    match account_id {
+        Some(account_id) => {
+            start_search_with_id(app, tx, api, i18n, account_id);
+            app.input = account_id.to_string();
+        }
+        None => app.set_status(i18n.anonymous()),
     }
-    if matches(keybinds.top, key.code, key.modifiers) {
-        if can_navigate(app) && !app.matches.is_empty() {
-            app.match_state.select(Some(0));
+}
+
+fn handle_palette_key(
+    key: KeyEvent,
+    app: &mut App,
+    tx: &mpsc::Sender<Message>,
+    api: &ApiClient,
+    keybinds: &ResolvedKeybinds,
+    i18n: &I18n,
+) {
+    match edit_action_for_key(key, keybinds) {
+        Some(EditAction::Cancel) => close_palette(app),
+        Some(EditAction::Submit) => {
+            let selected = filter_actions(&app.palette_query, i18n)
+                .get(app.palette_index)
+                .copied();
+            close_palette(app);
+            if let Some(action) = selected {
+                dispatch_action(action, app, tx, api, i18n);
+            }
         }
-        app.recent_active = false;
+        Some(EditAction::Prev) => move_palette(app, i18n, false),
+        Some(EditAction::Next | EditAction::Complete) => move_palette(app, i18n, true),
+        Some(EditAction::ClearInput) => {
+            app.palette_query.clear();
+            app.palette_index = 0;
+        }
+        Some(EditAction::Help | EditAction::Run) | None => {
+            if edit_text(&mut app.palette_query, key) {
+                app.palette_index = 0;
+            }
+        }
+    }
+}
+
+fn open_palette(app: &mut App) {
+    app.palette_query.clear();
+    app.palette_index = 0;
+    app.input_mode = InputMode::Palette;
+    app.image_reset = true;
+}
+
+fn close_palette(app: &mut App) {
+    app.input_mode = InputMode::Normal;
+    app.image_reset = true;
+}
+
+fn move_palette(app: &mut App, i18n: &I18n, down: bool) {
+    let total = filter_actions(&app.palette_query, i18n).len();
+    if total == 0 {
         return;
     }
-    if matches(keybinds.bottom, key.code, key.modifiers) {
-        if can_navigate(app) && !app.matches.is_empty() {
-            app.match_state.select(Some(app.matches.len() - 1));
-        }
+    app.palette_index = if down {
+        (app.palette_index + 1) % total
+    } else {
+        (app.palette_index + total - 1) % total
+    };
+}
+
+fn switch_tab(app: &mut App, index: usize) {
+    app.tab_index = index;
+    app.image_reset = true;
+}
+
+fn clear_list_focus(app: &mut App) {
+    app.recent_active = false;
+    app.favorite_active = false;
+}
+
+fn select_current(
+    app: &mut App,
+    tx: &mpsc::Sender<Message>,
+    api: &ApiClient,
+    i18n: &I18n,
+) {
+    if let Some(account_id) = app.selected_favorite().map(|entry| entry.account_id) {
+        start_search_with_id(app, tx, api, i18n, account_id);
+        app.input = account_id.to_string();
+        app.favorite_active = false;
+        return;
+    }
+    if app.recent_active
+        && let Some(idx) = app.recent_index
+        && let Some(account_id) = app
+            .recent_searches
+            .get(idx)
+            .map(|entry| entry.account_id)
+    {
+        start_search_with_id(app, tx, api, i18n, account_id);
+        app.input = account_id.to_string();
         app.recent_active = false;
         return;
     }
-    if key.code == KeyCode::Tab && key.modifiers.contains(KeyModifiers::CONTROL) {
-        select_next_recent(app);
+    if app.tab_index == TAB_HEROES {
+        if app.hero_page.is_none() {
+            open_hero_page(app, i18n);
+        }
         return;
     }
-    if key.code == KeyCode::BackTab && key.modifiers.contains(KeyModifiers::CONTROL) {
-        select_prev_recent(app);
+    if app.tab_index == TAB_PRO {
+        select_pro(app, tx, api, i18n);
         return;
     }
-    if matches(keybinds.select, key.code, key.modifiers) {
-        if app.recent_active {
-            if let Some(idx) = app.recent_index {
-                if let Some(account_id) = app
-                    .recent_searches
-                    .get(idx)
-                    .map(|entry| entry.account_id)
-                {
-                    start_search_with_id(app, tx, api, i18n, account_id);
-                    app.input = account_id.to_string();
-                    app.recent_active = false;
-                    return;
-                }
-            }
-        }
-        if let Some(match_id) = app.selected_match().map(|m| m.match_id) {
-            app.detail_loading = true;
-            app.net_total = 1;
-            app.net_done = 0;
-            app.net_inflight = 1;
-            app.net_last_ms = None;
-            app.set_status(i18n.status_loading_match(match_id));
-            spawn_match_detail(tx.clone(), api.clone(), match_id);
-        }
+    if app.tab_index == TAB_LIVE {
         return;
     }
-    if matches(keybinds.tab_next, key.code, key.modifiers) {
-        app.tab_index = (app.tab_index + 1) % 3;
-        app.image_reset = true;
+    if app.tab_index == TAB_EXPLORER {
+        run_explorer(app, tx, api, i18n);
         return;
     }
-    if matches(keybinds.tab_prev, key.code, key.modifiers) {
-        if app.tab_index == 0 {
-            app.tab_index = 2;
-        } else {
-            app.tab_index -= 1;
+    if app.tab_index == TAB_PUBLIC {
+        let match_id = app
+            .public_matches
+            .as_ref()
+            .and_then(|matches| matches.get(app.public_index))
+            .map(|m| m.match_id);
+        if let Some(match_id) = match_id {
+            open_match(app, tx, api, i18n, match_id);
+            switch_tab(app, 1);
         }
-        app.image_reset = true;
         return;
     }
+    if let Some(match_id) = app.selected_match().map(|m| m.match_id) {
+        open_match(app, tx, api, i18n, match_id);
+    }
+}
 
-    match key.code {
-        KeyCode::Esc => {
-            app.input_mode = InputMode::Normal;
-            app.set_status(i18n.status_search_cancelled());
-        }
-        _ => {}
+fn open_match(
+    app: &mut App,
+    tx: &mpsc::Sender<Message>,
+    api: &ApiClient,
+    i18n: &I18n,
+    match_id: u64,
+) {
+    app.detail_loading = true;
+    app.net_total = 1;
+    app.net_done = 0;
+    app.net_inflight = 1;
+    app.net_last_ms = None;
+    app.set_status(i18n.status_loading_match(match_id));
+    spawn_match_detail(tx.clone(), api.clone(), match_id);
+}
+
+fn run_explorer(
+    app: &mut App,
+    tx: &mpsc::Sender<Message>,
+    api: &ApiClient,
+    i18n: &I18n,
+) {
+    let sql = app.explorer.text.trim().to_string();
+    if sql.is_empty() {
+        app.set_status(i18n.status_explorer_empty_sql());
+        return;
+    }
+    app.input_mode = InputMode::Normal;
+    app.explorer_loading = true;
+    app.net_total = 1;
+    app.net_done = 0;
+    app.net_inflight = 1;
+    app.net_last_ms = None;
+    app.set_status(i18n.status_explorer_running());
+    spawn_explorer(tx.clone(), api.clone(), sql);
+}
+
+fn select_pro(
+    app: &mut App,
+    tx: &mpsc::Sender<Message>,
+    api: &ApiClient,
+    i18n: &I18n,
+) {
+    let pro = &app.pro;
+    let match_id = match app.pro_page {
+        Some(ProPage::Team(team_id)) => pro
+            .team(team_id)
+            .and_then(|team| team.matches.get(app.pro_page_index))
+            .map(|m| m.match_id),
+        Some(ProPage::League(league_id)) => pro
+            .league(league_id)
+            .and_then(|league| league.matches.get(app.pro_page_index))
+            .map(|m| m.match_id),
+        None => match app.pro_view {
+            ProView::Matches => pro
+                .matches
+                .as_ref()
+                .and_then(|matches| matches.get(app.pro_index))
+                .map(|m| m.match_id),
+            ProView::Players => {
+                let account_id = pro
+                    .players
+                    .as_ref()
+                    .and_then(|players| players.get(app.pro_index))
+                    .map(|player| player.account_id);
+                if let Some(account_id) = account_id {
+                    start_search_with_id(app, tx, api, i18n, account_id);
+                    app.input = account_id.to_string();
+                }
+                return;
+            }
+            ProView::Teams => {
+                let page = pro
+                    .teams
+                    .as_ref()
+                    .and_then(|teams| teams.get(app.pro_index))
+                    .map(|team| ProPage::Team(team.team_id));
+                open_pro_page(app, page);
+                return;
+            }
+            ProView::Leagues => {
+                let page = pro
+                    .leagues
+                    .as_ref()
+                    .and_then(|leagues| leagues.get(app.pro_index))
+                    .map(|league| ProPage::League(league.leagueid));
+                open_pro_page(app, page);
+                return;
+            }
+        },
+    };
+    if let Some(match_id) = match_id {
+        open_match(app, tx, api, i18n, match_id);
+        switch_tab(app, 1);
+    }
+}
+
+fn open_pro_page(app: &mut App, page: Option<ProPage>) {
+    if page.is_some() {
+        app.pro_page = page;
+        app.pro_page_index = 0;
+        app.image_reset = true;
     }
 }
 
@@ -216,6 +1047,75 @@ fn select_prev_match(app: &mut App) {
     app.match_state.select(Some(prev));
 }
 
+fn tab_list_len(app: &App, i18n: &I18n) -> Option<usize> {
+    match app.tab_index {
+        TAB_HEROES if app.hero_page.is_none() => Some(app.hero_rows(i18n).len()),
+        TAB_PRO => Some(app.pro_list_len()),
+        TAB_LIVE => Some(app.live.as_ref().map_or(0, Vec::len)),
+        TAB_PUBLIC => Some(app.public_matches.as_ref().map_or(0, Vec::len)),
+        TAB_EXPLORER => Some(app.explorer_result.as_ref().map_or(0, |table| table.rows.len())),
+        _ => None,
+    }
+}
+
+fn list_cursor(app: &mut App) -> &mut usize {
+    match app.tab_index {
+        TAB_LIVE => &mut app.live_index,
+        TAB_PUBLIC => &mut app.public_index,
+        TAB_EXPLORER => &mut app.explorer_index,
+        TAB_PRO if app.pro_page.is_some() => &mut app.pro_page_index,
+        TAB_PRO => &mut app.pro_index,
+        _ => &mut app.hero_index,
+    }
+}
+
+fn next_medal(medal: Option<usize>) -> Option<usize> {
+    match medal {
+        None => Some(1),
+        Some(medal) if medal < HERO_BRACKETS => Some(medal + 1),
+        Some(_) => None,
+    }
+}
+
+fn show_public_matches(app: &mut App, i18n: &I18n) {
+    app.reset_public_matches();
+    switch_tab(app, TAB_PUBLIC);
+    let range = i18n.rank_range(app.public_min_rank, app.public_max_rank);
+    app.set_status(i18n.status_public_range(&range));
+}
+
+fn open_hero_page(app: &mut App, i18n: &I18n) {
+    app.hero_page = app.selected_hero(i18n);
+    app.image_reset = true;
+}
+
+fn show_hero_list(app: &mut App) {
+    app.hero_index = 0;
+    app.hero_page = None;
+    app.hero_requested = None;
+    switch_tab(app, TAB_HEROES);
+}
+
+fn select_next_row(app: &mut App, total: usize) {
+    if total == 0 {
+        return;
+    }
+    let cursor = list_cursor(app);
+    *cursor = if *cursor + 1 < total { *cursor + 1 } else { 0 };
+}
+
+fn select_prev_row(app: &mut App, total: usize) {
+    if total == 0 {
+        return;
+    }
+    let cursor = list_cursor(app);
+    *cursor = if *cursor > 0 && *cursor < total {
+        *cursor - 1
+    } else {
+        total - 1
+    };
+}
+
 fn parse_account_id(input: &str) -> Result<u32, String> {
     let trimmed = input.trim();
     let value: u64 = trimmed
@@ -268,10 +1168,21 @@ fn start_search_with_id(
     i18n: &I18n,
     account_id: u32,
 ) {
+    app.account_id = Some(account_id);
+    reset_player_state(app);
+    app.set_status(i18n.status_loading_player(account_id));
+    spawn_search(tx.clone(), api.clone(), account_id, SearchTarget::Primary);
+}
+
+fn reset_player_state(app: &mut App) {
     app.loading = true;
     app.detail_loading = false;
-    app.account_id = Some(account_id);
     app.profile = None;
+    app.aggregates = None;
+    app.histogram = None;
+    app.wardmap = None;
+    app.wordcloud = None;
+    app.chart_requested = None;
     app.clear_matches();
     app.avatar_url = None;
     app.avatar_loading = false;
@@ -283,8 +1194,49 @@ fn start_search_with_id(
     app.net_done = 0;
     app.net_inflight = 2;
     app.net_last_ms = None;
-    app.set_status(i18n.status_loading_player(account_id));
-    spawn_search(tx.clone(), api.clone(), account_id);
+}
+
+fn start_compare_from_input(
+    app: &mut App,
+    tx: &mpsc::Sender<Message>,
+    api: &ApiClient,
+    i18n: &I18n,
+) -> Result<(), ()> {
+    let parsed: Result<Vec<u32>, String> = app
+        .input
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|part| !part.is_empty())
+        .map(parse_account_id)
+        .collect();
+    let mut ids = match parsed {
+        Ok(ids) => ids,
+        Err(_) => {
+            app.set_status(i18n.status_invalid_id());
+            return Err(());
+        }
+    };
+    if ids.len() == 1
+        && let Some(current) = app.account_id
+    {
+        ids.insert(0, current);
+    }
+    if ids.len() != 2 {
+        app.set_status(i18n.status_compare_prompt());
+        return Err(());
+    }
+
+    app.compare_players = ids.iter().map(|id| LoadedPlayer::pending(*id)).collect();
+    app.tab_index = TAB_COMPARE;
+    app.image_reset = true;
+    app.net_total = ids.len();
+    app.net_done = 0;
+    app.net_inflight = ids.len();
+    app.net_last_ms = None;
+    app.set_status(i18n.status_loading_compare(ids[0], ids[1]));
+    for (slot, account_id) in ids.into_iter().enumerate() {
+        spawn_search(tx.clone(), api.clone(), account_id, SearchTarget::Compare(slot));
+    }
+    Ok(())
 }
 
 fn select_next_recent(app: &mut App) {
@@ -292,6 +1244,7 @@ fn select_next_recent(app: &mut App) {
         return;
     }
     app.recent_active = true;
+    app.favorite_active = false;
     let idx = match app.recent_index {
         Some(current) if current + 1 < app.recent_searches.len() => current + 1,
         _ => 0,
@@ -304,6 +1257,7 @@ fn select_prev_recent(app: &mut App) {
         return;
     }
     app.recent_active = true;
+    app.favorite_active = false;
     let idx = match app.recent_index {
         Some(current) if current > 0 => current - 1,
         _ => app.recent_searches.len() - 1,
@@ -311,6 +1265,71 @@ fn select_prev_recent(app: &mut App) {
     app.recent_index = Some(idx);
 }
 
+fn select_next_favorite(app: &mut App) {
+    if app.favorites.is_empty() {
+        return;
+    }
+    app.favorite_active = true;
+    app.recent_active = false;
+    let idx = match app.favorite_index {
+        Some(current) if current + 1 < app.favorites.len() => current + 1,
+        _ => 0,
+    };
+    app.favorite_index = Some(idx);
+}
+
+fn select_prev_favorite(app: &mut App) {
+    if app.favorites.is_empty() {
+        return;
+    }
+    app.favorite_active = true;
+    app.recent_active = false;
+    let idx = match app.favorite_index {
+        Some(current) if current > 0 => current - 1,
+        _ => app.favorites.len() - 1,
+    };
+    app.favorite_index = Some(idx);
+}
+
+fn toggle_favorite(app: &mut App, i18n: &I18n) {
+    let entry = if app.recent_active {
+        app.recent_index
+            .and_then(|idx| app.recent_searches.get(idx))
+            .map(|entry| FavoriteEntry {
+                account_id: entry.account_id,
+                personaname: entry.personaname.clone(),
+                alias: None,
+                avatar_url: entry.avatar_url.clone(),
+            })
+    } else {
+        app.account_id.filter(|_| app.profile.is_some()).map(|account_id| {
+            let personaname = app
+                .profile
+                .as_ref()
+                .and_then(|p| p.profile.as_ref())
+                .and_then(|p| p.personaname.clone())
+                .unwrap_or_else(|| i18n.unknown().to_string());
+            FavoriteEntry {
+                account_id,
+                personaname,
+                alias: None,
+                avatar_url: app.avatar_url.clone(),
+            }
+        })
+    };
+    let Some(entry) = entry else {
+        app.set_status(i18n.status_no_favorite_target());
+        return;
+    };
+    let name = entry.personaname.clone();
+    if app.toggle_favorite(entry) {
+        app.set_status(i18n.status_favorite_added(&name));
+    } else {
+        app.set_status(i18n.status_favorite_removed(&name));
+    }
+    app.image_reset = true;
+}
+
 fn autocomplete_recent(app: &App) -> Option<&crate::app::SearchEntry> {
     if app.recent_searches.is_empty() {
         return None;
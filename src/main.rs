@@ -2,28 +2,35 @@ use std::io;
 use std::time::Duration;
 
 use anyhow::Result;
-use crossterm::event::EventStream;
+use crossterm::event::{DisableMouseCapture, EnableMouseCapture, EventStream};
 use crossterm::execute;
 use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
 use futures::StreamExt;
 use tokio::sync::mpsc;
 use tokio::time::interval;
 
+mod actions;
 mod api;
 mod app;
 mod config;
+mod editor;
+mod export;
 mod image;
 mod i18n;
 mod input;
 mod models;
+mod terminal;
 mod ui;
 
 use crate::api::ApiClient;
 use crate::app::{
-    handle_message, load_avatar_map, load_recent_searches, spawn_hero_images, spawn_hero_load,
-    spawn_image_fetch, spawn_item_images, spawn_player_avatars, App, Message,
+    handle_message, load_avatar_map, load_favorites, load_recent_searches, load_saved_queries, spawn_constants_load,
+    spawn_hero_images, spawn_hero_load, spawn_chart, spawn_distribution, spawn_hero_detail, spawn_image_fetch,
+    spawn_item_images, spawn_live,
+    spawn_player_avatars, spawn_pro, spawn_public_matches, App, Message, PendingCommand, LIVE_REFRESH_TICKS,
+    TAB_CHARTS, TAB_HEROES, TAB_LIVE,
 };
-use crate::config::Config;
+use crate::config::{default_theme, modified_at, Config, ResolvedKeybinds};
 use crate::image::{ensure_png, read_disk_cache, write_disk_cache, ImageSupport};
 use crate::i18n::I18n;
 use crate::input::handle_event;
@@ -31,30 +38,47 @@ use crate::ui::draw_ui;
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    let (mut config, config_path) = Config::load_or_create()?;
+    let mut config_mtime = modified_at(&config_path);
+    let mut keybinds = config.resolve_keybinds()?;
+    let mut i18n = I18n::new(crate::i18n::I18n::language_from_config(&config.ui.language));
+    let mut cdn_base = config.images.cdn_base.clone();
+
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = ratatui::backend::CrosstermBackend::new(stdout);
     let mut terminal = ratatui::Terminal::new(backend)?;
 
-    let (config, _config_path) = Config::load_or_create()?;
-    let theme = config.resolve_theme();
-    let keybinds = config.resolve_keybinds()?;
+    let color_depth = terminal::detect_color_depth();
+    let background = terminal::detect_background();
+    let (mut theme, theme_error) = match config.resolve_theme(color_depth, background) {
+        Ok(theme) => (theme, None),
+        Err(err) => (default_theme(color_depth, background), Some(err)),
+    };
     let mut images = ImageSupport::from_config(&config.images);
-    let i18n = I18n::new(crate::i18n::I18n::language_from_config(&config.ui.language));
-    let cdn_base = config.images.cdn_base.clone();
 
     let api = ApiClient::new(config.api.clone());
     let (tx, mut rx) = mpsc::channel::<Message>(16);
 
     let mut app = App::new();
     app.set_status(i18n.status_ready());
+    if let Some(warning) = keybind_warnings(&keybinds, &i18n) {
+        app.set_status(warning);
+    }
+    if let Some(err) = theme_error {
+        app.set_status(i18n.status_theme_invalid(&single_line(&err)));
+    }
     app.recent_searches = load_recent_searches(5);
+    app.favorites = load_favorites();
+    app.saved_queries = load_saved_queries();
     app.player_avatars = load_avatar_map();
     app.net_total = app.net_total.saturating_add(1);
     app.net_inflight = app.net_inflight.saturating_add(1);
     spawn_hero_load(tx.clone(), api.clone());
-    let cdn_base = cdn_base;
+    app.net_total = app.net_total.saturating_add(1);
+    app.net_inflight = app.net_inflight.saturating_add(1);
+    spawn_constants_load(tx.clone(), api.clone());
 
     let mut events = EventStream::new();
     let mut tick = interval(Duration::from_millis(200));
@@ -62,14 +86,16 @@ async fn main() -> Result<()> {
     loop {
         let mut image_targets = Vec::new();
         terminal.draw(|frame| {
-            image_targets = draw_ui(frame, &mut app, theme, &config.keybinds, &i18n).images;
+            let result = draw_ui(frame, &mut app, theme, &config.keybinds, &i18n);
+            image_targets = result.images;
+            app.hit_areas = result.hits;
         })?;
         if app.image_reset {
             images.reset(&mut terminal)?;
             app.image_reset = false;
         }
 
-        if !app.show_help {
+        if !app.overlay_open() {
             for target in image_targets {
                 if let Some(bytes) = app.image_cache.get(&target.url) {
                     images.render_avatar(&mut terminal, Some(target.area), Some(bytes))?;
@@ -102,9 +128,59 @@ async fn main() -> Result<()> {
             }
         }
 
+        if app.tab_index == TAB_CHARTS
+            && let Some(key) = app.chart_request(&i18n)
+        {
+            app.net_total = app.net_total.saturating_add(1);
+            app.net_inflight = app.net_inflight.saturating_add(1);
+            spawn_chart(tx.clone(), api.clone(), key);
+        }
+
+        if app.tab_index == TAB_HEROES
+            && let Some(hero_id) = app.hero_request()
+        {
+            app.net_total = app.net_total.saturating_add(1);
+            app.net_inflight = app.net_inflight.saturating_add(1);
+            spawn_hero_detail(tx.clone(), api.clone(), hero_id);
+        }
+
+        if let Some(request) = app.pro_request() {
+            app.net_total = app.net_total.saturating_add(1);
+            app.net_inflight = app.net_inflight.saturating_add(1);
+            spawn_pro(tx.clone(), api.clone(), request);
+        }
+
+        if app.live_request() {
+            app.net_total = app.net_total.saturating_add(1);
+            app.net_inflight = app.net_inflight.saturating_add(1);
+            spawn_live(tx.clone(), api.clone());
+        }
+
+        if let Some(key) = app.public_request() {
+            app.net_total = app.net_total.saturating_add(1);
+            app.net_inflight = app.net_inflight.saturating_add(1);
+            spawn_public_matches(tx.clone(), api.clone(), key);
+        }
+
+        if app.distribution_request() {
+            app.net_total = app.net_total.saturating_add(1);
+            app.net_inflight = app.net_inflight.saturating_add(1);
+            spawn_distribution(tx.clone(), api.clone());
+        }
+
         tokio::select! {
             _ = tick.tick() => {
                 app.advance_tick();
+                if app.tab_index == TAB_LIVE && app.tick.is_multiple_of(LIVE_REFRESH_TICKS) {
+                    app.live_stale = true;
+                }
+                if app.tick.is_multiple_of(5) {
+                    let mtime = modified_at(&config_path);
+                    if mtime.is_some() && mtime != config_mtime {
+                        config_mtime = mtime;
+                        app.pending_commands.push(PendingCommand::ReloadConfig);
+                    }
+                }
             },
             maybe_event = events.next() => {
                 if let Some(Ok(event)) = maybe_event {
@@ -113,17 +189,17 @@ async fn main() -> Result<()> {
             }
             maybe_msg = rx.recv() => {
                 if let Some(msg) = maybe_msg {
-                    if let Message::ImageLoaded { url, result: Ok(bytes) } = &msg {
-                        if let Ok(png) = ensure_png(bytes) {
-                            let _ = write_disk_cache(url, &png);
-                            app.cache_image(url.clone(), png);
-                        }
+                    if let Message::ImageLoaded { url, result: Ok(bytes) } = &msg
+                        && let Ok(png) = ensure_png(bytes)
+                    {
+                        let _ = write_disk_cache(url, &png);
+                        app.cache_image(url.clone(), png);
                     }
-                    if let Some(url) = handle_message(msg, &mut app, &i18n) {
-                        if !app.image_inflight.contains(&url) {
-                            app.image_inflight.insert(url.clone());
-                            spawn_image_fetch(tx.clone(), api.clone(), url);
-                        }
+                    if let Some(url) = handle_message(msg, &mut app, &i18n)
+                        && !app.image_inflight.contains(&url)
+                    {
+                        app.image_inflight.insert(url.clone());
+                        spawn_image_fetch(tx.clone(), api.clone(), url);
                     }
                     if !app.pending_player_avatar_ids.is_empty() {
                         let ids = std::mem::take(&mut app.pending_player_avatar_ids);
@@ -133,14 +209,87 @@ async fn main() -> Result<()> {
             }
         }
 
+        for command in std::mem::take(&mut app.pending_commands) {
+            match command {
+                PendingCommand::CycleTheme => {
+                    let names = config.theme_names();
+                    let current = names
+                        .iter()
+                        .position(|name| *name == config.theme.name)
+                        .map(|idx| idx + 1)
+                        .unwrap_or(0);
+                    let previous = std::mem::replace(&mut config.theme.name, names[current % names.len()].clone());
+                    match config.resolve_theme(color_depth, background) {
+                        Ok(next) => {
+                            theme = next;
+                            app.set_status(i18n.status_theme_changed(&config.theme.name));
+                        }
+                        Err(err) => {
+                            app.set_status(i18n.status_theme_invalid(&single_line(&err)));
+                            config.theme.name = previous;
+                        }
+                    }
+                }
+                PendingCommand::CycleLanguage => {
+                    i18n = I18n::new(i18n.language().next());
+                    app.set_status(i18n.status_language_changed());
+                }
+                PendingCommand::ReloadConfig => {
+                    let reloaded = Config::load(&config_path).and_then(|next| {
+                        let next_theme = next.resolve_theme(color_depth, background)?;
+                        let next_keybinds = next.resolve_keybinds()?;
+                        Ok((next, next_theme, next_keybinds))
+                    });
+                    match reloaded {
+                        Ok((next, next_theme, next_keybinds)) => {
+                            images.reset(&mut terminal)?;
+                            config = next;
+                            theme = next_theme;
+                            keybinds = next_keybinds;
+                            images = ImageSupport::from_config(&config.images);
+                            i18n = I18n::new(I18n::language_from_config(&config.ui.language));
+                            cdn_base = config.images.cdn_base.clone();
+                            match keybind_warnings(&keybinds, &i18n) {
+                                Some(warning) => app.set_status(warning),
+                                None => app.set_status(i18n.status_config_reloaded()),
+                            }
+                        }
+                        Err(err) => app.set_status(i18n.status_config_invalid(&single_line(&err))),
+                    }
+                }
+            }
+            app.image_reset = true;
+        }
+
         if app.should_quit {
             break;
         }
     }
 
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen, DisableMouseCapture)?;
     terminal.show_cursor()?;
 
     Ok(())
 }
+
+fn keybind_warnings(keybinds: &ResolvedKeybinds, i18n: &I18n) -> Option<String> {
+    let mut warnings = Vec::new();
+    if !keybinds.deprecated.is_empty() {
+        warnings.push(i18n.status_keybind_deprecated(&keybinds.deprecated.join("; ")));
+    }
+    if !keybinds.conflicts.is_empty() {
+        warnings.push(i18n.status_keybind_conflicts(&keybinds.conflicts.join("; ")));
+    }
+    (!warnings.is_empty()).then(|| warnings.join(" · "))
+}
+
+fn single_line(err: &anyhow::Error) -> String {
+    let detail = format!("{err:#}");
+    let mut lines = detail.lines().map(str::trim).filter(|line| !line.is_empty());
+    let first = lines.next().unwrap_or_default().to_string();
+    match lines.next_back() {
+        Some(last) if !last.starts_with('|') && !last.starts_with('^') => format!("{first} ({last})"),
+        _ => first,
+    }
+}
@@ -1,9 +1,85 @@
-use serde::Deserialize;
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct HeroStat {
     pub id: i32,
+    #[serde(default)]
+    pub name: String,
     pub localized_name: String,
+    pub primary_attr: Option<String>,
+    pub attack_type: Option<String>,
+    #[serde(default)]
+    pub roles: Vec<String>,
+    #[serde(rename = "1_pick", default)]
+    pub pick_1: i64,
+    #[serde(rename = "1_win", default)]
+    pub win_1: i64,
+    #[serde(rename = "2_pick", default)]
+    pub pick_2: i64,
+    #[serde(rename = "2_win", default)]
+    pub win_2: i64,
+    #[serde(rename = "3_pick", default)]
+    pub pick_3: i64,
+    #[serde(rename = "3_win", default)]
+    pub win_3: i64,
+    #[serde(rename = "4_pick", default)]
+    pub pick_4: i64,
+    #[serde(rename = "4_win", default)]
+    pub win_4: i64,
+    #[serde(rename = "5_pick", default)]
+    pub pick_5: i64,
+    #[serde(rename = "5_win", default)]
+    pub win_5: i64,
+    #[serde(rename = "6_pick", default)]
+    pub pick_6: i64,
+    #[serde(rename = "6_win", default)]
+    pub win_6: i64,
+    #[serde(rename = "7_pick", default)]
+    pub pick_7: i64,
+    #[serde(rename = "7_win", default)]
+    pub win_7: i64,
+    #[serde(rename = "8_pick", default)]
+    pub pick_8: i64,
+    #[serde(rename = "8_win", default)]
+    pub win_8: i64,
+    #[serde(default)]
+    pub pro_pick: i64,
+    #[serde(default)]
+    pub pro_win: i64,
+    #[serde(default)]
+    pub pro_ban: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct HeroMatchup {
+    pub hero_id: i32,
+    #[serde(default)]
+    pub games_played: i64,
+    #[serde(default)]
+    pub wins: i64,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct ItemPopularity {
+    #[serde(default)]
+    pub start_game_items: HashMap<i32, i64>,
+    #[serde(default)]
+    pub early_game_items: HashMap<i32, i64>,
+    #[serde(default)]
+    pub mid_game_items: HashMap<i32, i64>,
+    #[serde(default)]
+    pub late_game_items: HashMap<i32, i64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct HeroDuration {
+    pub duration_bin: i64,
+    #[serde(default)]
+    pub games_played: i64,
+    #[serde(default)]
+    pub wins: i64,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -16,6 +92,8 @@ pub struct HeroConstant {
 pub struct PlayerResponse {
     pub profile: Option<PlayerProfile>,
     pub mmr_estimate: Option<MmrEstimate>,
+    pub rank_tier: Option<i32>,
+    pub leaderboard_rank: Option<i32>,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -41,14 +119,32 @@ pub struct PlayerMatch {
     pub start_time: Option<i64>,
     pub hero_id: i32,
     pub game_mode: Option<i32>,
+    pub lobby_type: Option<i32>,
+    pub cluster: Option<i32>,
     pub kills: Option<i32>,
     pub deaths: Option<i32>,
     pub assists: Option<i32>,
+    pub gold_per_min: Option<i32>,
+    pub xp_per_min: Option<i32>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct MatchDetail {
+    pub start_time: Option<i64>,
+    pub patch: Option<i32>,
     pub players: Vec<MatchPlayer>,
+    pub chat: Option<Vec<ChatMessage>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ChatMessage {
+    pub time: i64,
+    #[serde(rename = "type", default)]
+    pub kind: String,
+    #[serde(default)]
+    pub key: serde_json::Value,
+    pub player_slot: Option<u16>,
+    pub unit: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -69,10 +165,235 @@ pub struct MatchPlayer {
     pub gold_per_min: Option<i32>,
     pub xp_per_min: Option<i32>,
     pub net_worth: Option<i32>,
+    pub lane: Option<i32>,
+    pub lane_role: Option<i32>,
+    pub is_roaming: Option<bool>,
+    pub lh_t: Option<Vec<i32>>,
+    pub dn_t: Option<Vec<i32>>,
+    pub gold_t: Option<Vec<i32>>,
+    pub lane_efficiency_pct: Option<f64>,
+    pub benchmarks: Option<HashMap<String, Benchmark>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Benchmark {
+    pub raw: Option<f64>,
+    pub pct: Option<f64>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct ItemConstant {
     pub id: i32,
     pub img: Option<String>,
+    pub dname: Option<String>,
+    #[serde(default)]
+    pub key: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct GameConstants {
+    pub game_modes: HashMap<i32, NamedConstant>,
+    pub lobby_types: HashMap<i32, NamedConstant>,
+    pub regions: HashMap<i32, String>,
+    pub clusters: HashMap<i32, i32>,
+    pub patches: Vec<PatchConstant>,
+    #[serde(skip)]
+    pub patch_dates: Vec<(chrono::DateTime<chrono::Utc>, usize)>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct NamedConstant {
+    pub id: i32,
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PatchConstant {
+    pub id: i32,
+    pub name: String,
+    pub date: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PlayerTotal {
+    pub field: String,
+    pub n: i64,
+    pub sum: f64,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct PlayerCounts {
+    pub lobby_type: HashMap<i32, CountEntry>,
+    pub game_mode: HashMap<i32, CountEntry>,
+    pub is_radiant: HashMap<i32, CountEntry>,
+    pub region: HashMap<i32, CountEntry>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct CountEntry {
+    pub games: i64,
+    pub win: i64,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct WinLoss {
+    pub win: i64,
+    pub lose: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct HistogramBucket {
+    pub x: f64,
+    #[serde(default)]
+    pub games: i64,
+    #[serde(default)]
+    pub win: i64,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct WardMap {
+    pub obs: HashMap<i32, HashMap<i32, i64>>,
+    pub sen: HashMap<i32, HashMap<i32, i64>>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct WordCloud {
+    pub my_word_counts: HashMap<String, i64>,
+    pub all_word_counts: HashMap<String, i64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ProMatch {
+    pub match_id: u64,
+    pub duration: Option<u32>,
+    pub start_time: Option<i64>,
+    pub radiant_name: Option<String>,
+    pub dire_name: Option<String>,
+    pub league_name: Option<String>,
+    pub radiant_score: Option<i32>,
+    pub dire_score: Option<i32>,
+    pub radiant_win: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ProPlayer {
+    pub account_id: u32,
+    pub name: Option<String>,
+    pub personaname: Option<String>,
+    pub team_name: Option<String>,
+    pub team_tag: Option<String>,
+    pub country_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ProTeam {
+    pub team_id: i64,
+    pub rating: Option<f64>,
+    #[serde(default)]
+    pub wins: i64,
+    #[serde(default)]
+    pub losses: i64,
+    pub last_match_time: Option<i64>,
+    pub name: Option<String>,
+    pub tag: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct TeamMatch {
+    pub match_id: u64,
+    pub radiant_win: Option<bool>,
+    pub radiant: Option<bool>,
+    pub duration: Option<u32>,
+    pub start_time: Option<i64>,
+    pub league_name: Option<String>,
+    pub opposing_team_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct TeamPlayer {
+    pub name: Option<String>,
+    #[serde(default)]
+    pub games_played: i64,
+    #[serde(default)]
+    pub wins: i64,
+    pub is_current_team_member: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct LiveGame {
+    pub average_mmr: Option<i32>,
+    pub game_time: Option<i64>,
+    pub spectators: Option<i64>,
+    pub radiant_score: Option<i32>,
+    pub dire_score: Option<i32>,
+    pub radiant_lead: Option<i32>,
+    pub team_name_radiant: Option<String>,
+    pub team_name_dire: Option<String>,
+    #[serde(default)]
+    pub players: Vec<LivePlayer>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct LivePlayer {
+    pub account_id: Option<u32>,
+    pub hero_id: Option<i32>,
+    pub team: Option<i32>,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PublicMatch {
+    pub match_id: u64,
+    pub radiant_win: Option<bool>,
+    pub start_time: Option<i64>,
+    pub duration: Option<u32>,
+    pub avg_rank_tier: Option<f64>,
+    #[serde(default)]
+    pub radiant_team: Vec<i32>,
+    #[serde(default)]
+    pub dire_team: Vec<i32>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Distributions {
+    pub ranks: RankDistribution,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct RankDistribution {
+    #[serde(default)]
+    pub rows: Vec<RankBin>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct RankBin {
+    pub bin_name: i32,
+    #[serde(default)]
+    pub count: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ExplorerResult {
+    #[serde(default)]
+    pub fields: Vec<ExplorerField>,
+    #[serde(default)]
+    pub rows: Vec<HashMap<String, serde_json::Value>>,
+    pub err: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ExplorerField {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct League {
+    pub leagueid: i32,
+    pub name: Option<String>,
+    pub tier: Option<String>,
 }
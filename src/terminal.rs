@@ -0,0 +1,162 @@
+use std::env;
+use std::time::Duration;
+
+use crate::config::{Background, ColorDepth};
+
+pub fn detect_color_depth() -> ColorDepth {
+    let colorterm = env::var("COLORTERM").unwrap_or_default().to_ascii_lowercase();
+    if colorterm.contains("truecolor") || colorterm.contains("24bit") {
+        return ColorDepth::TrueColor;
+    }
+    let term = env::var("TERM").unwrap_or_default().to_ascii_lowercase();
+    let term_program = env::var("TERM_PROGRAM").unwrap_or_default();
+    if term.contains("direct")
+        || term.contains("kitty")
+        || env::var("WT_SESSION").is_ok()
+        || matches!(term_program.as_str(), "iTerm.app" | "WezTerm" | "ghostty")
+    {
+        return ColorDepth::TrueColor;
+    }
+    if term.contains("256") {
+        return ColorDepth::Ansi256;
+    }
+    ColorDepth::Ansi16
+}
+
+pub fn detect_background() -> Background {
+    query_background(Duration::from_millis(150))
+        .map(|(r, g, b)| {
+            let luma = 0.299 * f64::from(r) + 0.587 * f64::from(g) + 0.114 * f64::from(b);
+            if luma > 128.0 {
+                Background::Light
+            } else {
+                Background::Dark
+            }
+        })
+        .or_else(background_from_env)
+        .unwrap_or(Background::Dark)
+}
+
+fn background_from_env() -> Option<Background> {
+    let value = env::var("COLORFGBG").ok()?;
+    let bg: u8 = value.rsplit(';').next()?.parse().ok()?;
+    Some(if matches!(bg, 7 | 9..=15) {
+        Background::Light
+    } else {
+        Background::Dark
+    })
+}
+
+#[cfg(unix)]
+fn query_background(timeout: Duration) -> Option<(u8, u8, u8)> {
+    use std::fs::OpenOptions;
+    use std::io::Write;
+    use std::os::fd::AsRawFd;
+    use std::time::Instant;
+
+    let mut tty = OpenOptions::new().read(true).write(true).open("/dev/tty").ok()?;
+    tty.write_all(b"\x1b]11;?\x1b\\\x1b[c").ok()?;
+    tty.flush().ok()?;
+
+    let mut response = Vec::new();
+    read_reply(&mut tty, Instant::now() + timeout, &mut response);
+    if !da1_received(&response) {
+        read_reply(&mut tty, Instant::now() + LATE_REPLY_GRACE, &mut Vec::new());
+    }
+    // SAFETY: the descriptor belongs to the open /dev/tty handle, and TCIFLUSH only discards unread input.
+    unsafe { libc::tcflush(tty.as_raw_fd(), libc::TCIFLUSH) };
+    parse_osc11(&String::from_utf8_lossy(&response))
+}
+
+#[cfg(unix)]
+const LATE_REPLY_GRACE: Duration = Duration::from_millis(350);
+
+#[cfg(unix)]
+fn read_reply(tty: &mut std::fs::File, deadline: std::time::Instant, response: &mut Vec<u8>) {
+    use std::io::Read;
+    use std::os::fd::AsRawFd;
+    use std::time::Instant;
+
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+        let mut poll_fd = libc::pollfd {
+            fd: tty.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        // SAFETY: poll_fd is a single initialized pollfd for a descriptor that stays open for the whole call.
+        let ready = unsafe { libc::poll(&mut poll_fd, 1, remaining.as_millis() as libc::c_int) };
+        if ready <= 0 {
+            break;
+        }
+        let mut chunk = [0u8; 64];
+        let Ok(read) = tty.read(&mut chunk) else {
+            break;
+        };
+        if read == 0 {
+            break;
+        }
+        response.extend_from_slice(&chunk[..read]);
+        if da1_received(response) {
+            break;
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn query_background(_timeout: Duration) -> Option<(u8, u8, u8)> {
+    None
+}
+
+#[cfg(unix)]
+fn da1_received(response: &[u8]) -> bool {
+    response
+        .windows(3)
+        .position(|window| window == b"\x1b[?")
+        .is_some_and(|start| response[start..].contains(&b'c'))
+}
+
+fn parse_osc11(response: &str) -> Option<(u8, u8, u8)> {
+    let start = response.find("rgb:")? + 4;
+    let body = &response[start..];
+    let end = body.find(['\x07', '\x1b']).unwrap_or(body.len());
+    let mut channels = body[..end].split('/').map(|part| {
+        let value = u32::from_str_radix(part, 16).ok()?;
+        let max = 16u32.checked_pow(part.len() as u32)?.checked_sub(1)?;
+        (max > 0).then(|| (value * 255 / max) as u8)
+    });
+    Some((channels.next()??, channels.next()??, channels.next()??))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_osc11_replies() {
+        assert_eq!(parse_osc11("\x1b]11;rgb:ffff/ffff/ffff\x1b\\"), Some((255, 255, 255)));
+        assert_eq!(parse_osc11("\x1b]11;rgb:1e1e/1e1e/2e2e\x07\x1b[?62;22c"), Some((30, 30, 46)));
+        assert_eq!(parse_osc11("\x1b]11;rgb:80/00/ff\x1b\\"), Some((128, 0, 255)));
+        assert_eq!(parse_osc11("\x1b]11;rgb:f/0/8"), Some((255, 0, 136)));
+    }
+
+    #[test]
+    fn rejects_malformed_osc11_replies() {
+        assert_eq!(parse_osc11(""), None);
+        assert_eq!(parse_osc11("\x1b[?62;22c"), None);
+        assert_eq!(parse_osc11("\x1b]11;rgb:ffff/ffff\x1b\\"), None);
+        assert_eq!(parse_osc11("\x1b]11;rgb:zz/00/00\x1b\\"), None);
+        assert_eq!(parse_osc11("\x1b]11;rgb://\x1b\\"), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn detects_da1_after_the_reply() {
+        assert!(!da1_received(b"\x1b]11;rgb:0/0/0\x1b\\"));
+        assert!(!da1_received(b"\x1b[?62;22"));
+        assert!(da1_received(b"\x1b]11;rgb:0/0/0\x1b\\\x1b[?62;22c"));
+    }
+}
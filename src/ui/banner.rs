@@ -2,19 +2,17 @@ use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph};
 
 use crate::app::App;
+use crate::config::Theme;
 use crate::i18n::I18n;
 
 pub fn draw_banner(
     frame: &mut Frame,
     _app: &App,
     area: Rect,
-    base: Color,
-    _text: Color,
-    accent: Color,
+    theme: Theme,
     i18n: &I18n,
 ) {
-    let accent_color = Color::Rgb(220, 38, 38);
-
+    let Theme { base, border, banner: art_color, banner_text, .. } = theme;
     let art = [
         "⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀",
         "⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠲⣶⡖⠒⠒⠲⢦⣄⡀⠀⠀⠀⠀⠀⠀⠀⢀⡤⠒⠂⠐⠲⢤⡀⠀⠀⠀⠀⠀⢰⡤⠤⠤⣤⡤⠤⠤⣴⠀⠀⠀⠀⠀⠀⠀⢠⣷⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣠⠞⠛⠻⢿⣦⡀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀",
@@ -27,19 +25,19 @@ pub fn draw_banner(
 
     let mut text_lines = Vec::new();
     for line_art in art.iter() {
-        text_lines.push(Line::styled(*line_art, Style::default().fg(accent_color)));
+        text_lines.push(Line::styled(*line_art, Style::default().fg(art_color)));
     }
     text_lines.push(Line::from(""));
     text_lines.push(Line::styled(
         i18n.banner_subtitle(),
-        Style::default().fg(Color::Rgb(248, 250, 252)),
+        Style::default().fg(banner_text),
     ));
 
     let banner = Paragraph::new(text_lines)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(accent)),
+                .border_style(Style::default().fg(border)),
         )
         .style(Style::default().bg(base))
         .alignment(Alignment::Center);
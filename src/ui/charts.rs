@@ -0,0 +1,244 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Bar, BarChart, BarGroup, Block, Borders, Paragraph, Wrap};
+
+use crate::app::{App, ChartView, WardKind};
+use crate::config::Theme;
+use crate::i18n::I18n;
+
+use super::helpers::{match_filter_summary, truncate_text};
+
+const WARD_MIN: f64 = 64.0;
+const WARD_SPAN: f64 = 128.0;
+
+pub fn draw_charts_tab(frame: &mut Frame, app: &App, area: Rect, theme: Theme, i18n: &I18n) {
+    match app.chart_view {
+        ChartView::Histogram => draw_histogram(frame, app, area, theme, i18n),
+        ChartView::Wardmap => draw_wardmap(frame, app, area, theme, i18n),
+        ChartView::Wordcloud => draw_wordcloud(frame, app, area, theme, i18n),
+    }
+}
+
+fn chart_block<'a>(app: &App, title: String, theme: Theme, i18n: &I18n) -> Block<'a> {
+    let mut filters = match_filter_summary(app, i18n, true);
+    if app.stats_patch_id().is_some() {
+        filters.push(app.stats_patch_label(i18n));
+    }
+    let title = if filters.is_empty() {
+        title
+    } else {
+        format!("{title} · {}", filters.join(" · "))
+    };
+    Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(Style::default().fg(theme.border))
+}
+
+fn draw_placeholder(frame: &mut Frame, area: Rect, block: Block, message: &str, theme: Theme) {
+    let placeholder = Paragraph::new(message.to_string())
+        .block(block)
+        .style(Style::default().bg(theme.base).fg(theme.text))
+        .wrap(Wrap { trim: true });
+    frame.render_widget(placeholder, area);
+}
+
+fn draw_histogram(frame: &mut Frame, app: &App, area: Rect, theme: Theme, i18n: &I18n) {
+    let Theme { base, text, win, loss, .. } = theme;
+    let title = i18n.title_histogram(i18n.total_field(app.chart_field()));
+    let block = chart_block(app, title, theme, i18n);
+    if app.account_id.is_none() {
+        draw_placeholder(frame, area, block, i18n.no_player_loaded(), theme);
+        return;
+    }
+    let Some(histogram) = app.current_histogram() else {
+        draw_placeholder(frame, area, block, i18n.loading_player(), theme);
+        return;
+    };
+    let (Some(first), Some(last)) = (
+        histogram.buckets.iter().position(|bucket| bucket.games > 0),
+        histogram.buckets.iter().rposition(|bucket| bucket.games > 0),
+    ) else {
+        draw_placeholder(frame, area, block, i18n.histogram_empty(), theme);
+        return;
+    };
+
+    let buckets = &histogram.buckets[first..=last];
+    let inner_width = area.width.saturating_sub(2);
+    let count = buckets.len().max(1) as u16;
+    let bar_width = (inner_width / count).saturating_sub(1).clamp(1, 6);
+    let bars: Vec<Bar> = buckets
+        .iter()
+        .map(|bucket| {
+            let winrate = bucket.win as f64 / bucket.games.max(1) as f64;
+            let color = if winrate >= 0.5 { win } else { loss };
+            let value = if bucket.games > 0 {
+                format!("{:.0}%", winrate * 100.0)
+            } else {
+                String::new()
+            };
+            Bar::default()
+                .value(bucket.games.max(0) as u64)
+                .label(Line::from(format!("{}", bucket.x)))
+                .text_value(value)
+                .style(Style::default().fg(color))
+                .value_style(Style::default().fg(base).bg(color))
+        })
+        .collect();
+    let chart = BarChart::default()
+        .block(block)
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(bar_width)
+        .bar_gap(1)
+        .style(Style::default().bg(base).fg(text));
+    frame.render_widget(chart, area);
+}
+
+fn draw_wardmap(frame: &mut Frame, app: &App, area: Rect, theme: Theme, i18n: &I18n) {
+    let Theme { base, text, success, accent, warn, loss, highlight, .. } = theme;
+    let title = i18n.title_wardmap(i18n.ward_kind(app.ward_kind));
+    if app.account_id.is_none() {
+        let block = chart_block(app, title, theme, i18n);
+        draw_placeholder(frame, area, block, i18n.no_player_loaded(), theme);
+        return;
+    }
+    let Some(wardmap) = app.current_wardmap() else {
+        let block = chart_block(app, title, theme, i18n);
+        draw_placeholder(frame, area, block, i18n.loading_player(), theme);
+        return;
+    };
+    let placements = match app.ward_kind {
+        WardKind::Observer => &wardmap.wards.obs,
+        WardKind::Sentry => &wardmap.wards.sen,
+    };
+    let total: i64 = placements.values().flat_map(|column| column.values()).sum();
+    let block = chart_block(app, format!("{title} · {}", i18n.wardmap_total(total)), theme, i18n);
+    if total == 0 {
+        draw_placeholder(frame, area, block, i18n.histogram_empty(), theme);
+        return;
+    }
+
+    let inner = block.inner(area);
+    frame.render_widget(block.style(Style::default().bg(base).fg(text)), area);
+    let side = inner.width.min(inner.height.saturating_mul(2)) as usize;
+    if side < 4 {
+        return;
+    }
+    let rows = side / 2;
+    let mut density = vec![vec![0i64; side]; rows * 2];
+    for (x, column) in placements {
+        for (y, count) in column {
+            let px = ((f64::from(*x) - WARD_MIN) / WARD_SPAN * side as f64).floor();
+            let py = ((1.0 - (f64::from(*y) - WARD_MIN) / WARD_SPAN) * (rows * 2) as f64).floor();
+            if px < 0.0 || py < 0.0 {
+                continue;
+            }
+            let (px, py) = (px as usize, py as usize);
+            if px < side && py < rows * 2 {
+                density[py][px] += count;
+            }
+        }
+    }
+    let max = density.iter().flatten().copied().max().unwrap_or(0).max(1);
+    let ramp = [success, accent, warn, loss];
+    let grid = (side / 8).max(1);
+    let color_at = |px: usize, py: usize| -> Color {
+        let count = density[py][px];
+        if count > 0 {
+            let level = ((count as f64 / max as f64) * ramp.len() as f64).ceil() as usize;
+            return ramp[level.clamp(1, ramp.len()) - 1];
+        }
+        if px.is_multiple_of(grid) || py.is_multiple_of(grid) {
+            highlight
+        } else {
+            base
+        }
+    };
+
+    let lines: Vec<Line> = (0..rows)
+        .map(|row| {
+            let spans: Vec<Span> = (0..side)
+                .map(|px| {
+                    Span::styled(
+                        "▀",
+                        Style::default()
+                            .fg(color_at(px, row * 2))
+                            .bg(color_at(px, row * 2 + 1)),
+                    )
+                })
+                .collect();
+            Line::from(spans)
+        })
+        .collect();
+    let map_area = Rect::new(
+        inner.x + (inner.width - side as u16) / 2,
+        inner.y,
+        side as u16,
+        rows as u16,
+    );
+    frame.render_widget(Paragraph::new(lines), map_area);
+}
+
+fn draw_wordcloud(frame: &mut Frame, app: &App, area: Rect, theme: Theme, i18n: &I18n) {
+    let Theme { base, text, accent, border, .. } = theme;
+    let title = i18n.title_wordcloud().to_string();
+    if app.account_id.is_none() {
+        let block = chart_block(app, title, theme, i18n);
+        draw_placeholder(frame, area, block, i18n.no_player_loaded(), theme);
+        return;
+    }
+    let Some(wordcloud) = app.current_wordcloud() else {
+        let block = chart_block(app, title, theme, i18n);
+        draw_placeholder(frame, area, block, i18n.loading_player(), theme);
+        return;
+    };
+    let mut words: Vec<(&String, i64)> = wordcloud
+        .words
+        .my_word_counts
+        .iter()
+        .filter(|(_, count)| **count > 0)
+        .map(|(word, count)| (word, *count))
+        .collect();
+    words.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    let block = chart_block(app, format!("{title} · {}", i18n.wordcloud_total(words.len())), theme, i18n);
+    let Some(max) = words.first().map(|(_, count)| *count) else {
+        draw_placeholder(frame, area, block, i18n.histogram_empty(), theme);
+        return;
+    };
+
+    let inner = block.inner(area);
+    frame.render_widget(block.style(Style::default().bg(base).fg(text)), area);
+    let column_width = 30u16;
+    let columns = (inner.width / column_width).max(1);
+    let rows = inner.height as usize;
+    if rows == 0 {
+        return;
+    }
+    for (column, chunk) in words.chunks(rows).take(columns as usize).enumerate() {
+        let lines: Vec<Line> = chunk
+            .iter()
+            .map(|(word, count)| {
+                let share = *count as f64 / max as f64;
+                let style = if share >= 0.5 {
+                    Style::default().fg(accent).add_modifier(Modifier::BOLD)
+                } else if share >= 0.15 {
+                    Style::default().fg(text)
+                } else {
+                    Style::default().fg(border)
+                };
+                let bar = "█".repeat((share * 8.0).ceil() as usize);
+                Line::from(vec![
+                    Span::styled(format!("{:<14} ", truncate_text(word, 14)), style),
+                    Span::styled(format!("{bar:<8} "), Style::default().fg(accent)),
+                    Span::styled(count.to_string(), style),
+                ])
+            })
+            .collect();
+        let column_area = Rect::new(
+            inner.x + column as u16 * column_width,
+            inner.y,
+            column_width.min(inner.width),
+            inner.height,
+        );
+        frame.render_widget(Paragraph::new(lines), column_area);
+    }
+}
@@ -0,0 +1,312 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap};
+
+use crate::app::{App, LoadedPlayer};
+use crate::config::Theme;
+use crate::i18n::I18n;
+
+use super::helpers::{summarize_matches, truncate_text, PlayerSummary};
+use super::ImageTarget;
+
+struct CompareRow<'a> {
+    label: &'a str,
+    values: Vec<CompareValue>,
+}
+
+struct CompareValue {
+    left: String,
+    right: String,
+    left_score: Option<f64>,
+    right_score: Option<f64>,
+    lower_is_better: bool,
+}
+
+impl CompareValue {
+    fn text(left: String, right: String) -> Self {
+        Self::higher(left, right, None, None)
+    }
+
+    fn higher(left: String, right: String, left_score: Option<f64>, right_score: Option<f64>) -> Self {
+        Self {
+            left,
+            right,
+            left_score,
+            right_score,
+            lower_is_better: false,
+        }
+    }
+
+    fn lower(left: String, right: String, left_score: Option<f64>, right_score: Option<f64>) -> Self {
+        Self {
+            lower_is_better: true,
+            ..Self::higher(left, right, left_score, right_score)
+        }
+    }
+}
+
+pub fn draw_compare_tab(
+    frame: &mut Frame,
+    app: &App,
+    area: Rect,
+    theme: Theme,
+    images: &mut Vec<ImageTarget>,
+    i18n: &I18n,
+) {
+    let Theme { base, text, accent, success, border, .. } = theme;
+    if app.compare_players.len() < 2 {
+        let empty = Paragraph::new(i18n.compare_empty())
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(i18n.tab_compare())
+                    .border_style(Style::default().fg(border)),
+            )
+            .style(Style::default().bg(base).fg(text))
+            .wrap(Wrap { trim: true });
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(8), Constraint::Min(0)])
+        .split(area);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(layout[0]);
+
+    let left = &app.compare_players[0];
+    let right = &app.compare_players[1];
+    draw_player_header(frame, left, columns[0], theme, images, i18n);
+    draw_player_header(frame, right, columns[1], theme, images, i18n);
+
+    let left_summary = summarize_matches(&left.matches);
+    let right_summary = summarize_matches(&right.matches);
+    let labels = i18n.compare_labels();
+    let loading = i18n.loading_player();
+
+    let rank = |player: &LoadedPlayer| player.profile.as_ref().and_then(|p| p.rank_tier);
+    let leaderboard = |player: &LoadedPlayer| {
+        player.profile.as_ref().and_then(|p| p.leaderboard_rank)
+    };
+    let format_avg = |value: Option<f64>| {
+        value
+            .map(|v| format!("{v:.0}"))
+            .unwrap_or_else(|| i18n.placeholder_dash().to_string())
+    };
+    let format_heroes = |summary: &PlayerSummary| {
+        let heroes: Vec<String> = summary
+            .top_heroes
+            .iter()
+            .map(|(hero_id, count)| {
+                format!("{} ({count})", truncate_text(&app.hero_name(*hero_id, i18n), 14))
+            })
+            .collect();
+        if heroes.is_empty() {
+            i18n.placeholder_dash().to_string()
+        } else {
+            heroes.join(", ")
+        }
+    };
+
+    let has_matches = |summary: &PlayerSummary| summary.total > 0;
+    let score = |summary: &PlayerSummary, value: f64| Some(value).filter(|_| has_matches(summary));
+    let one = |value: f64| format!("{value:.1}");
+    let metrics: Vec<CompareRow> = vec![
+        CompareRow {
+            label: labels[0],
+            values: vec![CompareValue::higher(
+                i18n.format_rank_tier(rank(left), leaderboard(left)),
+                i18n.format_rank_tier(rank(right), leaderboard(right)),
+                rank(left).map(f64::from),
+                rank(right).map(f64::from),
+            )],
+        },
+        CompareRow {
+            label: labels[1],
+            values: vec![CompareValue::text(left_summary.total.to_string(), right_summary.total.to_string())],
+        },
+        CompareRow {
+            label: labels[2],
+            values: vec![CompareValue::higher(
+                format!("{:.1}%", left_summary.winrate() * 100.0),
+                format!("{:.1}%", right_summary.winrate() * 100.0),
+                score(&left_summary, left_summary.winrate()),
+                score(&right_summary, right_summary.winrate()),
+            )],
+        },
+        CompareRow {
+            label: labels[3],
+            values: vec![
+                CompareValue::higher(
+                    one(left_summary.avg_kills),
+                    one(right_summary.avg_kills),
+                    score(&left_summary, left_summary.avg_kills),
+                    score(&right_summary, right_summary.avg_kills),
+                ),
+                CompareValue::lower(
+                    one(left_summary.avg_deaths),
+                    one(right_summary.avg_deaths),
+                    score(&left_summary, left_summary.avg_deaths),
+                    score(&right_summary, right_summary.avg_deaths),
+                ),
+                CompareValue::higher(
+                    one(left_summary.avg_assists),
+                    one(right_summary.avg_assists),
+                    score(&left_summary, left_summary.avg_assists),
+                    score(&right_summary, right_summary.avg_assists),
+                ),
+            ],
+        },
+        CompareRow {
+            label: labels[4],
+            values: vec![CompareValue::higher(
+                format!("{:.2}", left_summary.kda()),
+                format!("{:.2}", right_summary.kda()),
+                score(&left_summary, left_summary.kda()),
+                score(&right_summary, right_summary.kda()),
+            )],
+        },
+        CompareRow {
+            label: labels[5],
+            values: vec![CompareValue::higher(
+                format_avg(left_summary.avg_gpm),
+                format_avg(right_summary.avg_gpm),
+                left_summary.avg_gpm,
+                right_summary.avg_gpm,
+            )],
+        },
+        CompareRow {
+            label: labels[6],
+            values: vec![CompareValue::higher(
+                format_avg(left_summary.avg_xpm),
+                format_avg(right_summary.avg_xpm),
+                left_summary.avg_xpm,
+                right_summary.avg_xpm,
+            )],
+        },
+        CompareRow {
+            label: labels[7],
+            values: vec![CompareValue::text(format_heroes(&left_summary), format_heroes(&right_summary))],
+        },
+    ];
+
+    let better = Style::default().fg(success).add_modifier(Modifier::BOLD);
+    let rows: Vec<Row> = metrics
+        .into_iter()
+        .map(|row| {
+            let mut left_spans = Vec::new();
+            let mut right_spans = Vec::new();
+            for (idx, value) in row.values.into_iter().enumerate() {
+                if idx > 0 {
+                    left_spans.push(Span::raw("/"));
+                    right_spans.push(Span::raw("/"));
+                }
+                let (left_style, right_style) = match (value.left_score, value.right_score) {
+                    (Some(l), Some(r)) if l != r && (l > r) != value.lower_is_better => (better, Style::default()),
+                    (Some(l), Some(r)) if l != r => (Style::default(), better),
+                    _ => (Style::default(), Style::default()),
+                };
+                left_spans.push(Span::styled(value.left, left_style));
+                right_spans.push(Span::styled(value.right, right_style));
+            }
+            let cell = |player: &LoadedPlayer, spans: Vec<Span<'static>>| {
+                if player.loading {
+                    Cell::from(loading.to_string())
+                } else {
+                    Cell::from(Line::from(spans))
+                }
+            };
+            Row::new(vec![
+                Cell::from(row.label.to_string()).style(Style::default().fg(accent)),
+                cell(left, left_spans),
+                cell(right, right_spans),
+            ])
+        })
+        .collect();
+
+    let header = Row::new(vec![
+        i18n.compare_metric().to_string(),
+        player_title(left, i18n),
+        player_title(right, i18n),
+    ])
+    .style(Style::default().fg(accent).add_modifier(Modifier::BOLD));
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(14),
+            Constraint::Percentage(43),
+            Constraint::Percentage(43),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(i18n.tab_compare())
+            .border_style(Style::default().fg(border)),
+    )
+    .column_spacing(1)
+    .style(Style::default().bg(base).fg(text));
+    frame.render_widget(table, layout[1]);
+}
+
+fn draw_player_header(
+    frame: &mut Frame,
+    player: &LoadedPlayer,
+    area: Rect,
+    theme: Theme,
+    images: &mut Vec<ImageTarget>,
+    i18n: &I18n,
+) {
+    let Theme { base, text, border, .. } = theme;
+    let body = if player.loading {
+        i18n.loading_player().to_string()
+    } else if let Some(err) = &player.error {
+        i18n.status_search_failed(err)
+    } else {
+        let rank = player.profile.as_ref().and_then(|p| p.rank_tier);
+        let leaderboard = player.profile.as_ref().and_then(|p| p.leaderboard_rank);
+        format!(
+            "account_id: {}\n{}",
+            player.account_id,
+            i18n.format_rank_tier(rank, leaderboard)
+        )
+    };
+    let lines: Vec<Line> = body
+        .lines()
+        .map(|line| Line::from(format!("          {line}")))
+        .collect();
+    let header = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(player_title(player, i18n))
+                .border_style(Style::default().fg(border)),
+        )
+        .style(Style::default().bg(base).fg(text))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(header, area);
+
+    if let Some(url) = player.avatar_url() {
+        images.push(ImageTarget {
+            area: Rect::new(area.x + 1, area.y + 1, 8, 6),
+            url: url.to_string(),
+        });
+    }
+}
+
+fn player_title(player: &LoadedPlayer, i18n: &I18n) -> String {
+    player
+        .personaname()
+        .map(|name| truncate_text(name, 24))
+        .unwrap_or_else(|| {
+            if player.loading {
+                player.account_id.to_string()
+            } else {
+                i18n.unknown().to_string()
+            }
+        })
+}
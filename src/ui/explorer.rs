@@ -0,0 +1,183 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap};
+use unicode_width::UnicodeWidthStr;
+
+use crate::app::{App, ExplorerTable, HitAreas, InputMode, ListHit};
+use crate::config::Theme;
+use crate::i18n::I18n;
+
+use super::helpers::truncate_text;
+
+const MAX_COLUMN_WIDTH: usize = 40;
+
+pub fn draw_explorer_tab(frame: &mut Frame, app: &App, area: Rect, theme: Theme, hits: &mut HitAreas, i18n: &I18n) {
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(10), Constraint::Min(5)])
+        .split(area);
+    let top = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
+        .split(layout[0]);
+    draw_editor(frame, app, top[0], theme, i18n);
+    draw_saved_queries(frame, app, top[1], theme, i18n);
+    draw_results(frame, app, layout[1], theme, hits, i18n);
+}
+
+fn explorer_block<'a>(title: String, theme: Theme) -> Block<'a> {
+    Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(Style::default().fg(theme.border))
+}
+
+fn draw_placeholder(frame: &mut Frame, area: Rect, block: Block, message: &str, theme: Theme) {
+    let placeholder = Paragraph::new(message.to_string())
+        .block(block)
+        .style(Style::default().bg(theme.base).fg(theme.text))
+        .wrap(Wrap { trim: true });
+    frame.render_widget(placeholder, area);
+}
+
+fn draw_editor(frame: &mut Frame, app: &App, area: Rect, theme: Theme, i18n: &I18n) {
+    let editing = app.input_mode == InputMode::Sql;
+    let mut block = explorer_block(i18n.title_explorer_editor(editing).to_string(), theme);
+    if editing {
+        block = block.border_style(Style::default().fg(theme.accent));
+    }
+    let inner_height = area.height.saturating_sub(2).max(1) as usize;
+    let inner_width = area.width.saturating_sub(2).max(1) as usize;
+    let (row, column) = app.explorer.cursor_position();
+    let scroll_y = row.saturating_sub(inner_height - 1);
+    let scroll_x = column.saturating_sub(inner_width - 1);
+    let editor = Paragraph::new(app.explorer.text.as_str())
+        .block(block)
+        .style(Style::default().bg(theme.base).fg(theme.text))
+        .scroll((scroll_y as u16, scroll_x as u16));
+    frame.render_widget(editor, area);
+    if editing {
+        let x = area.x + 1 + (column - scroll_x) as u16;
+        let y = area.y + 1 + (row - scroll_y) as u16;
+        frame.set_cursor(x, y);
+    }
+}
+
+fn draw_saved_queries(frame: &mut Frame, app: &App, area: Rect, theme: Theme, i18n: &I18n) {
+    let block = explorer_block(i18n.title_saved_queries().to_string(), theme);
+    if app.saved_queries.is_empty() {
+        draw_placeholder(frame, area, block, i18n.no_saved_queries(), theme);
+        return;
+    }
+    let rows: Vec<Row> = app
+        .saved_queries
+        .iter()
+        .map(|query| Row::new(vec![Cell::from(query.name.clone())]))
+        .collect();
+    let table = Table::new(rows, [Constraint::Min(4)])
+        .block(block)
+        .style(Style::default().bg(theme.base).fg(theme.text))
+        .highlight_style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD))
+        .highlight_symbol("▶ ");
+    let mut state = TableState::default();
+    state.select(app.query_index);
+    frame.render_stateful_widget(table, area, &mut state);
+}
+
+fn column_widths(table: &ExplorerTable) -> Vec<usize> {
+    table
+        .columns
+        .iter()
+        .enumerate()
+        .map(|(idx, column)| {
+            table
+                .rows
+                .iter()
+                .filter_map(|row| row.get(idx))
+                .map(|value| value.width())
+                .fold(column.width(), usize::max)
+                .clamp(1, MAX_COLUMN_WIDTH)
+        })
+        .collect()
+}
+
+fn draw_results(frame: &mut Frame, app: &App, area: Rect, theme: Theme, hits: &mut HitAreas, i18n: &I18n) {
+    let Theme { base, text, accent, highlight, .. } = theme;
+    if app.explorer_loading {
+        let block = explorer_block(i18n.title_explorer_results(0, 0), theme);
+        draw_placeholder(frame, area, block, i18n.loading_player(), theme);
+        return;
+    }
+    let Some(result) = app.explorer_result.as_ref() else {
+        let block = explorer_block(i18n.title_explorer_results(0, 0), theme);
+        draw_placeholder(frame, area, block, i18n.explorer_empty(), theme);
+        return;
+    };
+    let mut title = i18n.title_explorer_results(result.rows.len(), result.columns.len());
+    if result.rows.is_empty() {
+        draw_placeholder(frame, area, explorer_block(title, theme), i18n.explorer_no_rows(), theme);
+        return;
+    }
+
+    let widths = column_widths(result);
+    let first = app.explorer_column.min(result.columns.len().saturating_sub(1));
+    let available = area.width.saturating_sub(4) as usize;
+    let mut used = 0;
+    let mut last = first;
+    for width in &widths[first..] {
+        if last > first && used + width > available {
+            break;
+        }
+        used += width + 1;
+        last += 1;
+    }
+    if first > 0 || last < result.columns.len() {
+        title = format!("{title} · {}-{}/{}", first + 1, last, result.columns.len());
+    }
+
+    let header = Row::new(result.columns[first..last].iter().map(String::as_str))
+        .style(Style::default().fg(accent).add_modifier(Modifier::BOLD));
+    let total = result.rows.len();
+    let selected = app.explorer_index.min(total - 1);
+    let max_rows = area.height.saturating_sub(3).max(1) as usize;
+    let mut start = selected.saturating_sub(max_rows / 2);
+    if start + max_rows > total {
+        start = total.saturating_sub(max_rows);
+    }
+    let end = (start + max_rows).min(total);
+    let rows: Vec<Row> = result.rows[start..end]
+        .iter()
+        .map(|row| {
+            Row::new(
+                (first..last)
+                    .map(|idx| {
+                        let value = row.get(idx).map(|value| value.replace('\n', " ")).unwrap_or_default();
+                        Cell::from(truncate_text(&value, widths[idx]))
+                    })
+                    .collect::<Vec<Cell>>(),
+            )
+        })
+        .collect();
+    let constraints: Vec<Constraint> = widths[first..last]
+        .iter()
+        .map(|width| Constraint::Length(*width as u16))
+        .collect();
+
+    let table = Table::new(rows, constraints)
+        .header(header)
+        .block(explorer_block(title, theme))
+        .column_spacing(1)
+        .style(Style::default().bg(base).fg(text))
+        .highlight_style(Style::default().bg(highlight))
+        .highlight_symbol("▌ ");
+
+    let mut state = TableState::default();
+    state.select(Some(selected - start));
+    frame.render_stateful_widget(table, area, &mut state);
+    hits.explorer = Some(ListHit {
+        area,
+        first_row_y: area.y + 2,
+        row_height: 1,
+        offset: start,
+        len: end,
+    });
+}
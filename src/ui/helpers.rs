@@ -1,8 +1,44 @@
+use std::collections::HashMap;
+
 use ratatui::prelude::*;
 
-use crate::app::App;
+use crate::app::{App, PlayerAggregates};
+use crate::config::Theme;
 use crate::i18n::I18n;
-use crate::models::PlayerMatch;
+use crate::models::{Benchmark, ChatMessage, CountEntry, MatchDetail, MatchPlayer, PlayerMatch};
+
+pub fn find_player_detail(app: &App) -> Option<&MatchPlayer> {
+    let detail = app.match_detail.as_ref()?;
+    let account_id = app.account_id?;
+    detail
+        .players
+        .iter()
+        .find(|p| p.account_id == Some(account_id))
+}
+
+pub fn build_loadout_text(app: &App, i18n: &I18n) -> String {
+    let Some(player) = find_player_detail(app) else {
+        return String::new();
+    };
+    let hero = player.hero_id.map(|hero_id| app.hero_name(hero_id, i18n));
+    let items = [
+        player.item_0,
+        player.item_1,
+        player.item_2,
+        player.item_3,
+        player.item_4,
+        player.item_5,
+    ];
+    let items: Vec<String> = items
+        .iter()
+        .flatten()
+        .filter_map(|item| app.item_name(*item, i18n))
+        .collect();
+    hero.into_iter()
+        .chain((!items.is_empty()).then(|| items.join(", ")))
+        .collect::<Vec<_>>()
+        .join(" · ")
+}
 
 pub fn build_profile_text(app: &App, i18n: &I18n) -> String {
     if app.loading {
@@ -46,18 +82,9 @@ pub fn build_quick_stats(app: &App, i18n: &I18n) -> String {
     i18n.quick_stats_format(total, wins)
 }
 
-pub fn compute_winrate(app: &App) -> f64 {
-    let total = app.matches.len();
-    if total == 0 {
-        return 0.0;
-    }
-    let wins = app.matches.iter().filter(|m| is_win(m)).count();
-    wins as f64 / total as f64
-}
-
-pub fn build_sparkline(app: &App) -> Vec<u64> {
+pub fn build_sparkline(matches: &[PlayerMatch]) -> Vec<u64> {
     let mut data = Vec::new();
-    for m in app.matches.iter().take(20).rev() {
+    for m in matches.iter().take(20).rev() {
         data.push(if is_win(m) { 10 } else { 2 });
     }
     if data.is_empty() {
@@ -66,11 +93,166 @@ pub fn build_sparkline(app: &App) -> Vec<u64> {
     data
 }
 
-pub fn build_stats_text(app: &App, i18n: &I18n) -> String {
-    let total = app.matches.len();
-    let wins = app.matches.iter().filter(|m| is_win(m)).count();
-    let winrate = compute_winrate(app) * 100.0;
-    i18n.stats_summary_format(total, wins, winrate)
+pub fn build_stats_text(summary: &PlayerSummary, i18n: &I18n) -> String {
+    let kda_split = format!(
+        "{:.1}/{:.1}/{:.1}",
+        summary.avg_kills, summary.avg_deaths, summary.avg_assists
+    );
+    i18n.stats_summary_format(
+        summary.total,
+        summary.wins,
+        summary.winrate() * 100.0,
+        summary.kda(),
+        &kda_split,
+    )
+}
+
+pub const LIFETIME_FIELDS: [&str; 10] = [
+    "kills",
+    "deaths",
+    "assists",
+    "last_hits",
+    "denies",
+    "gold_per_min",
+    "xp_per_min",
+    "hero_damage",
+    "tower_damage",
+    "hero_healing",
+];
+
+pub struct BreakdownRow {
+    pub group: &'static str,
+    pub label: String,
+    pub games: i64,
+    pub wins: i64,
+}
+
+pub fn build_breakdown_rows(app: &App, aggregates: &PlayerAggregates, i18n: &I18n) -> Vec<BreakdownRow> {
+    let counts = &aggregates.counts;
+    let groups: [(&'static str, &HashMap<i32, CountEntry>, usize); 4] = [
+        ("lobby_type", &counts.lobby_type, 3),
+        ("game_mode", &counts.game_mode, 3),
+        ("side", &counts.is_radiant, 2),
+        ("region", &counts.region, 3),
+    ];
+    let mut rows = Vec::new();
+    for (group, entries, limit) in groups {
+        let mut entries: Vec<(&i32, &CountEntry)> =
+            entries.iter().filter(|(_, entry)| entry.games > 0).collect();
+        entries.sort_by(|a, b| b.1.games.cmp(&a.1.games).then(a.0.cmp(b.0)));
+        for (id, entry) in entries.into_iter().take(limit) {
+            let label = match group {
+                "lobby_type" => app.lobby_type_name(Some(*id), i18n),
+                "game_mode" => app.game_mode_name(Some(*id), i18n),
+                "side" if *id == 1 => i18n.title_radiant().to_string(),
+                "side" => i18n.title_dire().to_string(),
+                _ => app.region_name(Some(*id), i18n),
+            };
+            rows.push(BreakdownRow {
+                group,
+                label,
+                games: entry.games,
+                wins: entry.win,
+            });
+        }
+    }
+    rows
+}
+
+pub fn format_average(value: f64) -> String {
+    if value >= 1000.0 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.1}")
+    }
+}
+
+pub struct HeroBreakdown {
+    pub hero_id: i32,
+    pub games: usize,
+    pub wins: usize,
+    pub kda: f64,
+}
+
+pub fn hero_breakdown(matches: &[PlayerMatch]) -> Vec<HeroBreakdown> {
+    let mut by_hero: HashMap<i32, Vec<PlayerMatch>> = HashMap::new();
+    for m in matches {
+        by_hero.entry(m.hero_id).or_default().push(m.clone());
+    }
+    let mut rows: Vec<HeroBreakdown> = by_hero
+        .into_iter()
+        .map(|(hero_id, games)| {
+            let summary = summarize_matches(&games);
+            HeroBreakdown {
+                hero_id,
+                games: summary.total,
+                wins: summary.wins,
+                kda: summary.kda(),
+            }
+        })
+        .collect();
+    rows.sort_by(|a, b| b.games.cmp(&a.games).then(b.wins.cmp(&a.wins)).then(a.hero_id.cmp(&b.hero_id)));
+    rows
+}
+
+pub struct PlayerSummary {
+    pub total: usize,
+    pub wins: usize,
+    pub avg_kills: f64,
+    pub avg_deaths: f64,
+    pub avg_assists: f64,
+    pub avg_gpm: Option<f64>,
+    pub avg_xpm: Option<f64>,
+    pub top_heroes: Vec<(i32, usize)>,
+}
+
+impl PlayerSummary {
+    pub fn winrate(&self) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        self.wins as f64 / self.total as f64
+    }
+
+    pub fn kda(&self) -> f64 {
+        (self.avg_kills + self.avg_assists) / self.avg_deaths.max(1.0)
+    }
+}
+
+pub fn summarize_matches(matches: &[PlayerMatch]) -> PlayerSummary {
+    let total = matches.len();
+    let wins = matches.iter().filter(|m| is_win(m)).count();
+    let average = |values: Vec<i32>| -> Option<f64> {
+        if values.is_empty() {
+            None
+        } else {
+            Some(values.iter().map(|v| f64::from(*v)).sum::<f64>() / values.len() as f64)
+        }
+    };
+    let avg_kills = average(matches.iter().filter_map(|m| m.kills).collect()).unwrap_or(0.0);
+    let avg_deaths = average(matches.iter().filter_map(|m| m.deaths).collect()).unwrap_or(0.0);
+    let avg_assists = average(matches.iter().filter_map(|m| m.assists).collect()).unwrap_or(0.0);
+    let avg_gpm = average(matches.iter().filter_map(|m| m.gold_per_min).collect());
+    let avg_xpm = average(matches.iter().filter_map(|m| m.xp_per_min).collect());
+
+    let mut counts: HashMap<i32, usize> = HashMap::new();
+    for m in matches {
+        *counts.entry(m.hero_id).or_default() += 1;
+    }
+    let mut top_heroes: Vec<(i32, usize)> = counts.into_iter().collect();
+    top_heroes.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    top_heroes.truncate(3);
+
+    PlayerSummary {
+        total,
+        wins,
+        avg_kills,
+        avg_deaths,
+        avg_assists,
+        avg_gpm,
+        avg_xpm,
+        top_heroes,
+    }
 }
 
 pub fn is_win(match_item: &PlayerMatch) -> bool {
@@ -84,6 +266,154 @@ pub fn format_duration(seconds: u32) -> String {
     format!("{:02}:{:02}", mins, secs)
 }
 
+pub fn format_match_clock(seconds: i64) -> String {
+    let sign = if seconds < 0 { "-" } else { "" };
+    let seconds = seconds.abs();
+    format!("{sign}{:02}:{:02}", seconds / 60, seconds % 60)
+}
+
+pub fn chat_messages(detail: &MatchDetail) -> Vec<&ChatMessage> {
+    detail
+        .chat
+        .iter()
+        .flatten()
+        .filter(|message| message.kind == "chat" && message.key.is_string())
+        .collect()
+}
+
+pub const BENCHMARK_FIELDS: [&str; 9] = [
+    "gold_per_min",
+    "xp_per_min",
+    "kills_per_min",
+    "last_hits_per_min",
+    "hero_damage_per_min",
+    "hero_healing_per_min",
+    "tower_damage",
+    "stuns_per_min",
+    "lhten",
+];
+
+pub fn player_benchmarks(player: &MatchPlayer) -> Vec<(&'static str, &Benchmark)> {
+    let Some(benchmarks) = player.benchmarks.as_ref() else {
+        return Vec::new();
+    };
+    BENCHMARK_FIELDS
+        .iter()
+        .filter_map(|field| {
+            benchmarks
+                .get(*field)
+                .filter(|benchmark| benchmark.pct.is_some())
+                .map(|benchmark| (*field, benchmark))
+        })
+        .collect()
+}
+
+pub fn percentile_color(pct: f64, theme: Theme) -> Color {
+    let pct = pct.clamp(0.0, 1.0);
+    match (theme.loss, theme.win) {
+        (Color::Rgb(r1, g1, b1), Color::Rgb(r2, g2, b2)) => {
+            let mix = |from: u8, to: u8| (f64::from(from) + (f64::from(to) - f64::from(from)) * pct).round() as u8;
+            Color::Rgb(mix(r1, r2), mix(g1, g2), mix(b1, b2))
+        }
+        (loss, win) => {
+            if pct < 0.5 {
+                loss
+            } else {
+                win
+            }
+        }
+    }
+}
+
+pub const LANES: [&str; 5] = ["top", "mid", "bot", "jungle", "roaming"];
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum LaneOutcome {
+    Radiant,
+    Dire,
+    Even,
+}
+
+pub struct LaneMatchup<'a> {
+    pub lane: &'static str,
+    pub radiant: Vec<&'a MatchPlayer>,
+    pub dire: Vec<&'a MatchPlayer>,
+    pub outcome: Option<LaneOutcome>,
+}
+
+pub fn has_laning(detail: &MatchDetail) -> bool {
+    detail.players.iter().any(|p| p.lh_t.is_some() && p.lane.is_some())
+}
+
+pub fn player_lane(player: &MatchPlayer) -> Option<&'static str> {
+    if player.is_roaming == Some(true) {
+        return Some("roaming");
+    }
+    match player.lane? {
+        1 => Some("bot"),
+        2 => Some("mid"),
+        3 => Some("top"),
+        4 | 5 => Some("jungle"),
+        _ => None,
+    }
+}
+
+pub fn lane_role_name(role: i32) -> Option<&'static str> {
+    match role {
+        1 => Some("safe"),
+        2 => Some("mid"),
+        3 => Some("off"),
+        4 => Some("jungle"),
+        _ => None,
+    }
+}
+
+pub fn value_at(series: Option<&Vec<i32>>, minute: usize) -> Option<i32> {
+    let series = series?;
+    series.get(minute).or(series.last()).copied()
+}
+
+fn lane_total(players: &[&MatchPlayer], series: fn(&MatchPlayer) -> Option<&Vec<i32>>) -> Option<i32> {
+    players.iter().map(|p| value_at(series(p), 10)).sum()
+}
+
+pub fn lane_matchups(detail: &MatchDetail) -> Vec<LaneMatchup<'_>> {
+    LANES
+        .iter()
+        .filter_map(|lane| {
+            let (radiant, dire): (Vec<&MatchPlayer>, Vec<&MatchPlayer>) = detail
+                .players
+                .iter()
+                .filter(|p| player_lane(p) == Some(*lane))
+                .partition(|p| p.player_slot.is_some_and(|slot| slot < 128));
+            if radiant.is_empty() && dire.is_empty() {
+                return None;
+            }
+            let contested = !matches!(*lane, "jungle" | "roaming") && !radiant.is_empty() && !dire.is_empty();
+            let series: fn(&MatchPlayer) -> Option<&Vec<i32>> =
+                if radiant.iter().chain(&dire).all(|p| p.gold_t.is_some()) {
+                    |p| p.gold_t.as_ref()
+                } else {
+                    |p| p.lh_t.as_ref()
+                };
+            let outcome = match (contested, lane_total(&radiant, series), lane_total(&dire, series)) {
+                (true, Some(left), Some(right)) => {
+                    let margin = f64::from(left.max(right)) * 0.1;
+                    Some(if f64::from((left - right).abs()) <= margin {
+                        LaneOutcome::Even
+                    } else if left > right {
+                        LaneOutcome::Radiant
+                    } else {
+                        LaneOutcome::Dire
+                    })
+                }
+                _ => None,
+            };
+            Some(LaneMatchup { lane, radiant, dire, outcome })
+        })
+        .collect()
+}
+
 pub fn format_relative_time(start_time: Option<i64>, i18n: &I18n) -> String {
     let Some(start) = start_time else {
         return i18n.placeholder_dash().to_string();
@@ -102,8 +432,24 @@ pub fn format_relative_time(start_time: Option<i64>, i18n: &I18n) -> String {
     i18n.time_days(diff / 86400)
 }
 
-pub fn format_game_mode(game_mode: Option<i32>, i18n: &I18n) -> String {
-    i18n.format_game_mode(game_mode)
+pub fn match_filter_summary(app: &App, i18n: &I18n, charts: bool) -> Vec<String> {
+    let hero = app.hero_filter.trim();
+    let mut filters = Vec::new();
+    if !hero.is_empty() {
+        filters.push(hero.to_string());
+    }
+    if let Some(kind) = app.kind_filter {
+        let label = i18n.match_kind(kind);
+        filters.push(if charts && app.chart_kind_filter().is_none() {
+            i18n.chart_kind_ignored(label)
+        } else {
+            label.to_string()
+        });
+    }
+    if let Some(region) = app.region_filter {
+        filters.push(app.region_name(Some(region), i18n));
+    }
+    filters
 }
 
 pub fn truncate_text(value: &str, max: usize) -> String {
@@ -0,0 +1,389 @@
+use std::collections::HashMap;
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Bar, BarChart, BarGroup, Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap};
+
+use crate::app::{bracket_record, bracket_records, App, HeroDetail, HitAreas, ListHit};
+use crate::config::Theme;
+use crate::i18n::I18n;
+use crate::models::{HeroDuration, HeroMatchup, HeroStat};
+
+use super::helpers::truncate_text;
+use super::ImageTarget;
+
+pub fn draw_heroes_tab(
+    frame: &mut Frame,
+    app: &App,
+    area: Rect,
+    theme: Theme,
+    images: &mut Vec<ImageTarget>,
+    hits: &mut HitAreas,
+    i18n: &I18n,
+) {
+    match app.hero_page.and_then(|hero_id| app.heroes.get(&hero_id)) {
+        Some(hero) => draw_hero_page(frame, app, hero, area, theme, images, i18n),
+        None => draw_hero_list(frame, app, area, theme, hits, i18n),
+    }
+}
+
+fn hero_block<'a>(title: String, theme: Theme) -> Block<'a> {
+    Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(Style::default().fg(theme.border))
+}
+
+fn draw_placeholder(frame: &mut Frame, area: Rect, block: Block, message: &str, theme: Theme) {
+    let placeholder = Paragraph::new(message.to_string())
+        .block(block)
+        .style(Style::default().bg(theme.base).fg(theme.text))
+        .wrap(Wrap { trim: true });
+    frame.render_widget(placeholder, area);
+}
+
+fn format_winrate(picks: i64, wins: i64, i18n: &I18n) -> String {
+    if picks <= 0 {
+        return i18n.placeholder_dash().to_string();
+    }
+    format!("{:.1}%", wins as f64 / picks as f64 * 100.0)
+}
+
+fn winrate_color(picks: i64, wins: i64, theme: Theme) -> Color {
+    if picks <= 0 {
+        theme.text
+    } else if wins * 2 >= picks {
+        theme.win
+    } else {
+        theme.loss
+    }
+}
+
+fn draw_hero_list(frame: &mut Frame, app: &App, area: Rect, theme: Theme, hits: &mut HitAreas, i18n: &I18n) {
+    let Theme { base, text, accent, highlight, .. } = theme;
+    let title = i18n.title_hero_list(
+        i18n.hero_bracket_label(app.hero_bracket),
+        &i18n.hero_role_label(app.hero_role),
+        i18n.hero_sort(app.hero_sort),
+    );
+    let block = hero_block(title, theme);
+    if app.heroes.is_empty() {
+        draw_placeholder(frame, area, block, i18n.loading_player(), theme);
+        return;
+    }
+    let heroes = app.hero_rows(i18n);
+    if heroes.is_empty() {
+        draw_placeholder(frame, area, block, i18n.heroes_empty(), theme);
+        return;
+    }
+
+    let header = Row::new(vec![
+        i18n.table_hero(),
+        i18n.table_attr(),
+        i18n.table_attack(),
+        i18n.table_roles(),
+        i18n.table_picks(),
+        i18n.table_winrate(),
+        i18n.table_pro_picks(),
+        i18n.table_pro_bans(),
+        i18n.table_pro_winrate(),
+    ])
+    .style(Style::default().fg(accent).add_modifier(Modifier::BOLD));
+
+    let total = heroes.len();
+    let selected = app.hero_index.min(total - 1);
+    let max_rows = area.height.saturating_sub(3).max(1) as usize;
+    let mut start = selected.saturating_sub(max_rows / 2);
+    if start + max_rows > total {
+        start = total.saturating_sub(max_rows);
+    }
+    let end = (start + max_rows).min(total);
+    let rows: Vec<Row> = heroes[start..end]
+        .iter()
+        .map(|hero| {
+            let (picks, wins) = bracket_record(hero, app.hero_bracket);
+            let roles = hero
+                .roles
+                .iter()
+                .map(|role| i18n.hero_role(role))
+                .collect::<Vec<String>>()
+                .join(", ");
+            Row::new(vec![
+                Cell::from(truncate_text(i18n.hero_name(hero), 20)),
+                Cell::from(hero.primary_attr.as_deref().map(|attr| i18n.primary_attr(attr)).unwrap_or_default()),
+                Cell::from(hero.attack_type.as_deref().map(|kind| i18n.attack_type(kind)).unwrap_or_default()),
+                Cell::from(roles),
+                Cell::from(picks.to_string()),
+                Cell::from(format_winrate(picks, wins, i18n))
+                    .style(Style::default().fg(winrate_color(picks, wins, theme))),
+                Cell::from(hero.pro_pick.to_string()),
+                Cell::from(hero.pro_ban.to_string()),
+                Cell::from(format_winrate(hero.pro_pick, hero.pro_win, i18n))
+                    .style(Style::default().fg(winrate_color(hero.pro_pick, hero.pro_win, theme))),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(20),
+            Constraint::Length(12),
+            Constraint::Length(10),
+            Constraint::Min(20),
+            Constraint::Length(9),
+            Constraint::Length(6),
+            Constraint::Length(6),
+            Constraint::Length(6),
+            Constraint::Length(6),
+        ],
+    )
+    .header(header)
+    .block(block)
+    .column_spacing(1)
+    .style(Style::default().bg(base).fg(text))
+    .highlight_style(Style::default().bg(highlight))
+    .highlight_symbol("▌ ");
+
+    let mut state = TableState::default();
+    state.select(Some(selected - start));
+    frame.render_stateful_widget(table, area, &mut state);
+    hits.heroes = Some(ListHit {
+        area,
+        first_row_y: area.y + 2,
+        row_height: 1,
+        offset: start,
+        len: end,
+    });
+}
+
+fn draw_hero_page(
+    frame: &mut Frame,
+    app: &App,
+    hero: &HeroStat,
+    area: Rect,
+    theme: Theme,
+    images: &mut Vec<ImageTarget>,
+    i18n: &I18n,
+) {
+    let Theme { base, text, accent, .. } = theme;
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(12), Constraint::Min(6), Constraint::Length(12)])
+        .split(area);
+    let top = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(30), Constraint::Percentage(30), Constraint::Percentage(40)])
+        .split(layout[0]);
+
+    let name = i18n.hero_name(hero).to_string();
+    let dash = i18n.placeholder_dash();
+    let label = |key: &str| Span::styled(format!("{key}: "), Style::default().fg(accent));
+    let roles = hero
+        .roles
+        .iter()
+        .map(|role| i18n.hero_role(role))
+        .collect::<Vec<String>>()
+        .join(", ");
+    let info = vec![
+        Line::from(Span::styled(name.clone(), Style::default().add_modifier(Modifier::BOLD))),
+        Line::from(vec![
+            label(i18n.table_attr()),
+            Span::raw(hero.primary_attr.as_deref().map(|attr| i18n.primary_attr(attr)).unwrap_or(dash.to_string())),
+        ]),
+        Line::from(vec![
+            label(i18n.table_attack()),
+            Span::raw(hero.attack_type.as_deref().map(|kind| i18n.attack_type(kind)).unwrap_or(dash.to_string())),
+        ]),
+        Line::from(vec![label(i18n.table_roles()), Span::raw(roles)]),
+        Line::from(vec![label(i18n.table_pro_picks()), Span::raw(hero.pro_pick.to_string())]),
+        Line::from(vec![label(i18n.table_pro_bans()), Span::raw(hero.pro_ban.to_string())]),
+        Line::from(vec![
+            label(i18n.table_pro_winrate()),
+            Span::raw(format_winrate(hero.pro_pick, hero.pro_win, i18n)),
+        ]),
+    ];
+    let info = Paragraph::new(info)
+        .block(hero_block(name.clone(), theme))
+        .style(Style::default().bg(base).fg(text))
+        .wrap(Wrap { trim: true });
+    frame.render_widget(info, top[0]);
+
+    let mut bracket_rows: Vec<Row> = bracket_records(hero)
+        .iter()
+        .enumerate()
+        .map(|(idx, (picks, wins))| {
+            let bracket = idx + 1;
+            let style = if app.hero_bracket == Some(bracket) {
+                Style::default().fg(accent).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(text)
+            };
+            Row::new(vec![
+                Cell::from(i18n.hero_bracket_label(Some(bracket)).to_string()),
+                Cell::from(picks.to_string()),
+                Cell::from(format_winrate(*picks, *wins, i18n))
+                    .style(Style::default().fg(winrate_color(*picks, *wins, theme))),
+            ])
+            .style(style)
+        })
+        .collect();
+    bracket_rows.push(Row::new(vec![
+        Cell::from(i18n.bracket_pro().to_string()),
+        Cell::from(hero.pro_pick.to_string()),
+        Cell::from(format_winrate(hero.pro_pick, hero.pro_win, i18n))
+            .style(Style::default().fg(winrate_color(hero.pro_pick, hero.pro_win, theme))),
+    ]));
+    let brackets = Table::new(
+        bracket_rows,
+        [Constraint::Length(14), Constraint::Length(10), Constraint::Min(6)],
+    )
+    .header(
+        Row::new(vec![i18n.table_bracket(), i18n.table_picks(), i18n.table_winrate()])
+            .style(Style::default().fg(accent).add_modifier(Modifier::BOLD)),
+    )
+    .block(hero_block(i18n.title_brackets().to_string(), theme))
+    .column_spacing(1)
+    .style(Style::default().bg(base).fg(text));
+    frame.render_widget(brackets, top[1]);
+
+    let Some(detail) = app.current_hero_detail() else {
+        let block = hero_block(i18n.title_matchups().to_string(), theme);
+        draw_placeholder(frame, layout[1], block, i18n.loading_player(), theme);
+        return;
+    };
+    draw_durations(frame, detail, top[2], theme, i18n);
+
+    let middle = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(layout[1]);
+    let mut matchups: Vec<&HeroMatchup> = detail.matchups.iter().filter(|m| m.games_played > 0).collect();
+    let min_games = matchups.iter().map(|m| m.games_played).max().unwrap_or(0) / 10;
+    matchups.retain(|m| m.games_played >= min_games.max(1));
+    matchups.sort_by(|a, b| {
+        let rate = |m: &HeroMatchup| m.wins as f64 / m.games_played as f64;
+        rate(b).total_cmp(&rate(a)).then(b.games_played.cmp(&a.games_played))
+    });
+    let visible = middle[0].height.saturating_sub(3) as usize;
+    let best: Vec<&HeroMatchup> = matchups.iter().take(visible).copied().collect();
+    let worst: Vec<&HeroMatchup> = matchups.iter().rev().take(visible).copied().collect();
+    draw_matchup_table(frame, app, &best, middle[0], i18n.title_best_matchups(), theme, i18n);
+    draw_matchup_table(frame, app, &worst, middle[1], i18n.title_worst_matchups(), theme, i18n);
+
+    let phases = [
+        ("start", &detail.items.start_game_items),
+        ("early", &detail.items.early_game_items),
+        ("mid", &detail.items.mid_game_items),
+        ("late", &detail.items.late_game_items),
+    ];
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 4); 4])
+        .split(layout[2]);
+    for ((phase, items), column) in phases.into_iter().zip(columns.iter()) {
+        draw_item_phase(frame, app, items, *column, i18n.item_phase(phase), theme, images, i18n);
+    }
+}
+
+fn draw_matchup_table(
+    frame: &mut Frame,
+    app: &App,
+    matchups: &[&HeroMatchup],
+    area: Rect,
+    title: &str,
+    theme: Theme,
+    i18n: &I18n,
+) {
+    let Theme { base, text, accent, .. } = theme;
+    let rows: Vec<Row> = matchups
+        .iter()
+        .map(|m| {
+            Row::new(vec![
+                Cell::from(truncate_text(&app.hero_name(m.hero_id, i18n), 20)),
+                Cell::from(m.games_played.to_string()),
+                Cell::from(format_winrate(m.games_played, m.wins, i18n))
+                    .style(Style::default().fg(winrate_color(m.games_played, m.wins, theme))),
+            ])
+        })
+        .collect();
+    let table = Table::new(rows, [Constraint::Length(20), Constraint::Length(8), Constraint::Min(6)])
+        .header(
+            Row::new(vec![i18n.table_hero(), i18n.table_games(), i18n.table_winrate()])
+                .style(Style::default().fg(accent).add_modifier(Modifier::BOLD)),
+        )
+        .block(hero_block(title.to_string(), theme))
+        .column_spacing(1)
+        .style(Style::default().bg(base).fg(text));
+    frame.render_widget(table, area);
+}
+
+fn draw_durations(frame: &mut Frame, detail: &HeroDetail, area: Rect, theme: Theme, i18n: &I18n) {
+    let Theme { base, text, win, loss, .. } = theme;
+    let block = hero_block(i18n.title_durations().to_string(), theme);
+    let mut durations: Vec<&HeroDuration> = detail.durations.iter().filter(|d| d.games_played > 0).collect();
+    if durations.is_empty() {
+        draw_placeholder(frame, area, block, i18n.histogram_empty(), theme);
+        return;
+    }
+    durations.sort_by_key(|d| d.duration_bin);
+    let inner_width = area.width.saturating_sub(2);
+    let count = durations.len() as u16;
+    let bar_width = (inner_width / count).saturating_sub(1).clamp(1, 5);
+    let bars: Vec<Bar> = durations
+        .iter()
+        .map(|d| {
+            let winrate = d.wins as f64 / d.games_played as f64;
+            let color = if winrate >= 0.5 { win } else { loss };
+            Bar::default()
+                .value((winrate * 100.0).round() as u64)
+                .label(Line::from(format!("{}'", d.duration_bin / 60)))
+                .text_value(format!("{:.0}", winrate * 100.0))
+                .style(Style::default().fg(color))
+                .value_style(Style::default().fg(base).bg(color))
+        })
+        .collect();
+    let chart = BarChart::default()
+        .block(block)
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(bar_width)
+        .bar_gap(1)
+        .max(100)
+        .style(Style::default().bg(base).fg(text));
+    frame.render_widget(chart, area);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_item_phase(
+    frame: &mut Frame,
+    app: &App,
+    items: &HashMap<i32, i64>,
+    area: Rect,
+    title: &str,
+    theme: Theme,
+    images: &mut Vec<ImageTarget>,
+    i18n: &I18n,
+) {
+    let Theme { base, text, border, .. } = theme;
+    let block = hero_block(title.to_string(), theme);
+    let inner = block.inner(area);
+    frame.render_widget(block.style(Style::default().bg(base).fg(text)), area);
+    let mut items: Vec<(i32, i64)> = items.iter().map(|(id, count)| (*id, *count)).collect();
+    items.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    let name_width = inner.width.saturating_sub(6) as usize;
+    let rows = (inner.height / 2) as usize;
+    let mut lines: Vec<Line> = Vec::new();
+    for (idx, (item_id, count)) in items.into_iter().take(rows).enumerate() {
+        let name = app
+            .item_name(item_id, i18n)
+            .unwrap_or_else(|| i18n.unknown().to_string());
+        lines.push(Line::from(format!("      {}", truncate_text(&name, name_width))));
+        lines.push(Line::from(Span::styled(format!("      {count}"), Style::default().fg(border))));
+        if let Some(url) = app.item_images.get(&item_id) {
+            images.push(ImageTarget {
+                area: Rect::new(inner.x, inner.y + idx as u16 * 2, 4, 2),
+                url: url.clone(),
+            });
+        }
+    }
+    frame.render_widget(Paragraph::new(lines), inner);
+}
@@ -2,21 +2,26 @@ use ratatui::prelude::*;
 
 use crate::app::App;
 
+use super::helpers::find_player_detail;
 use super::ImageTarget;
 
-pub fn push_recent_images(app: &App, area: Rect, images: &mut Vec<ImageTarget>) {
+pub fn push_recent_images<'a>(
+    avatars: impl IntoIterator<Item = Option<&'a String>>,
+    area: Rect,
+    images: &mut Vec<ImageTarget>,
+) {
     let start_x = area.x + 1;
     let mut y = area.y + 1;
     let width = 6;
     let height = 2;
-    for entry in &app.recent_searches {
-        if let Some(url) = &entry.avatar_url {
-            if y + height <= area.y + area.height.saturating_sub(1) {
-                images.push(ImageTarget {
-                    area: Rect::new(start_x, y, width, height),
-                    url: url.clone(),
-                });
-            }
+    for avatar in avatars {
+        if let Some(url) = avatar
+            && y + height <= area.y + area.height.saturating_sub(1)
+        {
+            images.push(ImageTarget {
+                area: Rect::new(start_x, y, width, height),
+                url: url.clone(),
+            });
         }
         y = y.saturating_add(height);
     }
@@ -70,13 +75,13 @@ pub fn push_match_row_images(
     let width = 6;
     let height = 2;
     for match_item in app.matches.iter().skip(start).take(count) {
-        if let Some(url) = app.hero_images.get(&match_item.hero_id) {
-            if y + height <= area.y + area.height.saturating_sub(1) {
-                images.push(ImageTarget {
-                    area: Rect::new(start_x, y, width, height),
-                    url: url.clone(),
-                });
-            }
+        if let Some(url) = app.hero_images.get(&match_item.hero_id)
+            && y + height <= area.y + area.height.saturating_sub(1)
+        {
+            images.push(ImageTarget {
+                area: Rect::new(start_x, y, width, height),
+                url: url.clone(),
+            });
         }
         y = y.saturating_add(height);
     }
@@ -115,25 +120,23 @@ pub fn push_team_images(
         if y + 2 > max_y {
             break;
         }
-        if let Some(account_id) = player.account_id {
-            if let Some(url) = app.player_avatars.get(&account_id) {
-                if y + 2 <= max_y {
-                    images.push(ImageTarget {
-                        area: Rect::new(player_x, y, 6, 2),
-                        url: url.clone(),
-                    });
-                }
-            }
+        if let Some(account_id) = player.account_id
+            && let Some(url) = app.player_avatars.get(&account_id)
+            && y + 2 <= max_y
+        {
+            images.push(ImageTarget {
+                area: Rect::new(player_x, y, 6, 2),
+                url: url.clone(),
+            });
         }
-        if let Some(hero_id) = player.hero_id {
-            if let Some(url) = app.hero_images.get(&hero_id) {
-                if y + 2 <= max_y {
-                    images.push(ImageTarget {
-                        area: Rect::new(hero_x, y, 6, 2),
-                        url: url.clone(),
-                    });
-                }
-            }
+        if let Some(hero_id) = player.hero_id
+            && let Some(url) = app.hero_images.get(&hero_id)
+            && y + 2 <= max_y
+        {
+            images.push(ImageTarget {
+                area: Rect::new(hero_x, y, 6, 2),
+                url: url.clone(),
+            });
         }
         let items = [
             player.item_0,
@@ -145,25 +148,16 @@ pub fn push_team_images(
         ];
         let mut x = items_x;
         for item in items.iter().flatten() {
-            if let Some(url) = app.item_images.get(item) {
-                if y + 2 <= max_y {
-                    images.push(ImageTarget {
-                        area: Rect::new(x, y, 4, 2),
-                        url: url.clone(),
-                    });
-                }
+            if let Some(url) = app.item_images.get(item)
+                && y + 2 <= max_y
+            {
+                images.push(ImageTarget {
+                    area: Rect::new(x, y, 4, 2),
+                    url: url.clone(),
+                });
             }
             x = x.saturating_add(5);
         }
         y = y.saturating_add(2);
     }
 }
-
-fn find_player_detail(app: &App) -> Option<&crate::models::MatchPlayer> {
-    let detail = app.match_detail.as_ref()?;
-    let account_id = app.account_id?;
-    detail
-        .players
-        .iter()
-        .find(|p| p.account_id == Some(account_id))
-}
@@ -0,0 +1,215 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap};
+
+use crate::app::{App, HitAreas, ListHit};
+use crate::config::Theme;
+use crate::i18n::I18n;
+use crate::models::{LiveGame, LivePlayer};
+
+use super::helpers::{format_match_clock, truncate_text};
+
+pub fn draw_live_tab(frame: &mut Frame, app: &App, area: Rect, theme: Theme, hits: &mut HitAreas, i18n: &I18n) {
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(6), Constraint::Length(8)])
+        .split(area);
+    let mut title = i18n.title_live(app.live.as_ref().map_or(0, Vec::len));
+    if let Some(updated) = app.live_updated {
+        title = format!("{title} · {}", i18n.live_updated(updated.elapsed().as_secs()));
+    }
+    let block = live_block(title, theme);
+    let Some(games) = app.live.as_ref() else {
+        draw_placeholder(frame, area, block, i18n.loading_player(), theme);
+        return;
+    };
+    if games.is_empty() {
+        draw_placeholder(frame, area, block, i18n.live_empty(), theme);
+        return;
+    }
+    let selected = app.live_index.min(games.len() - 1);
+    draw_live_games(frame, app, games, selected, layout[0], block, theme, hits, i18n);
+
+    let game = &games[selected];
+    let teams = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(layout[1]);
+    let sides = [
+        (0, i18n.title_radiant(), game.team_name_radiant.as_deref(), theme.success),
+        (1, i18n.title_dire(), game.team_name_dire.as_deref(), theme.loss),
+    ];
+    for ((team, side, name, color), area) in sides.into_iter().zip(teams.iter()) {
+        let title = match name.filter(|name| !name.is_empty()) {
+            Some(name) => format!("{side} · {name}"),
+            None => side.to_string(),
+        };
+        let players: Vec<&LivePlayer> = game.players.iter().filter(|player| player.team == Some(team)).collect();
+        draw_live_team(frame, app, &players, *area, title, color, theme, i18n);
+    }
+}
+
+fn live_block<'a>(title: String, theme: Theme) -> Block<'a> {
+    Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(Style::default().fg(theme.border))
+}
+
+fn draw_placeholder(frame: &mut Frame, area: Rect, block: Block, message: &str, theme: Theme) {
+    let placeholder = Paragraph::new(message.to_string())
+        .block(block)
+        .style(Style::default().bg(theme.base).fg(theme.text))
+        .wrap(Wrap { trim: true });
+    frame.render_widget(placeholder, area);
+}
+
+fn has_tracked(app: &App, game: &LiveGame) -> bool {
+    game.players
+        .iter()
+        .any(|player| player.account_id.is_some_and(|id| app.is_tracked(id)))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_live_games(
+    frame: &mut Frame,
+    app: &App,
+    games: &[LiveGame],
+    selected: usize,
+    area: Rect,
+    block: Block,
+    theme: Theme,
+    hits: &mut HitAreas,
+    i18n: &I18n,
+) {
+    let Theme { base, text, accent, highlight, success, loss, .. } = theme;
+    let dash = i18n.placeholder_dash();
+    let header = Row::new(vec![
+        "",
+        i18n.table_avg_mmr(),
+        i18n.title_radiant(),
+        i18n.table_score(),
+        i18n.title_dire(),
+        i18n.table_time(),
+        i18n.table_lead(),
+        i18n.table_spectators(),
+    ])
+    .style(Style::default().fg(accent).add_modifier(Modifier::BOLD));
+
+    let total = games.len();
+    let max_rows = area.height.saturating_sub(3).max(1) as usize;
+    let mut start = selected.saturating_sub(max_rows / 2);
+    if start + max_rows > total {
+        start = total.saturating_sub(max_rows);
+    }
+    let end = (start + max_rows).min(total);
+    let rows: Vec<Row> = games[start..end]
+        .iter()
+        .map(|game| {
+            let team = |name: Option<&str>| match name.filter(|name| !name.is_empty()) {
+                Some(name) => truncate_text(name, 20),
+                None => dash.to_string(),
+            };
+            let score = match (game.radiant_score, game.dire_score) {
+                (Some(radiant), Some(dire)) => format!("{radiant} - {dire}"),
+                _ => dash.to_string(),
+            };
+            let lead = match game.radiant_lead {
+                Some(lead) if lead > 0 => Cell::from(format!("+{lead}")).style(Style::default().fg(success)),
+                Some(lead) if lead < 0 => Cell::from(format!("+{}", -lead)).style(Style::default().fg(loss)),
+                Some(_) => Cell::from("0"),
+                None => Cell::from(dash.to_string()),
+            };
+            let tracked = has_tracked(app, game);
+            let row = Row::new(vec![
+                Cell::from(if tracked { "●" } else { "" }),
+                Cell::from(game.average_mmr.map(|mmr| mmr.to_string()).unwrap_or_else(|| dash.to_string())),
+                Cell::from(team(game.team_name_radiant.as_deref())),
+                Cell::from(score),
+                Cell::from(team(game.team_name_dire.as_deref())),
+                Cell::from(game.game_time.map(format_match_clock).unwrap_or_else(|| dash.to_string())),
+                lead,
+                Cell::from(game.spectators.unwrap_or_default().to_string()),
+            ]);
+            if tracked {
+                row.style(Style::default().fg(accent).add_modifier(Modifier::BOLD))
+            } else {
+                row
+            }
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(1),
+            Constraint::Length(8),
+            Constraint::Min(12),
+            Constraint::Length(7),
+            Constraint::Min(12),
+            Constraint::Length(6),
+            Constraint::Length(7),
+            Constraint::Length(6),
+        ],
+    )
+    .header(header)
+    .block(block)
+    .column_spacing(1)
+    .style(Style::default().bg(base).fg(text))
+    .highlight_style(Style::default().bg(highlight))
+    .highlight_symbol("▌ ");
+
+    let mut state = TableState::default();
+    state.select(Some(selected - start));
+    frame.render_stateful_widget(table, area, &mut state);
+    hits.live = Some(ListHit {
+        area,
+        first_row_y: area.y + 2,
+        row_height: 1,
+        offset: start,
+        len: end,
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_live_team(
+    frame: &mut Frame,
+    app: &App,
+    players: &[&LivePlayer],
+    area: Rect,
+    title: String,
+    color: Color,
+    theme: Theme,
+    i18n: &I18n,
+) {
+    let Theme { base, text, accent, .. } = theme;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(title, Style::default().fg(color).add_modifier(Modifier::BOLD)))
+        .border_style(Style::default().fg(theme.border));
+    let rows: Vec<Row> = players
+        .iter()
+        .map(|player| {
+            let hero = player
+                .hero_id
+                .filter(|id| *id > 0)
+                .map(|id| app.hero_name(id, i18n))
+                .unwrap_or_else(|| i18n.placeholder_dash().to_string());
+            let name = player
+                .account_id
+                .and_then(|id| app.pro_label(id).or_else(|| app.tracked_label(id)))
+                .or_else(|| player.name.clone())
+                .unwrap_or_else(|| i18n.anonymous().to_string());
+            let row = Row::new(vec![Cell::from(truncate_text(&hero, 18)), Cell::from(truncate_text(&name, 24))]);
+            if player.account_id.is_some_and(|id| app.is_tracked(id)) {
+                row.style(Style::default().fg(accent).add_modifier(Modifier::BOLD))
+            } else {
+                row
+            }
+        })
+        .collect();
+    let table = Table::new(rows, [Constraint::Length(18), Constraint::Min(10)])
+        .block(block)
+        .column_spacing(1)
+        .style(Style::default().bg(base).fg(text));
+    frame.render_widget(table, area);
+}
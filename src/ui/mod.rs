@@ -1,16 +1,26 @@
 use ratatui::prelude::*;
 use ratatui::widgets::Block;
 
-use crate::app::App;
+use crate::app::{App, HitAreas, InputMode};
 use crate::config::{Keybinds, Theme};
 use crate::i18n::I18n;
 
 mod banner;
+mod charts;
+mod compare;
+mod explorer;
 mod helpers;
+mod heroes;
 mod images;
+mod live;
+mod palette;
 mod panels;
+mod pro;
+mod public;
 mod tables;
 
+pub use helpers::is_win;
+
 #[derive(Clone)]
 pub struct ImageTarget {
     pub area: Rect,
@@ -19,6 +29,7 @@ pub struct ImageTarget {
 
 pub struct UiDrawResult {
     pub images: Vec<ImageTarget>,
+    pub hits: HitAreas,
 }
 
 pub fn draw_ui(
@@ -28,23 +39,15 @@ pub fn draw_ui(
     keybinds: &Keybinds,
     i18n: &I18n,
 ) -> UiDrawResult {
-    let Theme {
-        base,
-        text,
-        accent,
-        warn,
-        success,
-    } = theme;
-
     let size = frame.size();
-    frame.render_widget(Block::default().style(Style::default().bg(base)), size);
+    frame.render_widget(Block::default().style(Style::default().bg(theme.base)), size);
 
     let layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Length(9), Constraint::Min(0)].as_ref())
         .split(size);
 
-    banner::draw_banner(frame, app, layout[0], base, text, accent, i18n);
+    banner::draw_banner(frame, app, layout[0], theme, i18n);
 
     let content = Layout::default()
         .direction(Direction::Horizontal)
@@ -52,34 +55,32 @@ pub fn draw_ui(
         .split(layout[1]);
 
     let mut images = Vec::new();
+    let mut hits = HitAreas::default();
     panels::draw_left_panel(
         frame,
         app,
         content[0],
-        base,
-        text,
-        accent,
-        warn,
-        success,
+        theme,
         &mut images,
+        &mut hits,
         i18n,
     );
     panels::draw_right_panel(
         frame,
         app,
         content[1],
-        base,
-        text,
-        accent,
-        warn,
-        success,
+        theme,
         &mut images,
+        &mut hits,
         i18n,
     );
 
     if app.show_help {
-        panels::draw_help_popup(frame, keybinds, base, text, accent, i18n);
+        panels::draw_help_popup(frame, app, keybinds, theme, i18n);
+    }
+    if app.input_mode == InputMode::Palette {
+        palette::draw_palette(frame, app, keybinds, theme, i18n);
     }
 
-    UiDrawResult { images }
+    UiDrawResult { images, hits }
 }
@@ -0,0 +1,68 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState};
+
+use crate::actions::filter_actions;
+use crate::app::App;
+use crate::config::{Keybinds, Theme};
+use crate::i18n::I18n;
+
+use super::helpers::centered_rect;
+
+pub fn draw_palette(
+    frame: &mut Frame,
+    app: &App,
+    keybinds: &Keybinds,
+    theme: Theme,
+    i18n: &I18n,
+) {
+    let Theme { base, text, accent, highlight, border, .. } = theme;
+    let area = centered_rect(60, 60, frame.size());
+    frame.render_widget(Clear, area);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(area);
+
+    let query = Paragraph::new(format!("> {}", app.palette_query))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(i18n.title_palette())
+                .border_style(Style::default().fg(border)),
+        )
+        .style(Style::default().bg(base).fg(text));
+    frame.render_widget(query, layout[0]);
+
+    let actions = filter_actions(&app.palette_query, i18n);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border));
+    if actions.is_empty() {
+        let empty = Paragraph::new(i18n.palette_empty())
+            .block(block)
+            .style(Style::default().bg(base).fg(text));
+        frame.render_widget(empty, layout[1]);
+        return;
+    }
+
+    let rows: Vec<Row> = actions
+        .iter()
+        .map(|action| {
+            Row::new(vec![
+                Cell::from(i18n.action_label(*action).to_string()),
+                Cell::from(action.binding(keybinds).map(|b| b.to_string()).unwrap_or_default())
+                    .style(Style::default().fg(accent)),
+            ])
+        })
+        .collect();
+    let table = Table::new(rows, [Constraint::Min(20), Constraint::Length(12)])
+        .block(block)
+        .column_spacing(1)
+        .style(Style::default().bg(base).fg(text))
+        .highlight_style(Style::default().bg(highlight))
+        .highlight_symbol("▌ ");
+    let mut state = TableState::default();
+    state.select(Some(app.palette_index.min(actions.len() - 1)));
+    frame.render_stateful_widget(table, layout[1], &mut state);
+}
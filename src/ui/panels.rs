@@ -1,62 +1,121 @@
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, Clear, Gauge, Paragraph, Row, Sparkline, Table, TableState, Tabs, Wrap};
+use ratatui::widgets::{Block, Borders, Cell, Clear, Gauge, Paragraph, Row, Sparkline, Table, TableState, Tabs, Wrap};
 
-use crate::app::App;
-use crate::config::Keybinds;
+use crate::actions::help_sections;
+use crate::app::{App, HitAreas, InputMode, ListHit};
+use crate::config::{Keybinds, Theme};
 use crate::i18n::I18n;
 
 use super::helpers::{
-    build_profile_text, build_quick_stats, build_sparkline, build_stats_text, compute_winrate,
-    centered_rect,
+    build_loadout_text, build_profile_text, build_quick_stats, build_sparkline, build_stats_text, centered_rect,
+    build_breakdown_rows, format_average, hero_breakdown, summarize_matches, truncate_text, LIFETIME_FIELDS,
 };
+use super::charts::draw_charts_tab;
+use super::compare::draw_compare_tab;
+use super::explorer::draw_explorer_tab;
+use super::heroes::draw_heroes_tab;
 use super::images::{push_loadout_images, push_recent_images};
-use super::tables::{draw_match_detail_tables, draw_matches_table};
+use super::live::draw_live_tab;
+use super::pro::draw_pro_tab;
+use super::public::draw_public_tab;
+use super::helpers::{chat_messages, player_benchmarks};
+use super::tables::{draw_match_chat, draw_match_detail_tables, draw_matches_table, draw_player_benchmarks};
 use super::ImageTarget;
 
 pub fn draw_left_panel(
     frame: &mut Frame,
     app: &mut App,
     area: Rect,
-    base: Color,
-    text: Color,
-    accent: Color,
-    warn: Color,
-    success: Color,
+    theme: Theme,
     images: &mut Vec<ImageTarget>,
+    hits: &mut HitAreas,
     i18n: &I18n,
 ) {
+    let Theme { base, text, accent, warn, success, highlight, border, .. } = theme;
+    let favorites_height = (app.favorites.len() as u16 * 2 + 2).clamp(3, 12);
     let panel = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3),
+            Constraint::Length(favorites_height),
             Constraint::Length(10),
-            Constraint::Min(8),
+            Constraint::Min(4),
             Constraint::Length(5),
         ])
         .split(area);
 
-    let input_title = i18n.input_search(matches!(app.input_mode, crate::app::InputMode::Editing));
-    let input = Paragraph::new(app.input.as_str())
+    let (input_title, input_value) = match app.input_mode {
+        InputMode::Alias => (i18n.input_alias(), app.alias_input.as_str()),
+        InputMode::Compare => (i18n.input_compare(), app.input.as_str()),
+        InputMode::HeroFilter => (i18n.input_hero_filter(), app.hero_filter.as_str()),
+        InputMode::QueryName => (i18n.input_query_name(), app.query_name_input.as_str()),
+        mode => (i18n.input_search(mode == InputMode::Editing), app.input.as_str()),
+    };
+    let input = Paragraph::new(input_value)
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .title(input_title)
-                .border_style(Style::default().fg(accent)),
+                .border_style(Style::default().fg(border)),
         )
         .style(Style::default().bg(base).fg(text));
     frame.render_widget(input, panel[0]);
 
+    if app.favorites.is_empty() {
+        let favorites = Paragraph::new(i18n.no_favorites())
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(i18n.title_favorites())
+                    .border_style(Style::default().fg(border)),
+            )
+            .style(Style::default().bg(base).fg(text))
+            .wrap(Wrap { trim: true });
+        frame.render_widget(favorites, panel[1]);
+    } else {
+        let rows: Vec<Row> = app
+            .favorites
+            .iter()
+            .map(|entry| Row::new(vec![String::new(), entry.label()]).height(2))
+            .collect();
+        let favorites = Table::new(
+            rows,
+            [Constraint::Length(6), Constraint::Min(10)],
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(i18n.title_favorites())
+                .border_style(Style::default().fg(border)),
+        )
+        .column_spacing(1)
+        .style(Style::default().bg(base).fg(text))
+        .highlight_style(Style::default().bg(highlight))
+        .highlight_symbol("▌ ");
+        let mut state = TableState::default();
+        if app.favorite_active {
+            state.select(app.favorite_index);
+        }
+        frame.render_stateful_widget(favorites, panel[1], &mut state);
+        hits.favorites = Some(list_hit(panel[1], state.offset(), app.favorites.len()));
+        push_recent_images(
+            app.favorites.iter().skip(state.offset()).map(|entry| entry.avatar_url.as_ref()),
+            panel[1],
+            images,
+        );
+    }
+
     if app.recent_searches.is_empty() {
         let recent = Paragraph::new(i18n.no_recent())
             .block(
                 Block::default()
                     .borders(Borders::ALL)
                     .title(i18n.title_recent())
-                    .border_style(Style::default().fg(accent)),
+                    .border_style(Style::default().fg(border)),
             )
             .style(Style::default().bg(base).fg(text))
             .wrap(Wrap { trim: true });
-        frame.render_widget(recent, panel[1]);
+        frame.render_widget(recent, panel[2]);
     } else {
         let rows: Vec<Row> = app
             .recent_searches
@@ -77,21 +136,26 @@ pub fn draw_left_panel(
             Block::default()
                 .borders(Borders::ALL)
                 .title(i18n.title_recent())
-                .border_style(Style::default().fg(accent)),
+                .border_style(Style::default().fg(border)),
         )
         .column_spacing(1)
         .style(Style::default().bg(base).fg(text))
-        .highlight_style(Style::default().bg(Color::Rgb(49, 50, 68)))
+        .highlight_style(Style::default().bg(highlight))
         .highlight_symbol("▌ ");
         let mut state = TableState::default();
         if app.recent_active {
             state.select(app.recent_index);
         }
-        frame.render_stateful_widget(recent, panel[1], &mut state);
-        push_recent_images(app, panel[1], images);
+        frame.render_stateful_widget(recent, panel[2], &mut state);
+        hits.recent = Some(list_hit(panel[2], state.offset(), app.recent_searches.len()));
+        push_recent_images(
+            app.recent_searches.iter().skip(state.offset()).map(|entry| entry.avatar_url.as_ref()),
+            panel[2],
+            images,
+        );
     }
 
-    draw_search_help(frame, panel[2], base, text, accent, i18n);
+    draw_search_help(frame, panel[3], theme, i18n);
 
     let status_style = if app.loading || app.detail_loading || app.avatar_loading {
         Style::default().fg(warn)
@@ -112,30 +176,44 @@ pub fn draw_left_panel(
             Block::default()
                 .borders(Borders::ALL)
                 .title(i18n.title_status())
-                .border_style(Style::default().fg(accent)),
+                .border_style(Style::default().fg(border)),
         )
         .style(Style::default().bg(base).fg(text).patch(status_style))
         .wrap(Wrap { trim: true });
-    frame.render_widget(status, panel[3]);
+    frame.render_widget(status, panel[4]);
 
     if app.net_total > 0 {
         let ratio = (app.net_done as f64 / app.net_total.max(1) as f64).min(1.0);
         let bar = Gauge::default()
             .gauge_style(Style::default().fg(accent).bg(base))
             .ratio(ratio);
-        let bar_area = Rect::new(panel[3].x + 1, panel[3].y + panel[3].height.saturating_sub(2), panel[3].width.saturating_sub(2), 1);
+        let bar_area = Rect::new(
+            panel[4].x + 1,
+            panel[4].y + panel[4].height.saturating_sub(2),
+            panel[4].width.saturating_sub(2),
+            1,
+        );
         frame.render_widget(bar, bar_area);
     }
 }
 
+fn list_hit(area: Rect, offset: usize, len: usize) -> ListHit {
+    ListHit {
+        area,
+        first_row_y: area.y + 1,
+        row_height: 2,
+        offset,
+        len,
+    }
+}
+
 fn draw_search_help(
     frame: &mut Frame,
     area: Rect,
-    base: Color,
-    text: Color,
-    accent: Color,
+    theme: Theme,
     i18n: &I18n,
 ) {
+    let Theme { base, text, border, .. } = theme;
     let title = i18n.title_results();
     let content = i18n.search_hint();
     let panel = Paragraph::new(content)
@@ -143,7 +221,7 @@ fn draw_search_help(
             Block::default()
                 .borders(Borders::ALL)
                 .title(title)
-                .border_style(Style::default().fg(accent)),
+                .border_style(Style::default().fg(border)),
         )
         .style(Style::default().bg(base).fg(text))
         .wrap(Wrap { trim: true });
@@ -154,12 +232,9 @@ pub fn draw_right_panel(
     frame: &mut Frame,
     app: &mut App,
     area: Rect,
-    base: Color,
-    text: Color,
-    accent: Color,
-    warn: Color,
-    success: Color,
+    theme: Theme,
     images: &mut Vec<ImageTarget>,
+    hits: &mut HitAreas,
     i18n: &I18n,
 ) {
     let layout = Layout::default()
@@ -167,12 +242,19 @@ pub fn draw_right_panel(
         .constraints([Constraint::Length(3), Constraint::Min(0)])
         .split(area);
 
-    draw_tabs(frame, app, layout[0], base, text, accent, i18n);
+    draw_tabs(frame, app, layout[0], theme, hits, i18n);
 
     match app.tab_index {
-        0 => draw_overview_tab(frame, app, layout[1], base, text, accent, warn, success, images, i18n),
-        1 => draw_matches_tab(frame, app, layout[1], base, text, accent, images, i18n),
-        _ => draw_stats_tab(frame, app, layout[1], base, text, accent, success, i18n),
+        0 => draw_overview_tab(frame, app, layout[1], theme, images, hits, i18n),
+        1 => draw_matches_tab(frame, app, layout[1], theme, images, hits, i18n),
+        2 => draw_stats_tab(frame, app, layout[1], theme, i18n),
+        3 => draw_charts_tab(frame, app, layout[1], theme, i18n),
+        4 => draw_heroes_tab(frame, app, layout[1], theme, images, hits, i18n),
+        5 => draw_pro_tab(frame, app, layout[1], theme, hits, i18n),
+        6 => draw_live_tab(frame, app, layout[1], theme, hits, i18n),
+        7 => draw_public_tab(frame, app, layout[1], theme, hits, i18n),
+        8 => draw_explorer_tab(frame, app, layout[1], theme, hits, i18n),
+        _ => draw_compare_tab(frame, app, layout[1], theme, images, i18n),
     }
 }
 
@@ -180,12 +262,30 @@ fn draw_tabs(
     frame: &mut Frame,
     app: &App,
     area: Rect,
-    base: Color,
-    text: Color,
-    accent: Color,
+    theme: Theme,
+    hits: &mut HitAreas,
     i18n: &I18n,
 ) {
-    let titles = [i18n.tab_overview(), i18n.tab_matches(), i18n.tab_stats()]
+    let Theme { base, text, accent, border, .. } = theme;
+    let labels = [
+        i18n.tab_overview(),
+        i18n.tab_matches(),
+        i18n.tab_stats(),
+        i18n.tab_charts(),
+        i18n.tab_heroes(),
+        i18n.tab_pro(),
+        i18n.tab_live(),
+        i18n.tab_public(),
+        i18n.tab_explorer(),
+        i18n.tab_compare(),
+    ];
+    let mut x = area.x + 1;
+    for (idx, label) in labels.iter().enumerate() {
+        let width = Line::from(*label).width() as u16 + 2;
+        hits.tabs.push((Rect::new(x, area.y + 1, width, 1), idx));
+        x = x.saturating_add(width + 1);
+    }
+    let titles = labels
         .iter()
         .map(|t| Line::from(*t))
         .collect::<Vec<_>>();
@@ -195,7 +295,7 @@ fn draw_tabs(
             Block::default()
                 .borders(Borders::ALL)
                 .title(i18n.title_views())
-                .border_style(Style::default().fg(accent)),
+                .border_style(Style::default().fg(border)),
         )
         .style(Style::default().bg(base).fg(text))
         .highlight_style(Style::default().fg(accent).add_modifier(Modifier::BOLD));
@@ -206,14 +306,12 @@ fn draw_overview_tab(
     frame: &mut Frame,
     app: &App,
     area: Rect,
-    base: Color,
-    text: Color,
-    accent: Color,
-    warn: Color,
-    success: Color,
+    theme: Theme,
     images: &mut Vec<ImageTarget>,
+    hits: &mut HitAreas,
     i18n: &I18n,
 ) {
+    let Theme { base, text, warn, success, border, .. } = theme;
     let layout = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
@@ -235,7 +333,7 @@ fn draw_overview_tab(
             Block::default()
                 .borders(Borders::ALL)
                 .title(i18n.title_profile())
-                .border_style(Style::default().fg(accent)),
+                .border_style(Style::default().fg(border)),
         )
         .style(Style::default().bg(base).fg(text).patch(status_style))
         .wrap(Wrap { trim: true });
@@ -246,23 +344,23 @@ fn draw_overview_tab(
             Block::default()
                 .borders(Borders::ALL)
                 .title(i18n.title_quick_stats())
-                .border_style(Style::default().fg(accent)),
+                .border_style(Style::default().fg(border)),
         )
         .style(Style::default().bg(base).fg(text))
         .wrap(Wrap { trim: true });
     frame.render_widget(quick, profile_panel[1]);
 
-    draw_match_detail_tables(frame, app, profile_panel[2], base, text, accent, images, i18n);
+    draw_match_detail_tables(frame, app, profile_panel[2], theme, images, hits, i18n);
 
     let right_panel = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(8), Constraint::Length(6), Constraint::Min(0)])
+        .constraints([Constraint::Length(8), Constraint::Length(8), Constraint::Min(0)])
         .split(layout[1]);
 
     let avatar_block = Block::default()
         .borders(Borders::ALL)
         .title(i18n.title_avatar())
-        .border_style(Style::default().fg(accent))
+        .border_style(Style::default().fg(border))
         .style(Style::default().bg(base).fg(text));
     frame.render_widget(avatar_block, right_panel[0]);
     if let Some(url) = &app.avatar_url {
@@ -277,17 +375,28 @@ fn draw_overview_tab(
     let loadout_block = Block::default()
         .borders(Borders::ALL)
         .title(i18n.title_loadout())
-        .border_style(Style::default().fg(accent))
+        .border_style(Style::default().fg(border))
         .style(Style::default().bg(base).fg(text));
     frame.render_widget(loadout_block, right_panel[1]);
-    push_loadout_images(app, right_panel[1], images);
+    let loadout = right_panel[1];
+    let names_area = Rect::new(
+        loadout.x + 1,
+        loadout.y + 5,
+        loadout.width.saturating_sub(2),
+        loadout.height.saturating_sub(6),
+    );
+    let names = Paragraph::new(build_loadout_text(app, i18n))
+        .style(Style::default().bg(base).fg(text))
+        .wrap(Wrap { trim: true });
+    frame.render_widget(names, names_area);
+    push_loadout_images(app, loadout, images);
 
     let hint = Paragraph::new(i18n.hint_tabs())
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .title(i18n.title_hints())
-                .border_style(Style::default().fg(accent)),
+                .border_style(Style::default().fg(border)),
         )
         .style(Style::default().bg(base).fg(text));
     frame.render_widget(hint, right_panel[2]);
@@ -297,10 +406,9 @@ fn draw_matches_tab(
     frame: &mut Frame,
     app: &mut App,
     area: Rect,
-    base: Color,
-    text: Color,
-    accent: Color,
+    theme: Theme,
     images: &mut Vec<ImageTarget>,
+    hits: &mut HitAreas,
     i18n: &I18n,
 ) {
     let layout = Layout::default()
@@ -308,110 +416,305 @@ fn draw_matches_tab(
         .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
         .split(area);
 
-    draw_matches_table(frame, app, layout[0], base, text, accent, images, i18n);
-    draw_match_detail_tables(frame, app, layout[1], base, text, accent, images, i18n);
+    draw_matches_table(frame, app, layout[0], theme, images, hits, i18n);
+    let has_benchmarks = !app.detail_loading
+        && !app.show_laning
+        && app
+            .selected_team_player()
+            .is_some_and(|player| !player_benchmarks(player).is_empty());
+    let has_chat = !app.detail_loading
+        && app
+            .match_detail
+            .as_ref()
+            .is_some_and(|detail| !chat_messages(detail).is_empty());
+    if !has_benchmarks && !has_chat {
+        draw_match_detail_tables(frame, app, layout[1], theme, images, hits, i18n);
+        return;
+    }
+    let detail_layout = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+        .split(layout[1]);
+    draw_match_detail_tables(frame, app, detail_layout[0], theme, images, hits, i18n);
+    if has_benchmarks {
+        draw_player_benchmarks(frame, app, detail_layout[1], theme, i18n);
+        return;
+    }
+    let max_scroll = draw_match_chat(frame, app, detail_layout[1], theme, hits, i18n);
+    app.chat_scroll = app.chat_scroll.min(max_scroll);
 }
 
 fn draw_stats_tab(
     frame: &mut Frame,
     app: &App,
     area: Rect,
-    base: Color,
-    text: Color,
-    accent: Color,
-    success: Color,
+    theme: Theme,
     i18n: &I18n,
 ) {
+    let Theme { base, text, accent, win, loss, border, gauge, .. } = theme;
     let layout = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(5), Constraint::Length(6), Constraint::Min(0)])
+        .constraints([Constraint::Length(5), Constraint::Percentage(45), Constraint::Min(0)])
         .split(area);
+    let top = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(layout[0]);
 
-    let winrate = compute_winrate(app);
+    let matches = app.stats_matches();
+    let summary = summarize_matches(&matches);
+    let winrate = summary.winrate();
     let gauge = Gauge::default()
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(i18n.title_winrate())
-                .border_style(Style::default().fg(accent)),
+                .title(format!("{} · {}", i18n.title_winrate(), app.stats_patch_label(i18n)))
+                .border_style(Style::default().fg(border)),
         )
-        .gauge_style(Style::default().fg(success).bg(base))
+        .gauge_style(Style::default().fg(gauge).bg(base))
         .ratio(winrate)
         .label(format!("{:.0}%", winrate * 100.0));
-    frame.render_widget(gauge, layout[0]);
+    frame.render_widget(gauge, top[0]);
 
-    let spark_data = build_sparkline(app);
+    let spark_data = build_sparkline(&matches);
     let spark = Sparkline::default()
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .title(i18n.title_recent_results())
-                .border_style(Style::default().fg(accent)),
+                .border_style(Style::default().fg(border)),
         )
         .style(Style::default().fg(accent).bg(base))
         .data(&spark_data);
-    frame.render_widget(spark, layout[1]);
+    frame.render_widget(spark, top[1]);
 
-    let info = Paragraph::new(build_stats_text(app, i18n))
+    let middle = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(layout[1]);
+
+    let info = Paragraph::new(build_stats_text(&summary, i18n))
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .title(i18n.title_summary())
-                .border_style(Style::default().fg(accent)),
+                .border_style(Style::default().fg(border)),
         )
         .style(Style::default().bg(base).fg(text))
         .wrap(Wrap { trim: true });
-    frame.render_widget(info, layout[2]);
+    frame.render_widget(info, middle[0]);
+
+    let header = Row::new(vec![
+        i18n.table_hero(),
+        i18n.table_games(),
+        i18n.table_winrate(),
+        i18n.table_kda(),
+    ])
+    .style(Style::default().fg(accent).add_modifier(Modifier::BOLD));
+    let rows: Vec<Row> = hero_breakdown(&matches)
+        .into_iter()
+        .map(|row| {
+            let winrate = row.wins as f64 / row.games.max(1) as f64;
+            let color = if winrate >= 0.5 { win } else { loss };
+            Row::new(vec![
+                Cell::from(truncate_text(&app.hero_name(row.hero_id, i18n), 20)),
+                Cell::from(row.games.to_string()),
+                Cell::from(format!("{:.0}%", winrate * 100.0)).style(Style::default().fg(color)),
+                Cell::from(format!("{:.2}", row.kda)),
+            ])
+        })
+        .collect();
+    let heroes = Table::new(
+        rows,
+        [
+            Constraint::Min(12),
+            Constraint::Length(6),
+            Constraint::Length(5),
+            Constraint::Length(6),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(i18n.title_heroes())
+            .border_style(Style::default().fg(border)),
+    )
+    .column_spacing(1)
+    .style(Style::default().bg(base).fg(text));
+    frame.render_widget(heroes, middle[1]);
+
+    let bottom = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(layout[2]);
+    draw_lifetime_panels(frame, app, bottom[0], bottom[1], theme, i18n);
+}
+
+fn draw_lifetime_panels(
+    frame: &mut Frame,
+    app: &App,
+    totals_area: Rect,
+    breakdown_area: Rect,
+    theme: Theme,
+    i18n: &I18n,
+) {
+    let Theme { base, text, accent, win, loss, border, .. } = theme;
+    let Some(aggregates) = app.aggregates.as_ref() else {
+        let message = if app.account_id.is_some() {
+            i18n.loading_player()
+        } else {
+            i18n.no_player_loaded()
+        };
+        for (area, title) in [
+            (totals_area, i18n.title_lifetime()),
+            (breakdown_area, i18n.title_breakdown()),
+        ] {
+            let placeholder = Paragraph::new(message)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(title)
+                        .border_style(Style::default().fg(border)),
+                )
+                .style(Style::default().bg(base).fg(text));
+            frame.render_widget(placeholder, area);
+        }
+        return;
+    };
+
+    let header = Row::new(vec![i18n.compare_metric(), i18n.table_average()])
+        .style(Style::default().fg(accent).add_modifier(Modifier::BOLD));
+    let rows: Vec<Row> = LIFETIME_FIELDS
+        .iter()
+        .map(|field| {
+            let value = aggregates
+                .average(field)
+                .map(format_average)
+                .unwrap_or_else(|| i18n.placeholder_dash().to_string());
+            Row::new(vec![i18n.total_field(field).to_string(), value])
+        })
+        .collect();
+    let totals = Table::new(rows, [Constraint::Min(12), Constraint::Length(8)])
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(
+                    "{} · {}",
+                    i18n.title_lifetime(),
+                    i18n.lifetime_record(aggregates.wl.win, aggregates.wl.lose)
+                ))
+                .border_style(Style::default().fg(border)),
+        )
+        .column_spacing(1)
+        .style(Style::default().bg(base).fg(text));
+    frame.render_widget(totals, totals_area);
+
+    let header = Row::new(vec!["", "", i18n.table_games(), i18n.table_winrate()])
+        .style(Style::default().fg(accent).add_modifier(Modifier::BOLD));
+    let mut previous = "";
+    let rows: Vec<Row> = build_breakdown_rows(app, aggregates, i18n)
+        .into_iter()
+        .map(|row| {
+            let group = if row.group == previous {
+                String::new()
+            } else {
+                i18n.breakdown(row.group).to_string()
+            };
+            previous = row.group;
+            let winrate = row.wins as f64 / row.games.max(1) as f64;
+            let color = if winrate >= 0.5 { win } else { loss };
+            Row::new(vec![
+                Cell::from(group).style(Style::default().fg(accent)),
+                Cell::from(truncate_text(&row.label, 20)),
+                Cell::from(row.games.to_string()),
+                Cell::from(format!("{:.0}%", winrate * 100.0)).style(Style::default().fg(color)),
+            ])
+        })
+        .collect();
+    let breakdown = Table::new(
+        rows,
+        [
+            Constraint::Length(10),
+            Constraint::Min(12),
+            Constraint::Length(6),
+            Constraint::Length(5),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(i18n.title_breakdown())
+            .border_style(Style::default().fg(border)),
+    )
+    .column_spacing(1)
+    .style(Style::default().bg(base).fg(text));
+    frame.render_widget(breakdown, breakdown_area);
 }
 
 pub fn draw_help_popup(
     frame: &mut Frame,
+    app: &mut App,
     keybinds: &Keybinds,
-    base: Color,
-    text: Color,
-    accent: Color,
+    theme: Theme,
     i18n: &I18n,
 ) {
-    let overlay = Block::default().style(Style::default().bg(Color::Rgb(12, 12, 18)));
+    let Theme { base, text, accent, border, overlay, .. } = theme;
+    let overlay = Block::default().style(Style::default().bg(overlay));
     frame.render_widget(overlay, frame.size());
 
     let area = centered_rect(70, 70, frame.size());
-    let labels = i18n.help_labels();
-    let help_text = format!(
-        "{}\n  {}  {}\n  {}  {}\n\n{}\n  {}  {}/{}\n  {}  {}\n  {}  {}/{}\n\n{}\n  {}  {}/{}\n\n{}\n  {}  {}\n  {}  {}",
-        i18n.help_group_search(),
-        keybinds.search,
-        labels[0],
-        keybinds.clear_input,
-        labels[5],
-        i18n.help_group_navigation(),
-        keybinds.up,
-        labels[2],
-        keybinds.down,
-        keybinds.select,
-        labels[3],
-        keybinds.top,
-        labels[4],
-        keybinds.bottom,
-        i18n.help_group_views(),
-        keybinds.tab_prev,
-        labels[6],
-        keybinds.tab_next,
-        i18n.help_group_misc(),
-        keybinds.help,
-        labels[7],
-        keybinds.quit,
-        labels[1],
-    );
+    let sections = help_sections(keybinds, i18n);
+    let key_width = sections
+        .iter()
+        .flat_map(|(_, entries)| entries)
+        .map(|entry| Line::from(entry.keys.as_str()).width())
+        .max()
+        .unwrap_or(0)
+        .min(24);
+    let mut lines: Vec<Line> = Vec::new();
+    for (group, entries) in sections {
+        if entries.is_empty() {
+            continue;
+        }
+        if !lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::styled(
+            i18n.help_group(group),
+            Style::default().fg(accent).add_modifier(Modifier::BOLD),
+        ));
+        for entry in entries {
+            let pad = key_width.saturating_sub(Line::from(entry.keys.as_str()).width());
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {}{}  ", entry.keys, " ".repeat(pad)),
+                    Style::default().fg(accent),
+                ),
+                Span::raw(entry.label),
+            ]));
+        }
+    }
+
+    let visible = area.height.saturating_sub(2);
+    let max_scroll = (lines.len() as u16).saturating_sub(visible);
+    app.help_scroll = app.help_scroll.min(max_scroll);
+    let title = if max_scroll > 0 {
+        format!("{} ({}/{})", i18n.keybind_title(), app.help_scroll + 1, max_scroll + 1)
+    } else {
+        i18n.keybind_title().to_string()
+    };
     frame.render_widget(Clear, area);
-    let popup = Paragraph::new(help_text)
+    let popup = Paragraph::new(lines)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(i18n.keybind_title())
-                .border_style(Style::default().fg(accent)),
+                .title(title)
+                .border_style(Style::default().fg(border)),
         )
         .style(Style::default().bg(base).fg(text))
-        .wrap(Wrap { trim: true });
+        .scroll((app.help_scroll, 0));
     frame.render_widget(popup, area);
 }
@@ -0,0 +1,403 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap};
+
+use crate::app::{App, HitAreas, ListHit, ProPage, ProView, TeamDetail};
+use crate::config::Theme;
+use crate::i18n::I18n;
+use crate::models::ProMatch;
+
+use super::helpers::{format_duration, format_relative_time, truncate_text};
+
+pub fn draw_pro_tab(frame: &mut Frame, app: &App, area: Rect, theme: Theme, hits: &mut HitAreas, i18n: &I18n) {
+    match app.pro_page {
+        Some(ProPage::Team(team_id)) => draw_team_page(frame, app, team_id, area, theme, hits, i18n),
+        Some(ProPage::League(league_id)) => draw_league_page(frame, app, league_id, area, theme, hits, i18n),
+        None => match app.pro_view {
+            ProView::Matches => draw_pro_matches(frame, app, area, theme, hits, i18n),
+            ProView::Players => draw_pro_players(frame, app, area, theme, hits, i18n),
+            ProView::Teams => draw_pro_teams(frame, app, area, theme, hits, i18n),
+            ProView::Leagues => draw_leagues(frame, app, area, theme, hits, i18n),
+        },
+    }
+}
+
+fn pro_block<'a>(title: String, theme: Theme) -> Block<'a> {
+    Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(Style::default().fg(theme.border))
+}
+
+fn draw_placeholder(frame: &mut Frame, area: Rect, block: Block, message: &str, theme: Theme) {
+    let placeholder = Paragraph::new(message.to_string())
+        .block(block)
+        .style(Style::default().bg(theme.base).fg(theme.text))
+        .wrap(Wrap { trim: true });
+    frame.render_widget(placeholder, area);
+}
+
+fn list_title(app: &App, i18n: &I18n) -> String {
+    i18n.title_pro(i18n.pro_view(app.pro_view))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_pro_table(
+    frame: &mut Frame,
+    area: Rect,
+    block: Block,
+    header: Vec<&str>,
+    rows: Vec<Row>,
+    widths: &[Constraint],
+    selected: usize,
+    theme: Theme,
+) -> ListHit {
+    let Theme { base, text, accent, highlight, .. } = theme;
+    let header = Row::new(header).style(Style::default().fg(accent).add_modifier(Modifier::BOLD));
+    let total = rows.len();
+    let selected = selected.min(total.saturating_sub(1));
+    let max_rows = area.height.saturating_sub(3).max(1) as usize;
+    let mut start = selected.saturating_sub(max_rows / 2);
+    if start + max_rows > total {
+        start = total.saturating_sub(max_rows);
+    }
+    let end = (start + max_rows).min(total);
+    let rows: Vec<Row> = rows.into_iter().skip(start).take(end - start).collect();
+
+    let table = Table::new(rows, widths.to_vec())
+        .header(header)
+        .block(block)
+        .column_spacing(1)
+        .style(Style::default().bg(base).fg(text))
+        .highlight_style(Style::default().bg(highlight))
+        .highlight_symbol("▌ ");
+    let mut state = TableState::default();
+    state.select(Some(selected - start));
+    frame.render_stateful_widget(table, area, &mut state);
+    ListHit {
+        area,
+        first_row_y: area.y + 2,
+        row_height: 1,
+        offset: start,
+        len: end,
+    }
+}
+
+fn text_or_dash(value: Option<&str>, i18n: &I18n, max: usize) -> String {
+    match value.filter(|value| !value.is_empty()) {
+        Some(value) => truncate_text(value, max),
+        None => i18n.placeholder_dash().to_string(),
+    }
+}
+
+fn pro_match_rows<'a>(matches: &[ProMatch], theme: Theme, i18n: &I18n) -> Vec<Row<'a>> {
+    matches
+        .iter()
+        .map(|m| {
+            let side_style = |radiant: bool| match m.radiant_win {
+                Some(radiant_win) if radiant_win == radiant => {
+                    Style::default().fg(theme.win).add_modifier(Modifier::BOLD)
+                }
+                _ => Style::default(),
+            };
+            let score = match (m.radiant_score, m.dire_score) {
+                (Some(radiant), Some(dire)) => format!("{radiant} - {dire}"),
+                _ => i18n.placeholder_dash().to_string(),
+            };
+            Row::new(vec![
+                Cell::from(text_or_dash(m.league_name.as_deref(), i18n, 28)),
+                Cell::from(text_or_dash(m.radiant_name.as_deref(), i18n, 20)).style(side_style(true)),
+                Cell::from(score),
+                Cell::from(text_or_dash(m.dire_name.as_deref(), i18n, 20)).style(side_style(false)),
+                Cell::from(m.duration.map(format_duration).unwrap_or_default()),
+                Cell::from(format_relative_time(m.start_time, i18n)),
+            ])
+        })
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_match_list(
+    frame: &mut Frame,
+    matches: &[ProMatch],
+    area: Rect,
+    block: Block,
+    selected: usize,
+    theme: Theme,
+    hits: &mut HitAreas,
+    i18n: &I18n,
+) {
+    if matches.is_empty() {
+        draw_placeholder(frame, area, block, i18n.pro_empty(), theme);
+        return;
+    }
+    let header = vec![
+        i18n.table_league(),
+        i18n.title_radiant(),
+        i18n.table_score(),
+        i18n.title_dire(),
+        i18n.table_duration(),
+        i18n.table_time(),
+    ];
+    let widths = [
+        Constraint::Min(20),
+        Constraint::Length(20),
+        Constraint::Length(7),
+        Constraint::Length(20),
+        Constraint::Length(6),
+        Constraint::Length(10),
+    ];
+    let rows = pro_match_rows(matches, theme, i18n);
+    hits.pro = Some(draw_pro_table(frame, area, block, header, rows, &widths, selected, theme));
+}
+
+fn draw_pro_matches(frame: &mut Frame, app: &App, area: Rect, theme: Theme, hits: &mut HitAreas, i18n: &I18n) {
+    let block = pro_block(list_title(app, i18n), theme);
+    let Some(matches) = &app.pro.matches else {
+        draw_placeholder(frame, area, block, i18n.loading_player(), theme);
+        return;
+    };
+    draw_match_list(frame, matches, area, block, app.pro_index, theme, hits, i18n);
+}
+
+fn draw_pro_players(frame: &mut Frame, app: &App, area: Rect, theme: Theme, hits: &mut HitAreas, i18n: &I18n) {
+    let block = pro_block(list_title(app, i18n), theme);
+    let Some(players) = &app.pro.players else {
+        draw_placeholder(frame, area, block, i18n.loading_player(), theme);
+        return;
+    };
+    if players.is_empty() {
+        draw_placeholder(frame, area, block, i18n.pro_empty(), theme);
+        return;
+    }
+    let header = vec![i18n.table_player(), i18n.table_team(), i18n.table_tag(), i18n.table_country()];
+    let widths = [Constraint::Length(24), Constraint::Min(20), Constraint::Length(10), Constraint::Length(8)];
+    let rows = players
+        .iter()
+        .map(|player| {
+            let name = player.name.as_deref().or(player.personaname.as_deref());
+            Row::new(vec![
+                Cell::from(text_or_dash(name, i18n, 24)),
+                Cell::from(text_or_dash(player.team_name.as_deref(), i18n, 30)),
+                Cell::from(text_or_dash(player.team_tag.as_deref(), i18n, 10)),
+                Cell::from(text_or_dash(player.country_code.as_deref(), i18n, 8).to_uppercase()),
+            ])
+        })
+        .collect();
+    hits.pro = Some(draw_pro_table(frame, area, block, header, rows, &widths, app.pro_index, theme));
+}
+
+fn draw_pro_teams(frame: &mut Frame, app: &App, area: Rect, theme: Theme, hits: &mut HitAreas, i18n: &I18n) {
+    let block = pro_block(list_title(app, i18n), theme);
+    let Some(teams) = &app.pro.teams else {
+        draw_placeholder(frame, area, block, i18n.loading_player(), theme);
+        return;
+    };
+    if teams.is_empty() {
+        draw_placeholder(frame, area, block, i18n.pro_empty(), theme);
+        return;
+    }
+    let header = vec![
+        i18n.table_team(),
+        i18n.table_tag(),
+        i18n.table_rating(),
+        i18n.table_result(),
+        i18n.table_winrate(),
+        i18n.table_last_match(),
+    ];
+    let widths = [
+        Constraint::Min(20),
+        Constraint::Length(10),
+        Constraint::Length(7),
+        Constraint::Length(11),
+        Constraint::Length(6),
+        Constraint::Length(12),
+    ];
+    let rows = teams
+        .iter()
+        .map(|team| {
+            let games = team.wins + team.losses;
+            let winrate = if games > 0 {
+                format!("{:.0}%", team.wins as f64 / games as f64 * 100.0)
+            } else {
+                i18n.placeholder_dash().to_string()
+            };
+            Row::new(vec![
+                Cell::from(text_or_dash(team.name.as_deref(), i18n, 30)),
+                Cell::from(text_or_dash(team.tag.as_deref(), i18n, 10)),
+                Cell::from(team.rating.map(|rating| format!("{rating:.0}")).unwrap_or_default()),
+                Cell::from(format!("{}/{}", team.wins, team.losses)),
+                Cell::from(winrate),
+                Cell::from(format_relative_time(team.last_match_time, i18n)),
+            ])
+        })
+        .collect();
+    hits.pro = Some(draw_pro_table(frame, area, block, header, rows, &widths, app.pro_index, theme));
+}
+
+fn draw_leagues(frame: &mut Frame, app: &App, area: Rect, theme: Theme, hits: &mut HitAreas, i18n: &I18n) {
+    let block = pro_block(list_title(app, i18n), theme);
+    let Some(leagues) = &app.pro.leagues else {
+        draw_placeholder(frame, area, block, i18n.loading_player(), theme);
+        return;
+    };
+    if leagues.is_empty() {
+        draw_placeholder(frame, area, block, i18n.pro_empty(), theme);
+        return;
+    }
+    let header = vec![i18n.table_league(), i18n.table_tier(), i18n.table_id()];
+    let widths = [Constraint::Min(30), Constraint::Length(14), Constraint::Length(8)];
+    let rows = leagues
+        .iter()
+        .map(|league| {
+            Row::new(vec![
+                Cell::from(text_or_dash(league.name.as_deref(), i18n, 60)),
+                Cell::from(league.tier.as_deref().map(|tier| i18n.league_tier(tier)).unwrap_or_default()),
+                Cell::from(league.leagueid.to_string()),
+            ])
+        })
+        .collect();
+    hits.pro = Some(draw_pro_table(frame, area, block, header, rows, &widths, app.pro_index, theme));
+}
+
+fn draw_league_page(
+    frame: &mut Frame,
+    app: &App,
+    league_id: i32,
+    area: Rect,
+    theme: Theme,
+    hits: &mut HitAreas,
+    i18n: &I18n,
+) {
+    let name = app
+        .pro
+        .leagues
+        .iter()
+        .flatten()
+        .find(|league| league.leagueid == league_id)
+        .and_then(|league| league.name.clone())
+        .unwrap_or_else(|| league_id.to_string());
+    let block = pro_block(i18n.title_matches_filtered(&name), theme);
+    let Some(league) = app.pro.league(league_id) else {
+        draw_placeholder(frame, area, block, i18n.loading_player(), theme);
+        return;
+    };
+    draw_match_list(frame, &league.matches, area, block, app.pro_page_index, theme, hits, i18n);
+}
+
+fn draw_team_page(
+    frame: &mut Frame,
+    app: &App,
+    team_id: i64,
+    area: Rect,
+    theme: Theme,
+    hits: &mut HitAreas,
+    i18n: &I18n,
+) {
+    let name = app
+        .pro
+        .teams
+        .iter()
+        .flatten()
+        .find(|team| team.team_id == team_id)
+        .and_then(|team| team.name.clone())
+        .unwrap_or_else(|| team_id.to_string());
+    let layout = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+        .split(area);
+    let roster_block = pro_block(i18n.title_team_roster(&name), theme);
+    let matches_block = pro_block(i18n.title_matches_filtered(&name), theme);
+    let Some(team) = app.pro.team(team_id) else {
+        draw_placeholder(frame, layout[0], roster_block, i18n.loading_player(), theme);
+        draw_placeholder(frame, layout[1], matches_block, i18n.loading_player(), theme);
+        return;
+    };
+    draw_team_roster(frame, team, layout[0], roster_block, theme, i18n);
+    if team.matches.is_empty() {
+        draw_placeholder(frame, layout[1], matches_block, i18n.pro_empty(), theme);
+        return;
+    }
+
+    let header = vec![
+        i18n.table_opponent(),
+        i18n.table_result(),
+        i18n.table_league(),
+        i18n.table_duration(),
+        i18n.table_time(),
+    ];
+    let widths = [
+        Constraint::Length(20),
+        Constraint::Length(4),
+        Constraint::Min(20),
+        Constraint::Length(6),
+        Constraint::Length(10),
+    ];
+    let rows = team
+        .matches
+        .iter()
+        .map(|m| {
+            let result = match (m.radiant, m.radiant_win) {
+                (Some(radiant), Some(radiant_win)) if radiant == radiant_win => {
+                    Cell::from(i18n.result_win().to_string()).style(Style::default().fg(theme.win))
+                }
+                (Some(_), Some(_)) => Cell::from(i18n.result_loss().to_string()).style(Style::default().fg(theme.loss)),
+                _ => Cell::from(i18n.placeholder_dash().to_string()),
+            };
+            Row::new(vec![
+                Cell::from(text_or_dash(m.opposing_team_name.as_deref(), i18n, 20)),
+                result,
+                Cell::from(text_or_dash(m.league_name.as_deref(), i18n, 40)),
+                Cell::from(m.duration.map(format_duration).unwrap_or_default()),
+                Cell::from(format_relative_time(m.start_time, i18n)),
+            ])
+        })
+        .collect();
+    hits.pro = Some(draw_pro_table(
+        frame,
+        layout[1],
+        matches_block,
+        header,
+        rows,
+        &widths,
+        app.pro_page_index,
+        theme,
+    ));
+}
+
+fn draw_team_roster(frame: &mut Frame, team: &TeamDetail, area: Rect, block: Block, theme: Theme, i18n: &I18n) {
+    let Theme { base, text, accent, border, .. } = theme;
+    if team.players.is_empty() {
+        draw_placeholder(frame, area, block, i18n.pro_empty(), theme);
+        return;
+    }
+    let header = Row::new(vec![i18n.table_player(), i18n.table_games(), i18n.table_winrate()])
+        .style(Style::default().fg(accent).add_modifier(Modifier::BOLD));
+    let rows: Vec<Row> = team
+        .players
+        .iter()
+        .map(|player| {
+            let style = if player.is_current_team_member == Some(true) {
+                Style::default().fg(text)
+            } else {
+                Style::default().fg(border)
+            };
+            let winrate = if player.games_played > 0 {
+                format!("{:.0}%", player.wins as f64 / player.games_played as f64 * 100.0)
+            } else {
+                i18n.placeholder_dash().to_string()
+            };
+            Row::new(vec![
+                Cell::from(text_or_dash(player.name.as_deref(), i18n, 20)),
+                Cell::from(player.games_played.to_string()),
+                Cell::from(winrate),
+            ])
+            .style(style)
+        })
+        .collect();
+    let table = Table::new(rows, [Constraint::Min(16), Constraint::Length(6), Constraint::Length(5)])
+        .header(header)
+        .block(block)
+        .column_spacing(1)
+        .style(Style::default().bg(base).fg(text));
+    frame.render_widget(table, area);
+}
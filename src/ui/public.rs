@@ -0,0 +1,202 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Bar, BarChart, BarGroup, Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap};
+
+use crate::app::{App, HitAreas, ListHit};
+use crate::config::Theme;
+use crate::i18n::I18n;
+use crate::models::RankBin;
+
+use super::helpers::{format_duration, format_relative_time, truncate_text};
+
+pub fn draw_public_tab(frame: &mut Frame, app: &App, area: Rect, theme: Theme, hits: &mut HitAreas, i18n: &I18n) {
+    let layout = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(area);
+    draw_public_matches(frame, app, layout[0], theme, hits, i18n);
+    draw_distribution(frame, app, layout[1], theme, i18n);
+}
+
+fn public_block<'a>(title: String, theme: Theme) -> Block<'a> {
+    Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(Style::default().fg(theme.border))
+}
+
+fn draw_placeholder(frame: &mut Frame, area: Rect, block: Block, message: &str, theme: Theme) {
+    let placeholder = Paragraph::new(message.to_string())
+        .block(block)
+        .style(Style::default().bg(theme.base).fg(theme.text))
+        .wrap(Wrap { trim: true });
+    frame.render_widget(placeholder, area);
+}
+
+fn hero_list(app: &App, heroes: &[i32], i18n: &I18n) -> String {
+    heroes
+        .iter()
+        .map(|id| app.hero_name(*id, i18n))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn draw_public_matches(frame: &mut Frame, app: &App, area: Rect, theme: Theme, hits: &mut HitAreas, i18n: &I18n) {
+    let Theme { base, text, accent, highlight, success, loss, .. } = theme;
+    let title = i18n.title_public(&i18n.rank_range(app.public_min_rank, app.public_max_rank));
+    let block = public_block(title, theme);
+    let Some(matches) = app.public_matches.as_ref() else {
+        draw_placeholder(frame, area, block, i18n.loading_player(), theme);
+        return;
+    };
+    if matches.is_empty() {
+        draw_placeholder(frame, area, block, i18n.public_empty(), theme);
+        return;
+    }
+
+    let header = Row::new(vec![
+        i18n.table_avg_rank(),
+        i18n.title_radiant(),
+        i18n.title_dire(),
+        i18n.table_winner(),
+        i18n.table_duration(),
+        i18n.table_time(),
+    ])
+    .style(Style::default().fg(accent).add_modifier(Modifier::BOLD));
+
+    let total = matches.len();
+    let selected = app.public_index.min(total - 1);
+    let max_rows = area.height.saturating_sub(3).max(1) as usize;
+    let mut start = selected.saturating_sub(max_rows / 2);
+    if start + max_rows > total {
+        start = total.saturating_sub(max_rows);
+    }
+    let end = (start + max_rows).min(total);
+    let rows: Vec<Row> = matches[start..end]
+        .iter()
+        .map(|m| {
+            let rank = m.avg_rank_tier.map(|tier| tier.round() as i32);
+            let winner = match m.radiant_win {
+                Some(true) => Cell::from(i18n.title_radiant().to_string()).style(Style::default().fg(success)),
+                Some(false) => Cell::from(i18n.title_dire().to_string()).style(Style::default().fg(loss)),
+                None => Cell::from(i18n.placeholder_dash().to_string()),
+            };
+            Row::new(vec![
+                Cell::from(i18n.format_rank_tier(rank, None)),
+                Cell::from(hero_list(app, &m.radiant_team, i18n)),
+                Cell::from(hero_list(app, &m.dire_team, i18n)),
+                winner,
+                Cell::from(m.duration.map(format_duration).unwrap_or_default()),
+                Cell::from(format_relative_time(m.start_time, i18n)),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(12),
+            Constraint::Min(20),
+            Constraint::Min(20),
+            Constraint::Length(8),
+            Constraint::Length(6),
+            Constraint::Length(10),
+        ],
+    )
+    .header(header)
+    .block(block)
+    .column_spacing(1)
+    .style(Style::default().bg(base).fg(text))
+    .highlight_style(Style::default().bg(highlight))
+    .highlight_symbol("▌ ");
+
+    let mut state = TableState::default();
+    state.select(Some(selected - start));
+    frame.render_stateful_widget(table, area, &mut state);
+    hits.public = Some(ListHit {
+        area,
+        first_row_y: area.y + 2,
+        row_height: 1,
+        offset: start,
+        len: end,
+    });
+}
+
+fn top_share(bins: &[RankBin], rank_tier: i32) -> Option<f64> {
+    let total: i64 = bins.iter().map(|bin| bin.count).sum();
+    if total <= 0 {
+        return None;
+    }
+    let at_or_above: i64 = bins
+        .iter()
+        .filter(|bin| bin.bin_name >= rank_tier)
+        .map(|bin| bin.count)
+        .sum();
+    Some(at_or_above as f64 / total as f64 * 100.0)
+}
+
+fn draw_distribution(frame: &mut Frame, app: &App, area: Rect, theme: Theme, i18n: &I18n) {
+    let Theme { base, text, accent, border, .. } = theme;
+    let rank_tier = app
+        .profile
+        .as_ref()
+        .and_then(|profile| profile.rank_tier)
+        .filter(|tier| *tier > 0);
+    let Some(bins) = app.distribution.as_ref() else {
+        let block = public_block(i18n.title_distribution().to_string(), theme);
+        draw_placeholder(frame, area, block, i18n.loading_player(), theme);
+        return;
+    };
+    let title = match rank_tier.and_then(|tier| top_share(bins, tier).map(|top| (tier, top))) {
+        Some((tier, top)) => {
+            let leaderboard = app.profile.as_ref().and_then(|profile| profile.leaderboard_rank);
+            let rank = i18n.format_rank_tier(Some(tier), leaderboard);
+            format!("{} · {}", i18n.title_distribution(), i18n.distribution_rank(&rank, &format!("{top:.1}")))
+        }
+        None => i18n.title_distribution().to_string(),
+    };
+    let block = public_block(title, theme);
+    let mut medals: Vec<(i32, Vec<&RankBin>)> = Vec::new();
+    for bin in bins.iter().filter(|bin| bin.bin_name > 0) {
+        let medal = bin.bin_name / 10;
+        match medals.last_mut() {
+            Some((last, group)) if *last == medal => group.push(bin),
+            _ => medals.push((medal, vec![bin])),
+        }
+    }
+    if medals.is_empty() {
+        draw_placeholder(frame, area, block, i18n.histogram_empty(), theme);
+        return;
+    }
+
+    let inner_width = area.width.saturating_sub(2);
+    let bar_count = medals.iter().map(|(_, group)| group.len()).sum::<usize>() as u16;
+    let gaps = medals.len().saturating_sub(1) as u16;
+    let bar_width = (inner_width.saturating_sub(gaps) / bar_count.max(1)).clamp(1, 4);
+    let mut chart = BarChart::default()
+        .block(block)
+        .bar_width(bar_width)
+        .bar_gap(0)
+        .group_gap(1)
+        .style(Style::default().bg(base).fg(text));
+    for (medal, group) in &medals {
+        let bars: Vec<Bar> = group
+            .iter()
+            .map(|bin| {
+                let color = if Some(bin.bin_name) == rank_tier { accent } else { border };
+                Bar::default()
+                    .value(bin.count.max(0) as u64)
+                    .text_value(String::new())
+                    .style(Style::default().fg(color))
+            })
+            .collect();
+        let label_width = group.len() * bar_width as usize;
+        let label = truncate_text(i18n.medal(*medal as usize), label_width);
+        let style = if rank_tier.is_some_and(|tier| tier / 10 == *medal) {
+            Style::default().fg(accent).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        chart = chart.data(BarGroup::default().label(Line::styled(label, style)).bars(&bars));
+    }
+    frame.render_widget(chart, area);
+}
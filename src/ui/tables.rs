@@ -1,11 +1,15 @@
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap};
 
-use crate::app::App;
+use crate::app::{App, HitAreas, ListHit, MatchKind};
+use crate::config::Theme;
 use crate::i18n::I18n;
 use crate::models::MatchDetail;
 
-use super::helpers::{format_duration, format_game_mode, format_relative_time, is_win, truncate_text};
+use super::helpers::{
+    chat_messages, format_duration, format_match_clock, format_relative_time, has_laning, is_win, lane_matchups,
+    lane_role_name, match_filter_summary, percentile_color, player_benchmarks, truncate_text, value_at, LaneOutcome,
+};
 use super::images::{push_match_row_images, push_team_images};
 use super::ImageTarget;
 
@@ -13,16 +17,19 @@ pub fn draw_matches_table(
     frame: &mut Frame,
     app: &mut App,
     area: Rect,
-    base: Color,
-    text: Color,
-    accent: Color,
+    theme: Theme,
     images: &mut Vec<ImageTarget>,
+    hits: &mut HitAreas,
     i18n: &I18n,
 ) {
+    let Theme { base, text, accent, highlight, border, .. } = theme;
     let header = Row::new(vec![
         i18n.table_hero(),
         i18n.table_result(),
         i18n.table_mode(),
+        i18n.table_type(),
+        i18n.table_region(),
+        i18n.table_patch(),
         i18n.table_duration(),
         i18n.table_time(),
         i18n.table_k(),
@@ -49,7 +56,13 @@ pub fn draw_matches_table(
             let duration = format_duration(m.duration);
             let rel_time = format_relative_time(m.start_time, i18n);
             let hero = truncate_text(&app.hero_name(m.hero_id, i18n), 18);
-            let mode = format_game_mode(m.game_mode, i18n);
+            let mode = truncate_text(&app.game_mode_name(m.game_mode, i18n), 12);
+            let kind = i18n.match_kind(MatchKind::of(m));
+            let region = truncate_text(&app.region_name(app.match_region(m), i18n), 12);
+            let patch = app
+                .match_patch(m)
+                .map(|patch| patch.name.clone())
+                .unwrap_or_else(|| i18n.placeholder_dash().to_string());
             let k = m
                 .kills
                 .map(|v| v.to_string())
@@ -63,14 +76,17 @@ pub fn draw_matches_table(
                 .map(|v| v.to_string())
                 .unwrap_or_else(|| i18n.placeholder_dash().to_string());
             let row_style = if win {
-                Style::default().fg(Color::Rgb(166, 227, 161))
+                Style::default().fg(theme.win)
             } else {
-                Style::default().fg(Color::Rgb(243, 139, 168))
+                Style::default().fg(theme.loss)
             };
             Row::new(vec![
                 Cell::from(format!("       {hero}")),
                 Cell::from(result),
                 Cell::from(mode),
+                Cell::from(kind),
+                Cell::from(region),
+                Cell::from(patch),
                 Cell::from(duration),
                 Cell::from(rel_time),
                 Cell::from(k),
@@ -82,13 +98,21 @@ pub fn draw_matches_table(
         })
         .collect();
 
-    let title = i18n.title_matches();
+    let filters = match_filter_summary(app, i18n, false);
+    let title = if !app.has_match_filter() {
+        i18n.title_matches().to_string()
+    } else {
+        i18n.title_matches_filtered(&filters.join(" · "))
+    };
     let table = Table::new(
         rows,
         [
             Constraint::Length(28),
             Constraint::Length(4),
+            Constraint::Length(12),
             Constraint::Length(8),
+            Constraint::Length(12),
+            Constraint::Length(6),
             Constraint::Length(6),
             Constraint::Length(6),
             Constraint::Length(3),
@@ -101,11 +125,11 @@ pub fn draw_matches_table(
         Block::default()
             .borders(Borders::ALL)
             .title(title)
-            .border_style(Style::default().fg(accent)),
+            .border_style(Style::default().fg(border)),
     )
     .column_spacing(1)
     .style(Style::default().bg(base).fg(text))
-    .highlight_style(Style::default().bg(Color::Rgb(49, 50, 68)))
+    .highlight_style(Style::default().bg(highlight))
     .highlight_symbol("▌ ");
 
     let mut state = TableState::default();
@@ -114,25 +138,32 @@ pub fn draw_matches_table(
     }
     frame.render_stateful_widget(table, area, &mut state);
     push_match_row_images(app, area, images, start, row_count);
+    hits.matches = Some(ListHit {
+        area,
+        first_row_y: area.y + 2,
+        row_height: 2,
+        offset: start,
+        len: end,
+    });
 }
 
 pub fn draw_match_detail_tables(
     frame: &mut Frame,
     app: &App,
     area: Rect,
-    base: Color,
-    text: Color,
-    accent: Color,
+    theme: Theme,
     images: &mut Vec<ImageTarget>,
+    hits: &mut HitAreas,
     i18n: &I18n,
 ) {
+    let Theme { base, text, border, .. } = theme;
     if app.detail_loading {
         let detail = Paragraph::new(i18n.loading_detail())
             .block(
                 Block::default()
                     .borders(Borders::ALL)
                     .title(i18n.title_match_detail())
-                    .border_style(Style::default().fg(accent)),
+                    .border_style(Style::default().fg(border)),
             )
             .style(Style::default().bg(base).fg(text))
             .wrap(Wrap { trim: true });
@@ -147,7 +178,7 @@ pub fn draw_match_detail_tables(
                     Block::default()
                         .borders(Borders::ALL)
                         .title(i18n.title_match_detail())
-                        .border_style(Style::default().fg(accent)),
+                        .border_style(Style::default().fg(border)),
                 )
                 .style(Style::default().bg(base).fg(text))
                 .wrap(Wrap { trim: true });
@@ -156,27 +187,32 @@ pub fn draw_match_detail_tables(
         }
     };
 
+    if app.show_laning {
+        draw_laning_table(frame, app, detail, area, theme, i18n);
+        return;
+    }
     let layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
         .split(area);
 
-    draw_team_table(frame, app, detail, layout[0], base, text, accent, images, i18n, true);
-    draw_team_table(frame, app, detail, layout[1], base, text, accent, images, i18n, false);
+    draw_team_table(frame, app, detail, layout[0], theme, images, hits, i18n, true);
+    draw_team_table(frame, app, detail, layout[1], theme, images, hits, i18n, false);
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn draw_team_table(
     frame: &mut Frame,
     app: &App,
     detail: &MatchDetail,
     area: Rect,
-    base: Color,
-    text: Color,
-    accent: Color,
+    theme: Theme,
     images: &mut Vec<ImageTarget>,
+    hits: &mut HitAreas,
     i18n: &I18n,
     radiant: bool,
 ) {
+    let Theme { base, text, accent, highlight, border, .. } = theme;
     let debug = std::env::var("DOTA2_TUI_DEBUG").ok().as_deref() == Some("1");
     let players: Vec<&crate::models::MatchPlayer> = detail
         .players
@@ -209,9 +245,10 @@ pub fn draw_team_table(
                 .unwrap_or_else(|| i18n.unknown().to_string());
             let hero = truncate_text(&hero, 18);
             let mut name = p
-                .personaname
-                .as_deref()
-                .map(|value| truncate_text(value, 16))
+                .account_id
+                .and_then(|id| app.pro_label(id))
+                .or_else(|| p.personaname.clone())
+                .map(|value| truncate_text(&value, 16))
                 .unwrap_or_else(|| i18n.anonymous().to_string());
             if debug {
                 let marker = match p.account_id {
@@ -268,7 +305,11 @@ pub fn draw_team_table(
         })
         .collect();
 
-    let title = if radiant { i18n.title_radiant() } else { i18n.title_dire() };
+    let side = if radiant { i18n.title_radiant() } else { i18n.title_dire() };
+    let title = match app.detail_patch(detail) {
+        Some(patch) if radiant => format!("{side} · {}", i18n.patch_named(&patch.name)),
+        _ => side.to_string(),
+    };
     let table = Table::new(
         rows,
         [
@@ -288,11 +329,258 @@ pub fn draw_team_table(
         Block::default()
             .borders(Borders::ALL)
             .title(title)
-            .border_style(Style::default().fg(accent)),
+            .border_style(Style::default().fg(border)),
     )
     .column_spacing(1)
-    .style(Style::default().bg(base).fg(text));
+    .style(Style::default().bg(base).fg(text))
+    .highlight_style(Style::default().bg(highlight));
 
-    frame.render_widget(table, area);
+    let mut state = TableState::default();
+    if let Some((side, idx)) = app.team_selection
+        && side == radiant
+    {
+        state.select(Some(idx.min(visible.len().saturating_sub(1))));
+    }
+    frame.render_stateful_widget(table, area, &mut state);
     push_team_images(app, &visible, area, images);
+    let hit = ListHit {
+        area,
+        first_row_y: area.y + 2,
+        row_height: 2,
+        offset: 0,
+        len: visible.len(),
+    };
+    if radiant {
+        hits.radiant = Some(hit);
+    } else {
+        hits.dire = Some(hit);
+    }
+}
+
+pub fn draw_laning_table(
+    frame: &mut Frame,
+    app: &App,
+    detail: &MatchDetail,
+    area: Rect,
+    theme: Theme,
+    i18n: &I18n,
+) {
+    let Theme { base, text, accent, success, loss, border, .. } = theme;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(i18n.title_laning())
+        .border_style(Style::default().fg(border));
+    if !has_laning(detail) {
+        let placeholder = Paragraph::new(i18n.laning_unparsed())
+            .block(block)
+            .style(Style::default().bg(base).fg(text))
+            .wrap(Wrap { trim: true });
+        frame.render_widget(placeholder, area);
+        return;
+    }
+
+    let dash = i18n.placeholder_dash();
+    let header = Row::new(vec![
+        i18n.table_hero().to_string(),
+        i18n.table_player().to_string(),
+        i18n.table_role().to_string(),
+        i18n.table_lh_dn(5),
+        i18n.table_lh_dn(10),
+        i18n.table_gold_at(10),
+        i18n.table_efficiency().to_string(),
+    ])
+    .style(Style::default().fg(accent).add_modifier(Modifier::BOLD));
+
+    let mut rows: Vec<Row> = Vec::new();
+    for matchup in lane_matchups(detail) {
+        let (verdict, color) = match matchup.outcome {
+            Some(LaneOutcome::Radiant) => (i18n.lane_won(i18n.title_radiant()), success),
+            Some(LaneOutcome::Dire) => (i18n.lane_won(i18n.title_dire()), loss),
+            Some(LaneOutcome::Even) => (i18n.lane_even().to_string(), text),
+            None => (String::new(), text),
+        };
+        let label = if verdict.is_empty() {
+            i18n.lane(matchup.lane).to_string()
+        } else {
+            format!("{} · {verdict}", i18n.lane(matchup.lane))
+        };
+        rows.push(Row::new(vec![Cell::from(label)]).style(Style::default().fg(color).add_modifier(Modifier::BOLD)));
+        for (players, side_color) in [(&matchup.radiant, success), (&matchup.dire, loss)] {
+            for p in players.iter() {
+                let hero = p
+                    .hero_id
+                    .map(|id| app.hero_name(id, i18n))
+                    .unwrap_or_else(|| i18n.unknown().to_string());
+                let name = p
+                    .account_id
+                    .and_then(|id| app.pro_label(id))
+                    .or_else(|| p.personaname.clone())
+                    .map(|value| truncate_text(&value, 16))
+                    .unwrap_or_else(|| i18n.anonymous().to_string());
+                let role = p
+                    .lane_role
+                    .and_then(lane_role_name)
+                    .map(|role| i18n.lane_role(role).to_string())
+                    .unwrap_or_else(|| dash.to_string());
+                let lh_dn = |minute: usize| {
+                    match (value_at(p.lh_t.as_ref(), minute), value_at(p.dn_t.as_ref(), minute)) {
+                        (Some(lh), Some(dn)) => format!("{lh}/{dn}"),
+                        (Some(lh), None) => lh.to_string(),
+                        _ => dash.to_string(),
+                    }
+                };
+                let gold = value_at(p.gold_t.as_ref(), 10)
+                    .map(|v| v.to_string())
+                    .unwrap_or_else(|| dash.to_string());
+                let efficiency = p
+                    .lane_efficiency_pct
+                    .map(|v| format!("{v:.0}%"))
+                    .unwrap_or_else(|| dash.to_string());
+                let style = if app.account_id.is_some() && p.account_id == app.account_id {
+                    Style::default().fg(accent).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(text)
+                };
+                rows.push(
+                    Row::new(vec![
+                        Cell::from(format!("  {}", truncate_text(&hero, 18))).style(Style::default().fg(side_color)),
+                        Cell::from(name),
+                        Cell::from(role),
+                        Cell::from(lh_dn(5)),
+                        Cell::from(lh_dn(10)),
+                        Cell::from(gold),
+                        Cell::from(efficiency),
+                    ])
+                    .style(style),
+                );
+            }
+        }
+    }
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(22),
+            Constraint::Length(18),
+            Constraint::Length(10),
+            Constraint::Length(9),
+            Constraint::Length(9),
+            Constraint::Length(8),
+            Constraint::Min(5),
+        ],
+    )
+    .header(header)
+    .block(block)
+    .column_spacing(1)
+    .style(Style::default().bg(base).fg(text));
+    frame.render_widget(table, area);
+}
+
+pub fn draw_player_benchmarks(frame: &mut Frame, app: &App, area: Rect, theme: Theme, i18n: &I18n) {
+    let Theme { base, text, border, .. } = theme;
+    let Some(player) = app.selected_team_player() else {
+        return;
+    };
+    let hero = player
+        .hero_id
+        .map(|id| app.hero_name(id, i18n))
+        .unwrap_or_else(|| i18n.unknown().to_string());
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(i18n.title_benchmarks(&hero))
+        .border_style(Style::default().fg(border));
+    let inner = block.inner(area);
+    let label_width = 16usize;
+    let bar_width = (inner.width as usize).saturating_sub(label_width + 14).max(4);
+    let lines: Vec<Line> = player_benchmarks(player)
+        .into_iter()
+        .map(|(field, benchmark)| {
+            let pct = benchmark.pct.unwrap_or_default().clamp(0.0, 1.0);
+            let color = percentile_color(pct, theme);
+            let filled = (pct * bar_width as f64).round() as usize;
+            let raw = match benchmark.raw {
+                Some(raw) if raw.abs() < 10.0 => format!("{raw:.2}"),
+                Some(raw) => format!("{raw:.0}"),
+                None => i18n.placeholder_dash().to_string(),
+            };
+            Line::from(vec![
+                Span::raw(format!("{:<label_width$} ", truncate_text(i18n.benchmark(field), label_width))),
+                Span::styled("█".repeat(filled), Style::default().fg(color)),
+                Span::styled("░".repeat(bar_width - filled), Style::default().fg(border)),
+                Span::styled(
+                    format!(" {:>3.0}%", pct * 100.0),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!(" {raw}")),
+            ])
+        })
+        .collect();
+    let panel = Paragraph::new(lines)
+        .block(block)
+        .style(Style::default().bg(base).fg(text));
+    frame.render_widget(panel, area);
+}
+
+pub fn draw_match_chat(
+    frame: &mut Frame,
+    app: &App,
+    area: Rect,
+    theme: Theme,
+    hits: &mut HitAreas,
+    i18n: &I18n,
+) -> u16 {
+    let Theme { base, text, success, loss, border, .. } = theme;
+    let Some(detail) = app.match_detail.as_ref() else {
+        return 0;
+    };
+    let lines: Vec<Line> = chat_messages(detail)
+        .into_iter()
+        .map(|message| {
+            let player = detail
+                .players
+                .iter()
+                .find(|p| p.player_slot.is_some() && p.player_slot == message.player_slot);
+            let speaker = player
+                .and_then(|p| p.hero_id)
+                .map(|hero_id| app.hero_name(hero_id, i18n))
+                .or_else(|| message.unit.clone())
+                .unwrap_or_else(|| i18n.unknown().to_string());
+            let radiant = message.player_slot.is_some_and(|slot| slot < 128);
+            Line::from(vec![
+                Span::styled(format!("{} ", format_match_clock(message.time)), Style::default().fg(border)),
+                Span::styled(
+                    format!("[{}] ", truncate_text(&speaker, 14)),
+                    Style::default().fg(if radiant { success } else { loss }),
+                ),
+                Span::raw(message.key.as_str().unwrap_or_default().to_string()),
+            ])
+        })
+        .collect();
+
+    let visible = area.height.saturating_sub(2);
+    let max_scroll = (lines.len() as u16).saturating_sub(visible);
+    let scroll = app.chat_scroll.min(max_scroll);
+    let title = if max_scroll > 0 {
+        format!("{} ({}/{})", i18n.title_chat(), scroll + 1, max_scroll + 1)
+    } else {
+        i18n.title_chat().to_string()
+    };
+    let chat = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(Style::default().fg(border)),
+        )
+        .style(Style::default().bg(base).fg(text))
+        .scroll((scroll, 0));
+    frame.render_widget(chat, area);
+    hits.chat = Some(ListHit {
+        area,
+        first_row_y: area.y + 1,
+        row_height: 1,
+        offset: scroll as usize,
+        len: 0,
+    });
+    max_scroll
 }
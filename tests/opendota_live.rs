@@ -40,3 +40,155 @@ async fn fetch_matches_live() {
     let matches = client.fetch_matches(account_id()).await;
     assert!(matches.is_ok(), "matches request failed: {:?}", matches);
 }
+
+#[tokio::test]
+async fn fetch_game_constants_live() {
+    if !live_enabled() {
+        return;
+    }
+    let client = ApiClient::new(api_config());
+    let constants = client.fetch_game_constants().await;
+    assert!(constants.is_ok(), "constants request failed: {:?}", constants);
+}
+
+#[tokio::test]
+async fn fetch_player_aggregates_live() {
+    if !live_enabled() {
+        return;
+    }
+    let client = ApiClient::new(api_config());
+    let totals = client.fetch_totals(account_id()).await;
+    assert!(totals.is_ok(), "totals request failed: {:?}", totals);
+    let counts = client.fetch_counts(account_id()).await;
+    assert!(counts.is_ok(), "counts request failed: {:?}", counts);
+    let wl = client.fetch_wl(account_id()).await;
+    assert!(wl.is_ok(), "wl request failed: {:?}", wl);
+}
+
+#[tokio::test]
+async fn fetch_histogram_live() {
+    if !live_enabled() {
+        return;
+    }
+    let client = ApiClient::new(api_config());
+    let buckets = client
+        .fetch_histogram(account_id(), "duration", vec![("significant", "0".to_string())])
+        .await;
+    assert!(buckets.is_ok(), "histogram request failed: {:?}", buckets);
+}
+
+#[tokio::test]
+async fn fetch_wardmap_live() {
+    if !live_enabled() {
+        return;
+    }
+    let client = ApiClient::new(api_config());
+    let wardmap = client
+        .fetch_wardmap(account_id(), vec![("significant", "0".to_string())])
+        .await;
+    assert!(wardmap.is_ok(), "wardmap request failed: {:?}", wardmap);
+}
+
+#[tokio::test]
+async fn fetch_wordcloud_live() {
+    if !live_enabled() {
+        return;
+    }
+    let client = ApiClient::new(api_config());
+    let words = client
+        .fetch_wordcloud(account_id(), vec![("significant", "0".to_string())])
+        .await;
+    assert!(words.is_ok(), "wordcloud request failed: {:?}", words);
+}
+
+#[tokio::test]
+async fn fetch_hero_matchups_live() {
+    if !live_enabled() {
+        return;
+    }
+    let client = ApiClient::new(api_config());
+    let matchups = client.fetch_hero_matchups(1).await;
+    assert!(matchups.is_ok(), "matchups request failed: {:?}", matchups);
+}
+
+#[tokio::test]
+async fn fetch_hero_items_and_durations_live() {
+    if !live_enabled() {
+        return;
+    }
+    let client = ApiClient::new(api_config());
+    let items = client.fetch_hero_item_popularity(1).await;
+    assert!(items.is_ok(), "item popularity request failed: {:?}", items);
+    let durations = client.fetch_hero_durations(1).await;
+    assert!(durations.is_ok(), "durations request failed: {:?}", durations);
+}
+
+#[tokio::test]
+async fn fetch_pro_scene_live() {
+    if !live_enabled() {
+        return;
+    }
+    let client = ApiClient::new(api_config());
+    let matches = client.fetch_pro_matches().await;
+    assert!(matches.is_ok(), "pro matches request failed: {:?}", matches);
+    let players = client.fetch_pro_players().await;
+    assert!(players.is_ok(), "pro players request failed: {:?}", players);
+    let leagues = client.fetch_leagues().await;
+    assert!(leagues.is_ok(), "leagues request failed: {:?}", leagues);
+    let teams = client.fetch_teams().await.expect("teams request failed");
+    if let Some(team) = teams.first() {
+        let team_matches = client.fetch_team_matches(team.team_id).await;
+        assert!(team_matches.is_ok(), "team matches request failed: {:?}", team_matches);
+        let team_players = client.fetch_team_players(team.team_id).await;
+        assert!(team_players.is_ok(), "team players request failed: {:?}", team_players);
+    }
+    if let Some(league_id) = leagues.ok().and_then(|leagues| leagues.first().map(|league| league.leagueid)) {
+        let league_matches = client.fetch_league_matches(league_id).await;
+        assert!(league_matches.is_ok(), "league matches request failed: {:?}", league_matches);
+    }
+}
+
+#[tokio::test]
+async fn fetch_live_games_live() {
+    if !live_enabled() {
+        return;
+    }
+    let client = ApiClient::new(api_config());
+    let games = client.fetch_live().await;
+    assert!(games.is_ok(), "live request failed: {:?}", games);
+}
+
+#[tokio::test]
+async fn fetch_public_matches_and_distributions_live() {
+    if !live_enabled() {
+        return;
+    }
+    let client = ApiClient::new(api_config());
+    let matches = client
+        .fetch_public_matches(vec![("min_rank", "50".to_string()), ("max_rank", "59".to_string())])
+        .await;
+    assert!(matches.is_ok(), "public matches request failed: {:?}", matches);
+    let distributions = client.fetch_distributions().await;
+    assert!(distributions.is_ok(), "distributions request failed: {:?}", distributions);
+}
+
+#[tokio::test]
+async fn fetch_explorer_live() {
+    if !live_enabled() {
+        return;
+    }
+    let client = ApiClient::new(api_config());
+    let result = client.fetch_explorer("SELECT 1 AS one").await;
+    assert!(result.is_ok(), "explorer request failed: {:?}", result);
+}
+
+#[tokio::test]
+async fn fetch_explorer_invalid_sql_live() {
+    if !live_enabled() {
+        return;
+    }
+    let client = ApiClient::new(api_config());
+    let result = client.fetch_explorer("SELEC 1").await;
+    let message = result.err().map(|err| err.to_string()).unwrap_or_default();
+    assert!(message.contains("syntax error"), "unexpected explorer error: {message}");
+}